@@ -0,0 +1,66 @@
+#![feature(test)]
+
+extern crate codespan;
+extern crate test;
+extern crate tigerc;
+
+use codespan::{CodeMap, FileName};
+use test::Bencher;
+
+use tigerc::{check, lex, parse};
+
+/// Generates a program with [depth] nested let blocks, each declaring
+/// [width] variables and reading variables bound in the outermost scope.
+fn synthetic(depth: usize, width: usize) -> String {
+    let mut program = String::new();
+
+    for d in 0..depth {
+        program.push_str("let\n");
+        for w in 0..width {
+            if d == 0 {
+                program.push_str(&format!("  var x_0_{} := {}\n", w, w));
+            } else {
+                program.push_str(&format!("  var x_{}_{} := x_0_{} + {}\n", d, w, w, w));
+            }
+        }
+        program.push_str("in\n");
+    }
+
+    program.push_str("0\n");
+
+    for _ in 0..depth {
+        program.push_str("end\n");
+    }
+
+    program
+}
+
+fn run(bencher: &mut Bencher, depth: usize, width: usize, type_check: bool) {
+    let source = synthetic(depth, width);
+    bencher.iter(|| {
+        let mut code = CodeMap::new();
+        let file = code.add_filemap(FileName::virtual_("bench"), source.clone());
+        let ast = parse::parse(lex::lex(file).unwrap()).unwrap();
+        if type_check { check::check(ast).unwrap(); }
+    });
+}
+
+#[bench]
+fn parse_deep(bencher: &mut Bencher) {
+    run(bencher, 200, 5, false)
+}
+
+#[bench]
+fn check_deep(bencher: &mut Bencher) {
+    run(bencher, 200, 5, true)
+}
+
+#[bench]
+fn parse_wide(bencher: &mut Bencher) {
+    run(bencher, 5, 200, false)
+}
+
+#[bench]
+fn check_wide(bencher: &mut Bencher) {
+    run(bencher, 5, 200, true)
+}
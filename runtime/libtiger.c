@@ -15,11 +15,14 @@ void __flush__() {
     fflush(stdout);
 }
 
+// Strings point at their first character, with their length in the word
+// before it and a NUL after the last character, so either layout works
 char* __getchar__() {
-    char* c = malloc(sizeof(char) * 2);
-    scanf("%c", c);
+    long* prefix = malloc(sizeof(long) + sizeof(char) * 2);
+    char* c = (char*) (prefix + 1);
+    prefix[0] = scanf("%c", c) == 1 ? 1 : 0;
     fflush(stdin);
-    c[1] = '\0';
+    c[prefix[0]] = '\0';
     return c;
 }
 
@@ -38,10 +41,30 @@ int __ord__(char* s) {
     }
 }
 
+// Only called for NUL-terminated strings, so the empty string has size 0
 int __size__(char* s) {
     return strlen(s);
 }
 
+// Arrays hold one word per element, after a word holding the element count
+// for bounds checks, and size is the element count
+long* init_array(long size, long init) {
+    if (size < 0) {
+        fprintf(stderr, "Negative array size\n");
+        abort();
+    }
+    long* prefix = malloc(sizeof(long) * (size + 1));
+    if (prefix == NULL) {
+        return NULL;
+    }
+    prefix[0] = size;
+    long* array = prefix + 1;
+    for (long i = 0; i < size; i++) {
+        array[i] = init;
+    }
+    return array;
+}
+
 int __not__(int i) {
     return i == 0;
 }
@@ -49,3 +72,66 @@ int __not__(int i) {
 void __exit__(int i) {
     exit(i);
 }
+
+// Copied into each frame in checked mode, and compared before returning
+long __canary__ = 0x2f8b6d91c4e7a053;
+
+void __abort__() {
+    fprintf(stderr, "Stack corruption detected\n");
+    abort();
+}
+
+// Called in checked mode when an array index is out of bounds
+void __bounds__() {
+    fprintf(stderr, "Index out of bounds\n");
+    abort();
+}
+
+// Called in checked mode when an allocation returns a null pointer
+void __oom__() {
+    fprintf(stderr, "Out of memory\n");
+    abort();
+}
+
+// Interface for a garbage-collected runtime, targeted with `--gc`: each frame
+// registers the stack slots of its heap-typed variables while it's live.
+// Nothing is reclaimed yet, since intermediate values held only in registers
+// aren't registered as roots.
+#define ROOTS 65536
+
+static long** __roots__[ROOTS];
+static long __root_count__ = 0;
+
+void __gc_push__(long** root) {
+    if (__root_count__ == ROOTS) {
+        fprintf(stderr, "Too many garbage collection roots\n");
+        abort();
+    }
+    __roots__[__root_count__++] = root;
+}
+
+void __gc_pop__(long count) {
+    __root_count__ -= count;
+}
+
+// Memory is zeroed, so unregistered fields never hold stale pointers
+long* __alloc__(long size) {
+    return calloc(1, size);
+}
+
+long* __alloc_array__(long size, long init) {
+    if (size < 0) {
+        fprintf(stderr, "Negative array size\n");
+        abort();
+    }
+    long* prefix = __alloc__(sizeof(long) * (size + 1));
+    if (prefix == NULL) {
+        return NULL;
+    }
+    prefix[0] = size;
+    long* array = prefix + 1;
+    for (long i = 0; i < size; i++) {
+        array[i] = init;
+    }
+    return array;
+}
@@ -27,18 +27,33 @@ impl Flow {
 
         for stm in ir {
 
+            // Code between a jump and the next label is unreachable, e.g. after a break
+            if header.is_none() {
+                if let ir::Stm::Label(_) = stm {} else { continue }
+            }
+
             match stm {
             | ir::Stm::Label(label) => {
-                let symbol = label;
-                header = Some(symbol);
+
+                // Close off fall-through block with an explicit jump
+                if let Some(current) = header.filter(|current| *current != label && !block.is_empty()) {
+                    graph.add_edge(current, label, Void {});
+                    block.push(ir::Stm::Jump(ir::Exp::Name(label), vec![label]));
+                    blocks.insert(current, block);
+                    block = Vec::new();
+                }
+
+                header = Some(label);
                 block.push(stm);
             },
-            | ir::Stm::Jump(ir::Exp::Name(label), _) => {
+            | ir::Stm::Jump(_, ref labels) => {
 
                 let current = header
                     .expect("Internal error: missing header for block");
 
-                graph.add_edge(current, label, Void {});
+                for label in labels {
+                    graph.add_edge(current, *label, Void {});
+                }
                 block.push(stm);
                 blocks.insert(current, block);
                 block = Vec::new();
@@ -92,6 +107,10 @@ impl Flow {
         let mut reordered = Vec::new();
         self.trace(self.start(), &mut height, &mut seen);
 
+        // End block falls through to the epilogue, so it must be placed last
+        let end = self.end();
+        let mut end_block = self.remove(end).unwrap_or_default();
+
         while !self.blocks.is_empty() {
 
             let mut node_symbol = self.blocks.keys()
@@ -116,6 +135,7 @@ impl Flow {
             }
         }
 
+        reordered.append(&mut end_block);
         reordered
     }
 
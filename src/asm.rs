@@ -1,8 +1,12 @@
 use std::fmt;
+use fnv::FnvHashSet;
 use simple_symbol::Symbol;
 
+use config::WORD_SIZE;
 use ir;
+use span::Span;
 use operand::*;
+use util::escape;
 
 pub struct Unit<T: Operand> {
     pub data: Vec<Asm<T>>,
@@ -14,6 +18,96 @@ pub struct Function<T: Operand> {
     pub stack_info: (usize, Symbol, Symbol),
 }
 
+impl <T: Operand> Function<T> {
+
+    /// Maps the index of each emitted instruction that begins a source
+    /// statement back to the statement's span.
+    pub fn line_map(&self) -> Vec<(usize, Span)> {
+        let mut index = 0;
+        let mut map = Vec::new();
+        for stm in &self.body {
+            match stm {
+            | Asm::Loc(span) => map.push((index, *span)),
+            | _              => index += 1,
+            }
+        }
+        map
+    }
+}
+
+/// Code generation statistics for one allocated function.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Emitted instructions, not counting labels, directives, or comments
+    pub instructions: usize,
+
+    /// Stack slots the allocator gave to temps
+    pub spills: usize,
+
+    /// Bytes reserved below the frame pointer
+    pub frame_size: i32,
+
+    pub calls: usize,
+}
+
+impl Unit<Reg> {
+    pub fn stats(&self) -> Vec<Stats> {
+        self.functions.iter()
+            .map(|function| function.stats())
+            .collect()
+    }
+}
+
+impl Function<Reg> {
+    pub fn stats(&self) -> Stats {
+
+        // Escaping variables and spilled arguments come before any temps
+        let reserved = -(self.stack_info.0 as i32 * WORD_SIZE);
+        let mut slots = FnvHashSet::default();
+        let mut stats = Stats::default();
+
+        for asm in &self.body {
+            match asm {
+            | Asm::Label(_)
+            | Asm::Comment(_)
+            | Asm::Loc(_)
+            | Asm::Direct(_) => continue,
+            | Asm::Call(_) => stats.calls += 1,
+            | Asm::Bin(Binop::Sub, Binary::IR(Imm::Int(size), Reg::RSP)) => {
+                stats.frame_size = stats.frame_size.max(*size)
+            },
+            | _ => (),
+            }
+
+            stats.instructions += 1;
+
+            let mem = match asm {
+            | Asm::Mov(binary)
+            | Asm::Bin(_, binary)
+            | Asm::Cmp(binary) => match (binary.source(), binary.dest()) {
+                | (Value::Mem(mem), _)
+                | (_, Value::Mem(mem)) => Some(mem),
+                | _                    => None,
+            },
+            | Asm::Mul(Unary::M(mem))
+            | Asm::Div(_, Unary::M(mem))
+            | Asm::Un(_, Unary::M(mem))
+            | Asm::Jmpi(Unary::M(mem))
+            | Asm::Pop(Unary::M(mem))
+            | Asm::Push(Unary::M(mem)) => Some(*mem),
+            | _                        => None,
+            };
+
+            if let Some(Mem::RO(Reg::RBP, offset)) = mem {
+                if offset < reserved { slots.insert(offset); }
+            }
+        }
+
+        stats.spills = slots.len();
+        stats
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Value<T: Operand> {
     Reg(T),
@@ -32,14 +126,19 @@ pub enum Asm<T: Operand> {
     Push(Unary<T>),
     Lea(Mem<T>, T),
     Cmp(Binary<T>),
+    Set(Relop, T),
+    Movzx(T, T),
     Jmp(Label),
+    Jmpi(Unary<T>),
     Jcc(Relop, Label),
     Call(Label),
     Label(Label),
     Comment(Symbol),
+    Loc(Span),
     Direct(Direct),
     Cqo,
     Ret,
+    Trap,
 }
 
 impl Into<Asm<Reg>> for Asm<Temp> {
@@ -50,9 +149,11 @@ impl Into<Asm<Reg>> for Asm<Temp> {
         | Asm::Call(label)     => Asm::Call(label),
         | Asm::Label(label)    => Asm::Label(label),
         | Asm::Comment(symbol) => Asm::Comment(symbol),
+        | Asm::Loc(span)       => Asm::Loc(span),
         | Asm::Direct(direct)  => Asm::Direct(direct),
         | Asm::Cqo             => Asm::Cqo,
         | Asm::Ret             => Asm::Ret,
+        | Asm::Trap            => Asm::Trap,
         | _                    => panic!("Internal error: converting temp-dependent Asm to reg"),
         }
     }
@@ -65,7 +166,10 @@ pub enum Div { Q, R }
 pub enum Direct {
     Local(Label),
     Global(Label),
+    Weak(Label),
     Align(i32),
+    Quad(i64),
+    QuadLabel(Label),
     Str(Symbol),
     Data,
     Text,
@@ -127,6 +231,7 @@ pub enum Binop {
     And,
     Or,
     XOr,
+    Shl,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -168,6 +273,7 @@ impl <T: Operand> fmt::Display for Unit<T> {
 impl <T: Operand> fmt::Display for Function<T> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         for stm in &self.body {
+            if let Asm::Loc(_) = stm { continue }
             write!(fmt, "    {}\n", stm).expect("Internal error: IO")
         }
         Ok(())
@@ -186,14 +292,19 @@ impl <T: Operand> fmt::Display for Asm<T> {
         | Asm::Push(un)         => write!(fmt, "pushq {}", un),
         | Asm::Lea(mem, reg)    => write!(fmt, "leaq {}, {}", mem, reg),
         | Asm::Cmp(bin)         => write!(fmt, "cmpq {}", bin),
+        | Asm::Set(op, reg)     => write!(fmt, "set{} {}", op, reg.byte()),
+        | Asm::Movzx(src, dst)  => write!(fmt, "movzbq {}, {}", src.byte(), dst),
         | Asm::Jmp(name)        => write!(fmt, "jmp {}", name),
+        | Asm::Jmpi(un)         => write!(fmt, "jmp *{}", un),
         | Asm::Jcc(op, name)    => write!(fmt, "j{} {}", op,  name),
         | Asm::Call(name)       => write!(fmt, "call {}", name),
         | Asm::Cqo              => write!(fmt, "cqo"),
         | Asm::Ret              => write!(fmt, "ret"),
+        | Asm::Trap             => write!(fmt, "ud2"),
         | Asm::Direct(direct)   => write!(fmt, "{}", direct),
         | Asm::Label(label)     => write!(fmt, "{}:", label),
         | Asm::Comment(comment) => write!(fmt, "# {}", comment),
+        | Asm::Loc(span)        => write!(fmt, "# {}", span),
         }
     }
 }
@@ -203,10 +314,13 @@ impl fmt::Display for Direct {
         match self {
         | Direct::Local(label)  => write!(fmt, ".local {}", label),
         | Direct::Global(label) => write!(fmt, ".globl {}", label),
+        | Direct::Weak(label)   => write!(fmt, ".weak {}", label),
         | Direct::Align(n)      => write!(fmt, ".align {}", n),
+        | Direct::Quad(n)       => write!(fmt, "    .quad {}", n),
+        | Direct::QuadLabel(l)  => write!(fmt, "    .quad {}", l),
         | Direct::Data          => write!(fmt, ".data"),
         | Direct::Text          => write!(fmt, ".text"),
-        | Direct::Str(s)      => write!(fmt, "    .string \"{}\"", s),
+        | Direct::Str(s)      => write!(fmt, "    .string \"{}\"", escape(&s.to_string())),
         }
     }
 }
@@ -251,6 +365,7 @@ impl fmt::Display for Binop {
         | Binop::And => write!(fmt, "andq"),
         | Binop::Or  => write!(fmt, "orq"),
         | Binop::XOr => write!(fmt, "xorq"),
+        | Binop::Shl => write!(fmt, "shlq"),
         }
     }
 }
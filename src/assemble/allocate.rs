@@ -1,5 +1,5 @@
 use std::mem;
-use simple_symbol::Symbol;
+use simple_symbol::{store, Symbol};
 use fnv::FnvHashMap;
 
 use config::WORD_SIZE;
@@ -40,6 +40,8 @@ pub trait Assigner {
 
     fn get_stack_size(&self) -> usize;
 
+    fn get_temps(&self) -> Vec<(Temp, Mem<Reg>)>;
+
     fn store_temps(&mut self, asm: &mut Vec<Asm<Reg>>);
 
     fn load_temps(&mut self, asm: &mut Vec<Asm<Reg>>);
@@ -73,16 +75,29 @@ impl <A: Assigner> Allocator<A> {
         let stack_size = if stack_size % 2 == 0 { stack_size } else { stack_size + 1 };
         let stack_op = Binary::IR(Imm::Int(stack_size as i32 * WORD_SIZE), Reg::RSP);
 
-        self.allocated = mem::replace(&mut self.allocated, Vec::with_capacity(0))
+        // Name each stack slot after the temp it holds
+        let annotations = self.assigner.get_temps()
             .into_iter()
-            .map(|stm| {
-                match stm {
-                | Asm::Comment(sym) if sym == sub_rsp => Asm::Bin(Binop::Sub, stack_op),
-                | Asm::Comment(sym) if sym == add_rsp => Asm::Bin(Binop::Add, stack_op),
-                | stm => stm,
-                }
+            .filter_map(|(temp, mem)| match temp {
+                | Temp::Temp { id, name } => Some(Asm::Comment(store(&format!("{}_{} in {}", name, id, mem)))),
+                | Temp::Reg(_)            => None,
             })
-            .collect()
+            .collect::<Vec<_>>();
+
+        // Prologue and epilogue share the same adjustment, which is
+        // omitted entirely when nothing lives on the stack
+        for stm in mem::replace(&mut self.allocated, Vec::new()) {
+            match stm {
+            | Asm::Comment(sym) if sym == sub_rsp => {
+                if stack_size > 0 { self.allocated.push(Asm::Bin(Binop::Sub, stack_op)); }
+                self.allocated.extend(&annotations);
+            },
+            | Asm::Comment(sym) if sym == add_rsp => {
+                if stack_size > 0 { self.allocated.push(Asm::Bin(Binop::Add, stack_op)); }
+            },
+            | stm => self.allocated.push(stm),
+            }
+        }
     }
 
     fn load_temp(&mut self, temp: Temp, dir: Dir) -> Reg {
@@ -119,8 +134,11 @@ impl <A: Assigner> Allocator<A> {
         | Asm::Un(op, unary)   => Asm::Un(*op, self.allocate_unary(unary, Dir::RW)),
         | Asm::Pop(unary)      => Asm::Pop(self.allocate_unary(unary, Dir::W)),
         | Asm::Push(unary)     => Asm::Push(self.allocate_unary(unary, Dir::R)),
+        | Asm::Jmpi(unary)     => Asm::Jmpi(self.allocate_unary(unary, Dir::R)),
         | Asm::Lea(mem, temp)  => Asm::Lea(self.load_mem(*mem), self.load_temp(*temp, Dir::W)),
         | Asm::Cmp(binary)     => Asm::Cmp(self.allocate_binary(binary, Dir::R)),
+        | Asm::Set(op, temp)   => Asm::Set(*op, self.load_temp(*temp, Dir::RW)),
+        | Asm::Movzx(src, dst) => Asm::Movzx(self.load_temp(*src, Dir::R), self.load_temp(*dst, Dir::W)),
         | stm                  => (*stm).into(),
         }
     }
@@ -148,6 +166,17 @@ impl Assigner for Trivial {
         self.stack_size
     }
 
+    fn get_temps(&self) -> Vec<(Temp, Mem<Reg>)> {
+        let mut temps = self.temps.iter()
+            .map(|(temp, offset)| (*offset, *temp))
+            .collect::<Vec<_>>();
+
+        temps.sort_by_key(|(offset, _)| *offset);
+        temps.into_iter()
+            .map(|(offset, temp)| (temp, Mem::RO(Reg::RBP, -(offset * WORD_SIZE))))
+            .collect()
+    }
+
     fn store_temps(&mut self, asm: &mut Vec<Asm<Reg>>) {
         asm.append(&mut self.stores);
     }
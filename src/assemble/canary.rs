@@ -0,0 +1,53 @@
+use asm::*;
+use operand::*;
+
+/// Guards every function with a stack canary. The prologue copies the
+/// runtime's `__canary__` into a fresh temp, which the allocator spills
+/// into the frame, and the epilogue compares it against the original
+/// before returning, calling `__abort__` if the frame was overwritten.
+pub fn canary(unit: Unit<Temp>) -> Unit<Temp> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(canary_function)
+            .collect(),
+    }
+}
+
+fn canary_function(function: Function<Temp>) -> Function<Temp> {
+
+    let (_, sub_rsp, add_rsp) = function.stack_info;
+    let saved = Temp::from_str("CANARY");
+    let ok = Label::from_str("CANARY_OK");
+    let mut body = Vec::with_capacity(function.body.len() + 9);
+
+    for asm in function.body {
+        match asm {
+        | Asm::Comment(comment) if comment == sub_rsp => {
+            body.push(asm);
+            body.extend(load(saved));
+        },
+        | Asm::Comment(comment) if comment == add_rsp => {
+            let current = Temp::from_str("CANARY");
+            body.extend(load(current));
+            body.push(Asm::Cmp(Binary::RR(saved, current)));
+            body.push(Asm::Jcc(Relop::E, ok));
+            body.push(Asm::Call(Label::from_fixed("__abort__")));
+            body.push(Asm::Label(ok));
+            body.push(asm);
+        },
+        | _ => body.push(asm),
+        }
+    }
+
+    Function { body, stack_info: function.stack_info }
+}
+
+/// Reads the canary's value through its address into `temp`.
+fn load(temp: Temp) -> Vec<Asm<Temp>> {
+    let address = Temp::from_str("CANARY_ADDRESS");
+    vec![
+        Asm::Mov(Binary::IR(Imm::Label(Label::from_fixed("__canary__")), address)),
+        Asm::Mov(Binary::MR(Mem::R(address), temp)),
+    ]
+}
@@ -1,5 +1,11 @@
 mod allocate;
+mod canary;
+mod spills;
+mod stub;
 mod tile;
 
-pub use self::tile::tile;
+pub use self::tile::{tile, tile_with};
+pub use self::stub::stub;
+pub use self::canary::canary;
+pub use self::spills::spills;
 pub use self::allocate::{allocate, Assigner, Trivial};
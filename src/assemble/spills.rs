@@ -0,0 +1,23 @@
+use asm::*;
+use error::{Error, BackendError};
+use operand::*;
+
+/// Rejects any function whose temps take more than `limit` stack slots,
+/// rather than silently emitting an enormous frame for it.
+pub fn spills(unit: &Unit<Reg>, limit: usize) -> Result<(), Error> {
+    for function in &unit.functions {
+        let spills = function.stats().spills;
+        if spills <= limit { continue }
+
+        let label = function.body.iter()
+            .filter_map(|asm| match asm {
+                | Asm::Label(label) => Some(*label),
+                | _                 => None,
+            })
+            .next()
+            .expect("Internal error: missing function label");
+
+        return Err(Error::backend(BackendError::TooManySpills(label, spills, limit)))
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use fnv::FnvHashSet;
+use simple_symbol::store;
+
+use asm::*;
+use operand::*;
+
+/// Appends a weak definition for every label that is called but never
+/// defined in this unit, so the output assembles without the runtime.
+pub fn stub(mut unit: Unit<Reg>) -> Unit<Reg> {
+
+    let defined = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter_map(|asm| match asm {
+            | Asm::Label(label) => Some(*label),
+            | _                 => None,
+        })
+        .collect::<FnvHashSet<Label>>();
+
+    let mut externs = Vec::new();
+    for function in &unit.functions {
+        for asm in &function.body {
+            match asm {
+            | Asm::Call(label) if !defined.contains(label) && !externs.contains(label) => {
+                externs.push(*label)
+            },
+            | _ => (),
+            }
+        }
+    }
+
+    for label in externs {
+        unit.functions.push(stub_function(label));
+    }
+
+    unit
+}
+
+fn stub_function(label: Label) -> Function<Reg> {
+
+    let stub = store("STUB");
+
+    // Return zero without touching the stack
+    Function {
+        body: vec![
+            Asm::Direct(Direct::Weak(label)),
+            Asm::Direct(Direct::Align(4)),
+            Asm::Label(label),
+            Asm::Mov(Binary::IR(Imm::Int(0), Reg::RAX)),
+            Asm::Ret,
+        ],
+        stack_info: (0, stub, stub),
+    }
+}
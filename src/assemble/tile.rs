@@ -3,17 +3,32 @@ use simple_symbol::store;
 
 use asm;
 use asm::Value;
-use config::WORD_SIZE;
+use config::{CONVENTION, STRINGS, WORD_SIZE};
 use ir;
 use ir::*;
 use operand::*;
 
 pub fn tile(ir: ir::Unit) -> asm::Unit<Temp> {
+    tile_with(ir, CONVENTION, STRINGS)
+}
+
+pub fn tile_with(ir: ir::Unit, convention: Convention, strings: Strings) -> asm::Unit<Temp> {
     asm::Unit {
         data: ir.data.into_iter()
             .flat_map(|data| {
+
+                // Length sits in the word just before the label
+                let length = match strings {
+                | Strings::Prefixed   => Some(asm::Asm::Direct(asm::Direct::Quad(data.data.to_string().len() as i64))),
+                | Strings::Terminated => None,
+                };
+
                 iter::once(
                         asm::Asm::Direct(asm::Direct::Local(data.label))
+                    ).chain(iter::once(
+                        asm::Asm::Direct(asm::Direct::Align(WORD_SIZE))
+                    )).chain(
+                        length
                     ).chain(iter::once(
                         asm::Asm::Label(data.label)
                     )).chain(iter::once(
@@ -22,21 +37,43 @@ pub fn tile(ir: ir::Unit) -> asm::Unit<Temp> {
             }).collect(),
 
         functions: ir.functions.into_iter()
-            .map(tile_function)
+            .map(|function| tile_function(function, convention, strings))
             .collect()
     }
 }
 
-pub fn tile_function(ir: ir::Function) -> asm::Function<Temp> {
+pub fn tile_function(ir: ir::Function, convention: Convention, strings: Strings) -> asm::Function<Temp> {
+
+    let mut tiler = Tiler {
+        asm: Vec::new(),
+        spilled_args: 0,
+        convention,
+        strings,
+    };
+
+    let mut index = 0;
+    while index < ir.body.len() {
+        match tiler.tile_set(&ir.body, index) {
+        | Some(tiled) => index += tiled,
+        | None        => { tiler.tile_stm(&ir.body[index]); index += 1; },
+        }
+    }
 
-    let mut tiler = Tiler::default();
-    for stm in &ir.body { tiler.tile_stm(stm); }
+    let stores = [
+        (Reg::RBX, "STORE_RBX"),
+        (Reg::R12, "STORE_R12"),
+        (Reg::R13, "STORE_R13"),
+        (Reg::R14, "STORE_R14"),
+        (Reg::R15, "STORE_R15"),
+        (Reg::RDI, "STORE_RDI"),
+        (Reg::RSI, "STORE_RSI"),
+    ];
 
-    let store_rbx = Temp::from_str("STORE_RBX");
-    let store_r12 = Temp::from_str("STORE_R12");
-    let store_r13 = Temp::from_str("STORE_R13");
-    let store_r14 = Temp::from_str("STORE_R14");
-    let store_r15 = Temp::from_str("STORE_R15");
+    // Callee-saved registers other than RSP and RBP, which are handled separately
+    let saved = stores.iter()
+        .filter(|(reg, _)| convention.is_callee_saved(*reg))
+        .map(|(reg, name)| (Temp::Reg(*reg), Temp::from_str(name)))
+        .collect::<Vec<_>>();
 
     let sub_rsp = store("REPLACE WITH RSP SUBTRACTION");
     let add_rsp = store("REPLACE WITH RSP ADDITION");
@@ -45,30 +82,27 @@ pub fn tile_function(ir: ir::Function) -> asm::Function<Temp> {
         asm::Asm::Direct(asm::Direct::Global(ir.label)),
         asm::Asm::Direct(asm::Direct::Align(4)),
         asm::Asm::Label(ir.label),
+        asm::Asm::Comment(store("prologue")),
         asm::Asm::Push(asm::Unary::R(Temp::Reg(Reg::RBP))),
         asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::RSP), Temp::Reg(Reg::RBP))),
         asm::Asm::Comment(sub_rsp),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::RBX), store_rbx)),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::R12), store_r12)),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::R13), store_r13)),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::R14), store_r14)),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::R15), store_r15)),
-    ];
-
-    let epilogue = vec![
-        asm::Asm::Mov(asm::Binary::RR(store_rbx, Temp::Reg(Reg::RBX))),
-        asm::Asm::Mov(asm::Binary::RR(store_r12, Temp::Reg(Reg::R12))),
-        asm::Asm::Mov(asm::Binary::RR(store_r13, Temp::Reg(Reg::R13))),
-        asm::Asm::Mov(asm::Binary::RR(store_r14, Temp::Reg(Reg::R14))),
-        asm::Asm::Mov(asm::Binary::RR(store_r15, Temp::Reg(Reg::R15))),
-        asm::Asm::Comment(add_rsp),
-        asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::RBP), Temp::Reg(Reg::RSP))),
-        asm::Asm::Pop(asm::Unary::R(Temp::Reg(Reg::RBP))),
-        asm::Asm::Ret,
-    ];
+    ].into_iter().chain(
+        saved.iter().map(|(reg, temp)| asm::Asm::Mov(asm::Binary::RR(*reg, *temp)))
+    );
+
+    let epilogue = iter::once(asm::Asm::Comment(store("epilogue")))
+        .chain(saved.iter().map(|(reg, temp)| asm::Asm::Mov(asm::Binary::RR(*temp, *reg))))
+        .chain(vec![
+            asm::Asm::Comment(add_rsp),
+            asm::Asm::Mov(asm::Binary::RR(Temp::Reg(Reg::RBP), Temp::Reg(Reg::RSP))),
+            asm::Asm::Pop(asm::Unary::R(Temp::Reg(Reg::RBP))),
+            asm::Asm::Ret,
+        ])
+        .collect::<Vec<_>>();
 
     asm::Function {
-        body: prologue.into_iter()
+        body: prologue
+            .chain(iter::once(asm::Asm::Comment(store("body"))))
             .chain(tiler.asm.into_iter())
             .chain(epilogue.into_iter())
             .collect(),
@@ -77,10 +111,11 @@ pub fn tile_function(ir: ir::Function) -> asm::Function<Temp> {
     }
 }
 
-#[derive(Default)]
 struct Tiler {
     asm: Vec<asm::Asm<Temp>>,
     spilled_args: usize,
+    convention: Convention,
+    strings: Strings,
 }
 
 impl Tiler {
@@ -107,8 +142,21 @@ impl Tiler {
         | Stm::Seq(_) => panic!("Internal error: no Seq statement in canonical IR"),
         | Stm::Comment(s) => self.asm.push(asm::Asm::Comment(store(s))),
         | Stm::Label(l) => self.asm.push(asm::Asm::Label(*l)),
+        | Stm::Loc(span) => self.asm.push(asm::Asm::Loc(*span)),
         | Stm::Jump(Exp::Name(label), _) => self.asm.push(asm::Asm::Jmp(*label)),
-        | Stm::Jump(_, _) => panic!("Internal error: can only jump to labels"),
+
+        // Jump through a table, which lists its targets in order right after
+        | Stm::Jump(target @ Exp::Mem(box Exp::Binop(box Exp::Name(table), ir::Binop::Add, _)), labels) => {
+            let unary = match self.tile_exp(target) {
+            | Value::Mem(mem) => asm::Unary::M(mem),
+            | value           => asm::Unary::R(self.into_temp(value)),
+            };
+            self.asm.push(asm::Asm::Jmpi(unary));
+            self.asm.push(asm::Asm::Direct(asm::Direct::Align(WORD_SIZE)));
+            self.asm.push(asm::Asm::Label(*table));
+            self.asm.extend(labels.iter().map(|label| asm::Asm::Direct(asm::Direct::QuadLabel(*label))));
+        },
+        | Stm::Jump(_, _) => panic!("Internal error: can only jump to labels or through tables"),
         | Stm::Move(l, r) => {
             let binary = self.tile_binary(l, r);
             self.asm.push(asm::Asm::Mov(binary));
@@ -122,6 +170,40 @@ impl Tiler {
         }
     }
 
+    /// Tiles a comparison materialized as 0 or 1, as canonized from `Tree::Cx`
+    /// into `Tree::Ex`, with `setcc` instead of branching around the false case.
+    /// Returns the number of statements tiled, if those starting at `index` match.
+    fn tile_set(&mut self, body: &[Stm], index: usize) -> Option<usize> {
+        let (l, op, r, t, temp) = match &body[index..] {
+        | [Stm::Move(Exp::Const(1), Exp::Temp(a)), Stm::CJump(l, op, r, t, _), Stm::Move(Exp::Const(0), Exp::Temp(b)), Stm::Label(label), ..]
+            if a == b && t == label => (l, op, r, *t, *a),
+        | _ => return None,
+        };
+
+        // The comparison reads the temp after it's been set to 1
+        if uses(l, temp) || uses(r, temp) { return None }
+
+        let binary = self.tile_binary(r, l);
+        self.asm.push(asm::Asm::Cmp(binary));
+        self.asm.push(asm::Asm::Set(op.into(), temp));
+        self.asm.push(asm::Asm::Movzx(temp, temp));
+
+        // Keep the label if anything else still jumps to it
+        let jumped = body.iter()
+            .filter(|stm| match stm {
+                | Stm::Jump(_, labels)      => labels.contains(&t),
+                | Stm::CJump(_, _, _, a, b) => *a == t || *b == t,
+                | _                         => false,
+            })
+            .count() > 1;
+
+        if jumped { self.asm.push(asm::Asm::Label(t)); }
+        Some(4)
+    }
+
+    /// Tiles `lhs` as the source and `rhs` as the destination. At most one
+    /// operand stays in memory, and an immediate is only ever a source, so
+    /// the result is valid for both `mov` and `cmp`.
     fn tile_binary(&mut self, lhs: &Exp, rhs: &Exp) -> asm::Binary<Temp> {
         match (self.tile_exp(lhs), self.tile_exp(rhs)) {
         | (Value::Imm(imm), Value::Reg(temp)) => asm::Binary::IR(imm, temp),
@@ -202,6 +284,20 @@ impl Tiler {
             binary_op.dest()
         }
 
+        // Multiplication by power of two
+        | Exp::Binop(box r, ir::Binop::Mul, box Const(n))
+        | Exp::Binop(box Const(n), ir::Binop::Mul, box r) if *n > 0 && *n & (*n - 1) == 0 => {
+
+            let result = Temp::from_str("TILE_SHIFT_RESULT");
+            let binary_mv = self.tile_binary(r, &ir::Exp::Temp(result));
+            self.asm.push(asm::Asm::Mov(binary_mv));
+            self.asm.push(asm::Asm::Bin(
+                asm::Binop::Shl,
+                asm::Binary::IR(Imm::Int(n.trailing_zeros() as i32), result),
+            ));
+            Value::Reg(result)
+        }
+
         // Mul, Div, Mod
         | Exp::Binop(box l, op, box r) => {
 
@@ -243,29 +339,57 @@ impl Tiler {
 
             Value::Reg(result)
         }
+        | Exp::Call(box Exp::Name(label), args) if self.strings == Strings::Prefixed && *label == Label::from_fixed("__size__") => {
+
+            // Load the length prefix instead of calling into the runtime
+            let string = self.tile_exp(&args[0]);
+            let string = self.into_temp(string);
+            Value::Mem(Mem::RO(string, -WORD_SIZE))
+        }
         | Exp::Call(box Exp::Name(label), args) => {
 
-            let mut arg_offset = 0;
+            let registers = self.convention.arguments();
+            let mut arg_offset = self.convention.shadow_space();
             let return_temp = Temp::from_str("TILE_CALL");
+            let mut values = Vec::with_capacity(args.len());
+
+            // Evaluate all arguments left to right before filling any argument
+            // register, since a nested call would clobber them. Loads have to
+            // happen before later arguments' effects, which may write memory,
+            // and fixed registers are copied into fresh temps that the allocator
+            // can spill around the call.
+            for (i, arg) in args.iter().enumerate() {
+                let effects = args[i + 1..].iter().any(Exp::has_side_effects);
+                let value = match self.tile_exp(arg) {
+                | Value::Mem(mem) if effects => Value::Reg(self.into_temp(Value::Mem(mem))),
+                | Value::Reg(reg @ Temp::Reg(_)) if effects => {
+                    let temp = Temp::from_str("TILE_ARG");
+                    self.asm.push(asm::Asm::Mov(asm::Binary::RR(reg, temp)));
+                    Value::Reg(temp)
+                },
+                | value => value,
+                };
+                values.push(value);
+            }
 
-            for (i, arg) in args.into_iter().enumerate() {
+            for (i, value) in values.into_iter().enumerate() {
 
-                // Dedicated register for first six arguments
-                let binary = match self.tile_exp(arg) {
-                | Value::Mem(mem) if i < 6 => {
+                // Dedicated registers for leading arguments
+                let binary = match value {
+                | Value::Mem(mem) if i < registers.len() => {
                     asm::Binary::MR(
                         mem,
-                        Temp::Reg(Reg::get_argument(i)),
+                        Temp::Reg(registers[i]),
                     )
                 }
-                | temp if i < 6 => {
+                | temp if i < registers.len() => {
                     asm::Binary::RR(
                         self.into_temp(temp),
-                        Temp::Reg(Reg::get_argument(i)),
+                        Temp::Reg(registers[i]),
                     )
                 }
 
-                // Spill arguments onto stack
+                // Spill arguments onto stack, above any shadow space
                 //
                 //       ---------
                 //       | ARG 9 |
@@ -297,6 +421,11 @@ impl Tiler {
 
             self.spilled_args = usize::max(self.spilled_args, arg_offset);
             self.asm.push(asm::Asm::Call(*label));
+
+            // Exiting and failing a bounds check never return, so fail loudly if they somehow do
+            if *label == Label::from_fixed("__exit__") || *label == Label::from_fixed("__bounds__") {
+                self.asm.push(asm::Asm::Trap);
+            }
             self.asm.push(asm::Asm::Mov(
                 asm::Binary::RR(
                     Temp::Reg(Reg::get_return()),
@@ -330,3 +459,15 @@ impl Tiler {
         }
     }
 }
+
+fn uses(exp: &Exp, temp: Temp) -> bool {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_) => false,
+    | Exp::Temp(used) => *used == temp,
+    | Exp::Binop(lhs, _, rhs) => uses(lhs, temp) || uses(rhs, temp),
+    | Exp::Mem(addr) => uses(addr, temp),
+    | Exp::Call(name, args) => uses(name, temp) || args.iter().any(|arg| uses(arg, temp)),
+    | Exp::ESeq(_, _) => true,
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
-use simple_symbol::Symbol;
+use simple_symbol::{store, Symbol};
 use span::Span;
+use util::escape;
 
 #[derive(Debug)]
 pub enum Dec {
@@ -138,6 +139,7 @@ pub enum Exp {
 
     For {
         name: Symbol,
+        name_span: Span,
         escape: bool,
         lo: Box<Exp>,
         hi: Box<Exp>,
@@ -160,6 +162,190 @@ pub enum Exp {
     },
 }
 
+impl Var {
+    pub fn is_pure(&self) -> bool {
+        match self {
+        | Var::Simple(_, _)         => true,
+        | Var::Field(var, _, _, _)  => var.is_pure(),
+        | Var::Index(var, index, _) => var.is_pure() && index.is_pure(),
+        }
+    }
+
+    /// Whether both variables are written the same way, ignoring spans.
+    pub fn same(&self, other: &Var) -> bool {
+        match (self, other) {
+        | (Var::Simple(l, _), Var::Simple(r, _))             => l == r,
+        | (Var::Field(l, lf, _, _), Var::Field(r, rf, _, _)) => lf == rf && l.same(r),
+        | (Var::Index(l, li, _), Var::Index(r, ri, _))       => l.same(r) && li.same(ri),
+        | _                                                  => false,
+        }
+    }
+
+    fn diverges(&self, exit: bool) -> bool {
+        match self {
+        | Var::Simple(_, _)         => false,
+        | Var::Field(var, _, _, _)  => var.diverges(exit),
+        | Var::Index(var, index, _) => var.diverges(exit) || index.diverges(exit),
+        }
+    }
+
+    fn breaks(&self) -> bool {
+        match self {
+        | Var::Simple(_, _)         => false,
+        | Var::Field(var, _, _, _)  => var.breaks(),
+        | Var::Index(var, index, _) => var.breaks() || index.breaks(),
+        }
+    }
+}
+
+impl Exp {
+
+    /// Whether evaluating this expression only computes its value.
+    /// Calls, assignments, and control flow are impure, as are record
+    /// and array creation, which call into the runtime to allocate.
+    pub fn is_pure(&self) -> bool {
+        match self {
+        | Exp::Nil(_)
+        | Exp::Int(_, _)
+        | Exp::Str(_, _)              => true,
+        | Exp::Var(var, _)            => var.is_pure(),
+        | Exp::Neg(exp, _)            => exp.is_pure(),
+        | Exp::Bin { lhs, rhs, .. }   => lhs.is_pure() && rhs.is_pure(),
+        | Exp::Seq(exps, _)           => exps.iter().all(|exp| exp.is_pure()),
+        | Exp::If { guard, then, or, .. } => {
+            guard.is_pure()
+            && then.is_pure()
+            && or.as_ref().map_or(true, |or| or.is_pure())
+        },
+        | Exp::Let { decs, body, .. } => {
+            decs.iter().all(|dec| match dec {
+                | Dec::Var { init, .. } => init.is_pure(),
+                | Dec::Fun(_, _)
+                | Dec::Type(_, _)       => true,
+            })
+            && body.is_pure()
+        },
+        | Exp::Break(_)
+        | Exp::Call { .. }
+        | Exp::Rec { .. }
+        | Exp::Ass { .. }
+        | Exp::While { .. }
+        | Exp::For { .. }
+        | Exp::Arr { .. }             => false,
+        }
+    }
+
+    /// Whether this expression's value is that of an assignment, as when
+    /// `:=` is written where the comparison `=` was meant.
+    pub fn is_assignment(&self) -> bool {
+        match self {
+        | Exp::Ass { .. }     => true,
+        | Exp::Seq(exps, _)   => exps.last().map_or(false, |exp| exp.is_assignment()),
+        | _                   => false,
+        }
+    }
+
+    /// Whether both expressions are written the same way, ignoring spans.
+    /// Only values, variables, and arithmetic are compared; anything else
+    /// is never considered the same.
+    pub fn same(&self, other: &Exp) -> bool {
+        match (self, other) {
+        | (Exp::Nil(_), Exp::Nil(_))       => true,
+        | (Exp::Int(l, _), Exp::Int(r, _)) => l == r,
+        | (Exp::Str(l, _), Exp::Str(r, _)) => l == r,
+        | (Exp::Var(l, _), Exp::Var(r, _)) => l.same(r),
+        | (Exp::Neg(l, _), Exp::Neg(r, _)) => l.same(r),
+        | (Exp::Bin { lhs: ll, op: lo, rhs: lr, .. }, Exp::Bin { lhs: rl, op: ro, rhs: rr, .. }) => {
+            lo == ro && ll.same(rl) && lr.same(rr)
+        },
+        | _ => false,
+        }
+    }
+
+    /// Whether evaluating this expression never completes normally, either
+    /// by looping forever or by calling `exit` on every path. `exit` is
+    /// whether calls to `exit` still reach the runtime's, since a user
+    /// function can shadow it.
+    pub fn diverges(&self, exit: bool) -> bool {
+        match self {
+        | Exp::Break(_)
+        | Exp::Nil(_)
+        | Exp::Int(_, _)
+        | Exp::Str(_, _)              => false,
+        | Exp::Var(var, _)            => var.diverges(exit),
+        | Exp::Call { name, args, .. } => {
+            (exit && *name == store("exit"))
+            || args.iter().any(|arg| arg.diverges(exit))
+        },
+        | Exp::Neg(exp, _)            => exp.diverges(exit),
+        | Exp::Bin { lhs, op, rhs, .. } => match op {
+            | Binop::LAnd | Binop::LOr => lhs.diverges(exit),
+            | _                        => lhs.diverges(exit) || rhs.diverges(exit),
+        },
+        | Exp::Rec { fields, .. }     => fields.iter().any(|field| field.exp.diverges(exit)),
+        | Exp::Seq(exps, _)           => exps.iter().any(|exp| exp.diverges(exit)),
+        | Exp::Ass { name, exp, .. }  => name.diverges(exit) || exp.diverges(exit),
+        | Exp::If { guard, then, or, .. } => {
+            guard.diverges(exit)
+            || or.as_ref().map_or(false, |or| then.diverges(exit) && or.diverges(exit))
+        },
+        | Exp::While { guard, body, .. } => {
+            guard.diverges(exit)
+            || match **guard {
+                | Exp::Int(n, _) => n != 0 && !body.breaks(),
+                | _              => false,
+            }
+        },
+        | Exp::For { lo, hi, .. }     => lo.diverges(exit) || hi.diverges(exit),
+        | Exp::Let { decs, body, .. } => {
+            let mut exit = exit;
+            for dec in decs {
+                match dec {
+                | Dec::Var { init, .. } => if init.diverges(exit) { return true },
+                | Dec::Fun(funs, _)     => exit &= funs.iter().all(|fun| fun.name != store("exit")),
+                | Dec::Type(_, _)       => (),
+                }
+            }
+            body.diverges(exit)
+        },
+        | Exp::Arr { size, init, .. } => size.diverges(exit) || init.diverges(exit),
+        }
+    }
+
+    /// Whether this expression contains a `break` out of an enclosing loop.
+    fn breaks(&self) -> bool {
+        match self {
+        | Exp::Break(_)               => true,
+        | Exp::Nil(_)
+        | Exp::Int(_, _)
+        | Exp::Str(_, _)              => false,
+        | Exp::Var(var, _)            => var.breaks(),
+        | Exp::Call { args, .. }      => args.iter().any(|arg| arg.breaks()),
+        | Exp::Neg(exp, _)            => exp.breaks(),
+        | Exp::Bin { lhs, rhs, .. }   => lhs.breaks() || rhs.breaks(),
+        | Exp::Rec { fields, .. }     => fields.iter().any(|field| field.exp.breaks()),
+        | Exp::Seq(exps, _)           => exps.iter().any(|exp| exp.breaks()),
+        | Exp::Ass { name, exp, .. }  => name.breaks() || exp.breaks(),
+        | Exp::If { guard, then, or, .. } => {
+            guard.breaks()
+            || then.breaks()
+            || or.as_ref().map_or(false, |or| or.breaks())
+        },
+        | Exp::While { guard, .. }    => guard.breaks(),
+        | Exp::For { lo, hi, .. }     => lo.breaks() || hi.breaks(),
+        | Exp::Let { decs, body, .. } => {
+            decs.iter().any(|dec| match dec {
+                | Dec::Var { init, .. } => init.breaks(),
+                | Dec::Fun(_, _)
+                | Dec::Type(_, _)       => false,
+            })
+            || body.breaks()
+        },
+        | Exp::Arr { size, init, .. } => size.breaks() || init.breaks(),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Binop {
     Add,
@@ -497,6 +683,14 @@ impl DisplayIndent for Var {
 // )
 // ```
 //
+// Calls without arguments leave out the empty list:
+//
+// ```
+// (
+//   call <NAME>
+// )
+// ```
+//
 // ### `Exp::Neg`
 //
 // ```
@@ -617,7 +811,7 @@ impl DisplayIndent for Exp {
         | Exp::Nil(_)      => { indent!(fmt, level, "nil"); return Ok(()) },
         | Exp::Var(var, _) => { var.display_indent(level, fmt)?; return Ok(()) },
         | Exp::Int(n, _)   => { indent!(fmt, level, n); return Ok(()) },
-        | Exp::Str(s, _)   => { indent!(fmt, level, format!("\"{}\"", s)); return Ok(()) },
+        | Exp::Str(s, _)   => { indent!(fmt, level, format!("\"{}\"", escape(s))); return Ok(()) },
         | _                => (),
         };
 
@@ -626,10 +820,12 @@ impl DisplayIndent for Exp {
             match self {
             | Exp::Call { name, args, .. } => {
                 indent!(fmt, level, format!("call {}", name));
-                enclose!(fmt, level, {
-                    let level = level + 1;
-                    for a in args { a.display_indent(level, fmt)?; }
-                });
+                if !args.is_empty() {
+                    enclose!(fmt, level, {
+                        let level = level + 1;
+                        for a in args { a.display_indent(level, fmt)?; }
+                    });
+                }
             },
             | Exp::Neg(exp, _) => {
                 indent!(fmt, level, "-");
@@ -705,9 +901,8 @@ impl DisplayIndent for Exp {
     }
 }
 
-impl DisplayIndent for Binop {
-
-    fn display_indent(&self, level: usize, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+impl fmt::Display for Binop {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         let sym = match self {
         | Binop::Add  => "+",
         | Binop::Sub  => "-",
@@ -724,7 +919,14 @@ impl DisplayIndent for Binop {
         | Binop::LOr  => "|",
         };
 
-        indent!(fmt, level, sym);
+        write!(fmt, "{}", sym)
+    }
+}
+
+impl DisplayIndent for Binop {
+
+    fn display_indent(&self, level: usize, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        indent!(fmt, level, self);
         Ok(())
     }
 }
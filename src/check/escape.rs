@@ -22,7 +22,10 @@ fn trap_name(depth: usize, escaped: &mut Escaped, name: &Symbol, escape: &mut bo
 fn trap_var(depth: usize, escaped: &mut Escaped, var: &mut Var) {
     match var {
     | Var::Simple(name, _) => {
-        escaped.insert(*name, depth);
+
+        // Keep the deepest usage, which decides whether the variable escapes
+        let usage = escaped.entry(*name).or_insert(depth);
+        if *usage < depth { *usage = depth }
     },
     | Var::Field(rec, _, _, _) => {
         trap_var(depth, escaped, rec)
@@ -1,11 +1,27 @@
 pub mod context;
 mod check;
 mod escape;
+mod overflow;
 
 use ir;
 use ast;
 use error;
+use config::{CONVENTION, ENTRY};
+use operand::{Convention, Label};
 
-pub fn check(mut ast: ast::Exp) -> Result<ir::Unit, error::Error> {
-    self::check::Checker::check(&mut ast)
+pub use self::check::Checker;
+pub use self::overflow::overflow;
+
+pub fn check(ast: ast::Exp) -> Result<(ir::Unit, Vec<error::Warning>), error::Error> {
+    check_with_entry(ast, Label::from_fixed(ENTRY))
+}
+
+pub fn check_with_entry(ast: ast::Exp, entry: Label) -> Result<(ir::Unit, Vec<error::Warning>), error::Error> {
+    check_with(ast, entry, CONVENTION)
+}
+
+/// Checks and translates `ast` into functions that receive their
+/// arguments as passed by `convention`.
+pub fn check_with(mut ast: ast::Exp, entry: Label, convention: Convention) -> Result<(ir::Unit, Vec<error::Warning>), error::Error> {
+    self::check::Checker::check(&mut ast, entry, convention)
 }
@@ -0,0 +1,139 @@
+use simple_symbol::{store, Symbol};
+
+use ast::*;
+use check::Checker;
+use error::{Error, TypeError};
+use span::IntoSpan;
+
+/// Reject arithmetic on constants whose result doesn't fit in an `i32`.
+/// Overflow involving any runtime value is left alone.
+pub fn overflow(ast: &Exp) -> Result<(), Error> {
+    overflow_exp(ast, true).map(|_| ())
+}
+
+/// Whether `name` is `not`, which declarations can shadow.
+fn is_not(name: &Symbol) -> bool {
+    *name == store("not")
+}
+
+fn overflow_var(var: &Var, not: bool) -> Result<(), Error> {
+    match var {
+    | Var::Simple(_, _) => Ok(()),
+    | Var::Field(rec, _, _, _) => overflow_var(rec, not),
+    | Var::Index(arr, index, _) => {
+        overflow_var(arr, not)?;
+        overflow_exp(index, not).map(|_| ())
+    },
+    }
+}
+
+/// Constant value of `exp`, folded bottom-up the same way as `Checker::eval_const`,
+/// where `not` is whether the built-in `not` is still in scope.
+fn overflow_exp(exp: &Exp, not: bool) -> Result<Option<i32>, Error> {
+
+    macro_rules! recurse {
+        ($exp:expr) => { overflow_exp($exp, not)? }
+    }
+
+    let overflowed = || Err(Error::semantic(exp.into_span(), TypeError::ConstantOverflow));
+
+    let value = match exp {
+    | Exp::Int(n, _) => Some(*n),
+    | Exp::Neg(neg, _) => match recurse!(neg) {
+        | Some(n) => match n.checked_neg() {
+            | Some(n) => Some(n),
+            | None    => return overflowed(),
+        },
+        | None => None,
+    },
+    | Exp::Bin{lhs, op, rhs, ..} => match (recurse!(lhs), recurse!(rhs)) {
+        | (Some(l), Some(r)) => match (Checker::apply(*op, l, r), op) {
+            | (Some(n), _) => Some(n),
+
+            // Division by zero traps at runtime instead
+            | (None, Binop::Div)
+            | (None, Binop::Mod) if r == 0 => None,
+            | (None, _) => return overflowed(),
+        },
+        | _ => None,
+    },
+    | Exp::Nil(_)
+    | Exp::Str(_, _)
+    | Exp::Break(_) => None,
+    | Exp::Var(var, _) => {
+        overflow_var(var, not)?;
+        None
+    },
+    | Exp::Call{name, args, ..} => {
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args { values.push(recurse!(arg)) }
+        match values.as_slice() {
+        | [Some(n)] if not && is_not(name) => Some((*n == 0) as i32),
+        | _                                => None,
+        }
+    },
+    | Exp::Rec{fields, ..} => {
+        for field in fields { recurse!(&*field.exp); }
+        None
+    },
+    | Exp::Seq(statements, _) => {
+        let mut value = None;
+        for statement in statements { value = recurse!(statement) }
+        if statements.len() == 1 { value } else { None }
+    },
+    | Exp::Ass{name, exp, ..} => {
+        overflow_var(name, not)?;
+        recurse!(exp);
+        None
+    },
+    | Exp::If{guard, then, or, ..} => {
+        recurse!(guard);
+        recurse!(then);
+        if let Some(or) = or { recurse!(or); }
+        None
+    },
+    | Exp::While{guard, body, ..} => {
+        recurse!(guard);
+        recurse!(body);
+        None
+    },
+    | Exp::For{name, lo, hi, body, ..} => {
+        recurse!(lo);
+        recurse!(hi);
+        overflow_exp(body, not && !is_not(name))?;
+        None
+    },
+    | Exp::Let{decs, body, ..} => {
+        let mut not = not;
+        for dec in decs { not = overflow_dec(dec, not)? }
+        overflow_exp(body, not)?;
+        None
+    },
+    | Exp::Arr{size, init, ..} => {
+        recurse!(size);
+        recurse!(init);
+        None
+    },
+    };
+
+    Ok(value)
+}
+
+/// Checks `dec`, returning whether the built-in `not` is still in scope after it.
+fn overflow_dec(dec: &Dec, not: bool) -> Result<bool, Error> {
+    match dec {
+    | Dec::Fun(funs, _) => {
+        let not = not && !funs.iter().any(|fun| is_not(&fun.name));
+        for fun in funs {
+            let not = not && !fun.args.iter().any(|arg| is_not(&arg.name));
+            overflow_exp(&fun.body, not)?;
+        }
+        Ok(not)
+    },
+    | Dec::Var{name, init, ..} => {
+        overflow_exp(init, not)?;
+        Ok(not && !is_not(name))
+    },
+    | Dec::Type(_, _) => Ok(not),
+    }
+}
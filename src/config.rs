@@ -1 +1,14 @@
+use operand::{Convention, Strings};
+
 pub const WORD_SIZE: i32 = 8;
+
+pub const CONVENTION: Convention = Convention::SystemV;
+
+/// String layout expected by the runtime, which decides how `size` is lowered.
+pub const STRINGS: Strings = Strings::Prefixed;
+
+/// Most stack slots the allocator may give a single function's temps.
+pub const MAX_SPILLS: usize = 4096;
+
+/// Symbol for the top-level expression's function.
+pub const ENTRY: &str = "main";
@@ -0,0 +1,51 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use asm;
+use operand::Reg;
+
+static BUILD: AtomicUsize = AtomicUsize::new(0);
+
+/// Assembles `unit` with the system `as` and links it against `runtime`
+/// (either `libtiger.c` or a compiled `libtiger.a`) with `cc`, returning
+/// the path of the resulting executable. Intermediate files are written
+/// to a fresh directory under the system temp directory.
+pub fn build(unit: &asm::Unit<Reg>, runtime: &Path) -> io::Result<PathBuf> {
+    let dir = env::temp_dir().join(format!(
+        "tigerc-{}-{}",
+        process::id(),
+        BUILD.fetch_add(1, Ordering::SeqCst),
+    ));
+    fs::create_dir_all(&dir)?;
+
+    let source = dir.join("main.s");
+    let object = dir.join("main.o");
+    let binary = dir.join("main");
+
+    write!(File::create(&source)?, "{}", unit)?;
+
+    run(Command::new("as")
+        .arg("-o").arg(&object)
+        .arg(&source))?;
+
+    run(Command::new("cc")
+        .arg("-no-pie")
+        .arg("-o").arg(&binary)
+        .arg(&object)
+        .arg(runtime))?;
+
+    Ok(binary)
+}
+
+fn run(command: &mut Command) -> io::Result<()> {
+    let output = command.output()?;
+    if output.status.success() { return Ok(()) }
+    Err(io::Error::new(
+        io::ErrorKind::Other,
+        format!("{:?} failed: {}", command, String::from_utf8_lossy(&output.stderr)),
+    ))
+}
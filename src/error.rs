@@ -1,18 +1,33 @@
 use codespan::{ByteIndex, CodeMap};
 use codespan_reporting::{Diagnostic, Label};
 use lalrpop_util::ParseError as LalrpopError;
+use simple_symbol::Symbol;
 
-use token::Token;
+use ast;
+use token::{describe, Token};
 use span::Span;
+use ty::Ty;
+use operand;
 
 #[derive(Debug, Clone)]
 pub struct Error {
     pub span: Span,
     pub kind: Kind,
+    pub secondary: Vec<Span>,
 }
 
 impl Error {
     pub fn to_debug(&self, files: &CodeMap) -> String {
+        if let Kind::Internal(err) = &self.kind {
+            let message: String = err.into();
+            return format!("internal error: {}", message)
+        }
+
+        if let Kind::Backend(err) = &self.kind {
+            let message: String = err.into();
+            return format!("backend error: {}", message)
+        }
+
         let file = files.find_file(self.span.start()).unwrap();
         let (row, col) = file.location(self.span.start()).unwrap();
 
@@ -20,6 +35,8 @@ impl Error {
         | Kind::Lexical(_)   => "lexical",
         | Kind::Syntactic(_) => "syntactic",
         | Kind::Semantic(_)  => "semantic",
+        | Kind::Internal(_)
+        | Kind::Backend(_)   => unreachable!(),
         };
 
         let message: String = (&self.kind).into();
@@ -27,41 +44,95 @@ impl Error {
     }
 
     pub fn lexical(start: ByteIndex, end: ByteIndex, err: LexError) -> Self {
-        Error { span: Span::new(start, end), kind: Kind::Lexical(err), }
+        Error { span: Span::new(start, end), kind: Kind::Lexical(err), secondary: Vec::new() }
     }
 
     pub fn syntactic(start: ByteIndex, end: ByteIndex, err: ParseError) -> Self {
-        Error { span: Span::new(start, end), kind: Kind::Syntactic(err), }
+        Error { span: Span::new(start, end), kind: Kind::Syntactic(err), secondary: Vec::new() }
     }
 
     pub fn semantic(span: Span, err: TypeError) -> Self {
-        Error { span, kind: Kind::Semantic(err), }
+        Error { span, kind: Kind::Semantic(err), secondary: Vec::new() }
+    }
+
+    pub fn internal(err: IRError) -> Self {
+        Error { span: Span::default(), kind: Kind::Internal(err), secondary: Vec::new() }
+    }
+
+    pub fn backend(err: BackendError) -> Self {
+        Error { span: Span::default(), kind: Kind::Backend(err), secondary: Vec::new() }
+    }
+
+    pub fn with_secondary(mut self, span: Span) -> Self {
+        self.secondary.push(span);
+        self
     }
 }
 
 impl Into<Diagnostic> for Error {
     fn into(self) -> Diagnostic {
-        let Error { span, kind } = self;
+        let Error { span, kind, secondary } = self;
 
-        let labels = vec![Label::new_primary(span)];
+        if let Kind::Internal(_) = &kind {
+            return Diagnostic::new_bug(&kind)
+        }
 
-        if let Kind::Semantic(err) = &kind {
-            match err {
-            | TypeError::Break => (),
-            |
-            _ => (),
-            };
-        };
+        if let Kind::Backend(_) = &kind {
+            return Diagnostic::new_error(&kind)
+        }
+
+        let labels = Some(Label::new_primary(span)).into_iter()
+            .chain(secondary.into_iter().map(Label::new_secondary))
+            .collect::<Vec<_>>();
 
         Diagnostic::new_error(&kind).with_labels(labels)
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Warning {
+    pub span: Span,
+    pub kind: WarningKind,
+}
+
+impl Warning {
+    pub fn new(span: Span, kind: WarningKind) -> Self {
+        Warning { span, kind }
+    }
+}
+
+impl Into<Diagnostic> for Warning {
+    fn into(self) -> Diagnostic {
+        Diagnostic::new_warning(&self.kind).with_label(Label::new_primary(self.span))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarningKind {
+    DiscardedValue,
+    ConstantComparison(bool),
+    UnusedFunction(Symbol),
+    UnreadVariable(Symbol),
+}
+
+impl <'a> Into<String> for &'a WarningKind {
+    fn into(self) -> String {
+        match self {
+        | WarningKind::DiscardedValue            => "Value of expression is discarded.".to_string(),
+        | WarningKind::ConstantComparison(value) => format!("Comparison is always {}.", value),
+        | WarningKind::UnusedFunction(name)      => format!("Function '{}' is never called.", name),
+        | WarningKind::UnreadVariable(name)      => format!("Variable '{}' is assigned but never read.", name),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Kind {
     Lexical(LexError),
     Syntactic(ParseError),
     Semantic(TypeError),
+    Internal(IRError),
+    Backend(BackendError),
 }
 
 impl <'a> Into<String> for &'a Kind {
@@ -70,6 +141,8 @@ impl <'a> Into<String> for &'a Kind {
         | Kind::Lexical(err)   => err.into(),
         | Kind::Syntactic(err) => err.into(),
         | Kind::Semantic(err)  => err.into(),
+        | Kind::Internal(err)  => err.into(),
+        | Kind::Backend(err)   => err.into(),
         }
     }
 }
@@ -78,15 +151,19 @@ impl <'a> Into<String> for &'a Kind {
 pub enum LexError {
     Comment,
     Integer,
+    Escape,
+    Unterminated,
     Unknown,
 }
 
 impl <'a> Into<String> for &'a LexError {
     fn into(self) -> String {
         match self {
-        | LexError::Comment => "Comments must begin with [/*].".to_string(),
-        | LexError::Integer => "Integers must be between −2,147,483,648 and 2,147,483,647.".to_string(),
-        | LexError::Unknown => "Unknown token.".to_string(),
+        | LexError::Comment      => "Comments must begin with [/*].".to_string(),
+        | LexError::Integer      => "Integers must be between −2,147,483,648 and 2,147,483,647.".to_string(),
+        | LexError::Escape       => "Invalid escape sequence in string.".to_string(),
+        | LexError::Unterminated => "Unterminated string.".to_string(),
+        | LexError::Unknown      => "Unknown token.".to_string(),
         }
     }
 }
@@ -94,57 +171,67 @@ impl <'a> Into<String> for &'a LexError {
 #[derive(Debug, Clone)]
 pub enum ParseError {
     Extra,
-    Unexpected,
-    EOF,
+    Unexpected(String, Vec<String>),
+    EOF(Vec<String>),
 }
 
 #[derive(Debug, Clone)]
 pub enum TypeError {
     Break,
 
-    CallCountMismatch,
-    CallTypeMismatch,
+    ArityMismatch(Symbol, usize, usize),
+    ArgTypeMismatch(Symbol, usize, Ty, Ty),
     UnboundFun,
     NotFun,
-    ReturnMismatch,
+    ReturnMismatch(Symbol, Ty, Ty),
 
     Neg,
+    ConstantOverflow,
 
-    DecConflict,
+    FunConflict(Symbol),
+    TypeConflict(Symbol),
 
-    BinaryMismatch,
+    BinaryMismatch(ast::Binop, Ty, Ty),
     BinaryUnit,
     BinaryNil,
+    DivByZero,
 
     UnboundRecord,
-    NotRecord,
+    NotRecord(Ty),
     FieldCountMismatch,
     FieldTypeMismatch,
     FieldNameMismatch,
+    RecordTooLarge,
 
     UnusedExp,
 
     UnboundVar,
+    UseBeforeInit,
     NotVar,
     VarMismatch,
+    DecMismatch(Symbol, Ty, Ty),
 
     GuardMismatch,
+    AssignmentGuard,
     BranchMismatch,
     UnusedBranch,
 
     UnusedWhileBody,
 
-    ForBound,
+    ForBound(&'static str, Ty),
     UnusedForBody,
 
     UnboundArr,
     NotArr,
     ArrMismatch,
     ArrSize,
+    NegativeArraySize,
 
-    UnboundType,
+    UnboundType(Symbol),
+    UnresolvedType(Symbol),
 
-    UnboundField,
+    UnboundField(Symbol, Option<Symbol>),
+    DuplicateField(Symbol),
 
     IndexMismatch,
 
@@ -153,16 +240,36 @@ pub enum TypeError {
     NotIndirect,
 }
 
+#[derive(Debug, Clone)]
+pub enum IRError {
+    ESeq(operand::Label),
+    Seq(operand::Label),
+    Exp(operand::Label),
+    Jump(operand::Label),
+    Call(operand::Label),
+    MissingLabel(operand::Label, operand::Label),
+    CJump(operand::Label, operand::Label),
+    UseBeforeDef(operand::Label, operand::Temp, String),
+}
+
+#[derive(Debug, Clone)]
+pub enum BackendError {
+    TooManySpills(operand::Label, usize, usize),
+}
+
 impl Into<Error> for LalrpopError<ByteIndex, Token, Error> {
     fn into(self) -> Error {
         match self {
         | LalrpopError::User { .. }
         | LalrpopError::InvalidToken { .. }                   => panic!("Internal error: should be covered by custom lexer"),
         | LalrpopError::ExtraToken { token: (start, _, end) } => Error::syntactic(start, end, ParseError::Extra),
-        | LalrpopError::UnrecognizedToken { token, .. }       => {
+        | LalrpopError::UnrecognizedToken { token, expected } => {
+            let expected = expected.iter()
+                .map(|name| describe(name))
+                .collect::<Vec<_>>();
             match token {
-            | None => Error::syntactic(0.into(), 0.into(), ParseError::EOF),
-            | Some((start, _, end)) => Error::syntactic(start, end, ParseError::Unexpected),
+            | None                      => Error::syntactic(0.into(), 0.into(), ParseError::EOF(expected)),
+            | Some((start, token, end)) => Error::syntactic(start, end, ParseError::Unexpected(token.describe().to_string(), expected)),
             }
         },
         }
@@ -172,51 +279,107 @@ impl Into<Error> for LalrpopError<ByteIndex, Token, Error> {
 impl <'a> Into<String> for &'a ParseError {
     fn into(self) -> String {
         match self {
-        | ParseError::Extra      => "Extra tokens encountered.".to_string(),
-        | ParseError::Unexpected => "Unexpected token encountered.".to_string(),
-        | ParseError::EOF        => "Unexpected EOF encountered.".to_string(),
+        | ParseError::Extra                       => "Extra tokens encountered.".to_string(),
+        | ParseError::Unexpected(found, expected) => format!("Unexpected {} encountered{}.", found, expecting(expected)),
+        | ParseError::EOF(expected)               => format!("Unexpected EOF encountered{}.", expecting(expected)),
         }
     }
 }
 
+/// Lists the tokens a parse error expected, if any, to end its message.
+fn expecting(expected: &[String]) -> String {
+    match expected {
+    | []         => String::new(),
+    | [expected] => format!(", expected {}", expected),
+    | expected   => format!(", expected one of {}", expected.join(", ")),
+    }
+}
+
 impl <'a> Into<String> for &'a TypeError {
     fn into(self) -> String {
         match self {
         | TypeError::Break              => "Cannot break outside of a loop.".to_string(),
-        | TypeError::CallCountMismatch  => "Wrong number of arguments to function.".to_string(),
-        | TypeError::CallTypeMismatch   => "Wrong type of argument to function.".to_string(),
+        | TypeError::ArityMismatch(name, expected, found) => {
+            let plural = if *expected == 1 { "" } else { "s" };
+            format!("Function '{}' expects {} argument{}, found {}.", name, expected, plural, found)
+        },
+        | TypeError::ArgTypeMismatch(name, index, expected, found) => {
+            format!("Argument {} of '{}': expected {}, found {}.", index, name, expected, found)
+        },
         | TypeError::UnboundFun         => "Could not find function.".to_string(),
         | TypeError::NotFun             => "Not a function.".to_string(),
-        | TypeError::ReturnMismatch     => "Function return type doesn't match body.".to_string(),
-        | TypeError::DecConflict        => "Conflicting declarations in mutually recursive group.".to_string(),
+        | TypeError::ReturnMismatch(name, expected, found) => {
+            format!("Function '{}' returns {}, but its body has type {}.", name, expected, found)
+        },
+        | TypeError::FunConflict(name)  => format!("Function '{}' declared twice in the same block.", name),
+        | TypeError::TypeConflict(name) => format!("Type '{}' declared twice in the same block.", name),
         | TypeError::Neg                => "Can only negate integers.".to_string(),
-        | TypeError::BinaryMismatch     => "Wrong arguments for binary operator.".to_string(),
+        | TypeError::ConstantOverflow   => "Constant arithmetic overflows a 32-bit integer.".to_string(),
+        | TypeError::BinaryMismatch(op, lhs, rhs) => {
+            format!("Cannot apply '{}' to {} and {}.", op, lhs, rhs)
+        },
         | TypeError::BinaryUnit         => "Cannot operate on unit value".to_string(),
         | TypeError::BinaryNil          => "Cannot compare two nil values".to_string(),
+        | TypeError::DivByZero          => "Division by zero.".to_string(),
         | TypeError::UnboundRecord      => "Could not find record.".to_string(),
-        | TypeError::NotRecord          => "Not a record.".to_string(),
+        | TypeError::NotRecord(ty)      => format!("Expected a record, found {}.", ty),
         | TypeError::FieldCountMismatch => "Number of fields doesn't match record type.".to_string(),
         | TypeError::FieldNameMismatch  => "Incorrect name for field.".to_string(),
         | TypeError::FieldTypeMismatch  => "Incorrect type for field.".to_string(),
+        | TypeError::RecordTooLarge     => "Record has too many fields to allocate.".to_string(),
         | TypeError::UnusedExp          => "Unused expression.".to_string(),
         | TypeError::UnboundVar         => "Could not find variable.".to_string(),
+        | TypeError::UseBeforeInit      => "Variable used in its own initialization.".to_string(),
         | TypeError::NotVar             => "Expected variable.".to_string(),
         | TypeError::VarMismatch        => "Incorrect type for assignment.".to_string(),
+        | TypeError::DecMismatch(name, expected, found) => {
+            format!("Variable '{}' declared as {} but initialized with {}.", name, expected, found)
+        },
         | TypeError::GuardMismatch      => "Guard expression must be an integer.".to_string(),
+        | TypeError::AssignmentGuard    => "Guard expression must be an integer, not an assignment. Did you mean '=' instead of ':='?".to_string(),
         | TypeError::BranchMismatch     => "Branches must return the same type.".to_string(),
         | TypeError::UnusedBranch       => "If branches must return unit.".to_string(),
         | TypeError::UnusedWhileBody    => "While body must return unit.".to_string(),
-        | TypeError::ForBound           => "For bounds must be integers.".to_string(),
+        | TypeError::ForBound(bound, ty) => format!("For-loop {} bound must be int, found {}.", bound, ty),
         | TypeError::UnusedForBody      => "For body must return unit.".to_string(),
         | TypeError::UnboundArr         => "Could not find array.".to_string(),
         | TypeError::NotArr             => "Not an array.".to_string(),
         | TypeError::ArrMismatch        => "Array initializer doesn't match array type.".to_string(),
         | TypeError::ArrSize            => "Array size must be integer.".to_string(),
-        | TypeError::UnboundType        => "Could not find type.".to_string(),
-        | TypeError::UnboundField       => "Unbound record field.".to_string(),
+        | TypeError::NegativeArraySize  => "Array size must not be negative.".to_string(),
+        | TypeError::UnboundType(name)  => format!("Could not find type '{}'.", name),
+        | TypeError::UnresolvedType(name) => format!("Type '{}' is used before its definition is known.", name),
+        | TypeError::UnboundField(field, Some(rec)) => format!("Record type '{}' has no field '{}'.", rec, field),
+        | TypeError::UnboundField(field, None)      => format!("Record has no field '{}'.", field),
+        | TypeError::DuplicateField(name) => format!("Field '{}' declared twice in the same record.", name),
         | TypeError::IndexMismatch      => "Array indices must be integers.".to_string(),
         | TypeError::UnknownNil         => "Cannot infer type for nil.".to_string(),
         | TypeError::NotIndirect        => "Recursive types must pass through arrays or records.".to_string(),
         }
     }
 }
+
+impl <'a> Into<String> for &'a IRError {
+    fn into(self) -> String {
+        match self {
+        | IRError::ESeq(fun)                => format!("ESeq expression in canonical IR of function {}.", fun),
+        | IRError::Seq(fun)                 => format!("Seq statement in canonical IR of function {}.", fun),
+        | IRError::Exp(fun)                 => format!("Exp statement in canonical IR of function {}.", fun),
+        | IRError::Jump(fun)                => format!("Jump to non-label in function {}.", fun),
+        | IRError::Call(fun)                => format!("Call of non-label in function {}.", fun),
+        | IRError::MissingLabel(fun, label) => format!("Jump to undefined label {} in function {}.", label, fun),
+        | IRError::CJump(fun, label)        => format!("Conditional jump in function {} does not fall through to {}.", fun, label),
+        | IRError::UseBeforeDef(fun, temp, stm) => format!("Temp {} used before definition in function {}: {}", temp, fun, stm),
+        }
+    }
+}
+
+impl <'a> Into<String> for &'a BackendError {
+    fn into(self) -> String {
+        match self {
+        | BackendError::TooManySpills(fun, spills, limit) => {
+            format!("Function {} needs {} spill slots, more than the limit of {}.", fun, spills, limit)
+        },
+        }
+    }
+}
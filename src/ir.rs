@@ -2,7 +2,8 @@ use std::fmt;
 use simple_symbol::Symbol;
 
 use asm;
-use translate::Frame;
+use span::Span;
+use translate::{Frame, Layout};
 use operand::*;
 
 #[derive(Debug)]
@@ -27,6 +28,7 @@ pub struct Function {
     pub label: Label,
     pub body: Vec<Stm>,
     pub escapes: usize,
+    pub layout: Layout,
 }
 
 impl Function {
@@ -34,6 +36,7 @@ impl Function {
         Function {
             label: frame.label,
             escapes: frame.escapes,
+            layout: frame.layout(),
             body: vec![
                 Stm::Seq(frame.prologue),
                 Stm::Move(
@@ -48,6 +51,7 @@ impl Function {
         Function {
             label: self.label,
             escapes: self.escapes,
+            layout: self.layout,
             body: f(self.body)
         }
     }
@@ -78,7 +82,7 @@ pub enum Tree {
     Cx(Cond),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Exp {
     Const(i32),
     Name(Label),
@@ -89,6 +93,24 @@ pub enum Exp {
     ESeq(Box<Stm>, Box<Exp>),
 }
 
+impl Exp {
+
+    /// Whether evaluating this expression can be observed beyond its value.
+    /// Every call is assumed to have side effects, since runtime externs
+    /// like `__prints__`, `__flush__`, and `malloc` must never be removed.
+    pub fn has_side_effects(&self) -> bool {
+        match self {
+        | Exp::Const(_)
+        | Exp::Name(_)
+        | Exp::Temp(_)            => false,
+        | Exp::Binop(lhs, _, rhs) => lhs.has_side_effects() || rhs.has_side_effects(),
+        | Exp::Mem(addr)          => addr.has_side_effects(),
+        | Exp::Call(_, _)
+        | Exp::ESeq(_, _)         => true,
+        }
+    }
+}
+
 impl From<Tree> for Exp {
     fn from(tree: Tree) -> Self {
         match tree {
@@ -124,7 +146,7 @@ impl From<Exp> for Tree {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Stm {
     Move(Exp, Exp),
     Exp(Exp),
@@ -133,6 +155,7 @@ pub enum Stm {
     Seq(Vec<Stm>),
     Label(Label),
     Comment(String),
+    Loc(Span),
 }
 
 impl From<Tree> for Stm {
@@ -143,7 +166,11 @@ impl From<Tree> for Stm {
         | Tree::Cx(gen_stm) => {
             let t = Label::from_str("TRUE_BRANCH");
             let f = Label::from_str("FALSE_BRANCH");
-            gen_stm(t, f)
+            Stm::Seq(vec![
+                gen_stm(t, f),
+                Stm::Label(f),
+                Stm::Label(t),
+            ])
         },
         }
     }
@@ -287,6 +314,7 @@ impl fmt::Display for Stm {
         | Stm::CJump(l, op, r, t, f) => write!(fmt, "(CJUMP {} {} {} {} {})", l, op, r, t, f),
         | Stm::Label(l)              => write!(fmt, "(LABEL {})", l),
         | Stm::Comment(c)            => write!(fmt, "(COMMENT {})", c),
+        | Stm::Loc(span)             => write!(fmt, "(LOC {})", span),
         | Stm::Seq(stms)                => {
             write!(fmt, "(SEQ").unwrap();
             for stm in stms {
@@ -35,14 +35,14 @@ fn is_digit(c: char) -> bool {
 
 fn is_ident_start(c: char) -> bool {
     match c {
-    | 'A' ... 'Z' | 'a' ... 'z' => true,
+    | 'A' ..= 'Z' | 'a' ..= 'z' => true,
     | _                       => false,
     }
 }
 
 fn is_ident(c: char) -> bool {
     match c {
-    | 'A' ... 'Z' | 'a' ... 'z' | '0' ... '9' | '_' => true,
+    | 'A' ..= 'Z' | 'a' ..= 'z' | '0' ..= '9' | '_' => true,
     | _                                          => false,
     }
 }
@@ -112,12 +112,84 @@ impl <'input> Lexer<'input> {
         self.take_while(start, is_digit)
     }
 
-    fn take_string(&mut self, start: ByteIndex) -> (ByteIndex, &'input str) {
-        if !self.test_peek(|c| c == '"') { return (start, "") }
+    /// Lexes a string literal, decoding escape sequences. The token's span
+    /// covers the source text, including quotes, rather than the decoded value.
+    fn take_string(&mut self, start: ByteIndex) -> Option<Result<Spanned, Error>> {
+        if !self.test_peek(|c| c == '"') { return None }
         self.skip();
-        let (end, _) = self.take_until(start, |c| c == '"');
+
+        let mut string = String::new();
+
+        loop {
+            match self.peek() {
+            | None => return error(start, self.source.span().end(), LexError::Unterminated),
+            | Some((end, '"')) => {
+                self.skip();
+                return success(start, end + ByteOffset(1), Token::Str(string))
+            },
+            | Some((escape, '\\')) => {
+                self.skip();
+                match self.take_escape() {
+                | Ok(Some(c)) => string.push(c),
+                | Ok(None)    => (),
+                | Err(err)    => {
+                    let end = self.peek().map(|(end, _)| end).unwrap_or(self.source.span().end());
+                    return error(escape, end, err)
+                },
+                }
+            },
+            | Some((_, c)) => {
+                self.skip();
+                string.push(c);
+            },
+            }
+        }
+    }
+
+    /// Decodes the escape sequence following a backslash, or `None` for
+    /// a `\f___f\` sequence of ignored whitespace.
+    fn take_escape(&mut self) -> Result<Option<char>, LexError> {
+        let c = match self.peek() {
+        | None         => return Err(LexError::Unterminated),
+        | Some((_, c)) => c,
+        };
+
         self.skip();
-        (end + ByteOffset(1), self.slice(start, end))
+
+        match c {
+        | 'n'  => Ok(Some('\n')),
+        | 't'  => Ok(Some('\t')),
+        | '"'  => Ok(Some('"')),
+        | '\\' => Ok(Some('\\')),
+        | '^'  => {
+            match self.peek() {
+            | Some((_, c @ '@' ..= '_')) => { self.skip(); Ok(Some((c as u8 - b'@') as char)) },
+            | _                          => Err(LexError::Escape),
+            }
+        },
+        | '0' ..= '9' => {
+            let mut code = c.to_digit(10).unwrap();
+            for _ in 0..2 {
+                match self.peek() {
+                | Some((_, c)) if is_digit(c) => { self.skip(); code = code * 10 + c.to_digit(10).unwrap() },
+                | _                           => return Err(LexError::Escape),
+                }
+            }
+            if code < 128 { Ok(Some(code as u8 as char)) } else { Err(LexError::Escape) }
+        },
+        | c if is_whitespace(c) => {
+            while let Some((_, c)) = self.peek() {
+                self.skip();
+                match c {
+                | '\\'                  => return Ok(None),
+                | c if is_whitespace(c) => (),
+                | _                     => return Err(LexError::Escape),
+                }
+            }
+            Err(LexError::Unterminated)
+        },
+        | _ => Err(LexError::Escape),
+        }
     }
 
 }
@@ -231,14 +303,7 @@ impl <'input> Iterator for Lexer<'input> {
                 };
 
                 // Check for literal string
-                match self.take_string(start) {
-                | (_, "")  => (),
-                | (end, _) => {
-                    // Cut off literal quotation marks
-                    let string = String::from(self.slice(start + ByteOffset(1), end - ByteOffset(1)));
-                    return success(start, end, Token::Str(string));
-                },
-                };
+                if let Some(string) = self.take_string(start) { return Some(string) }
 
                 // Failure to lex: consume until next whitespace and throw error
                 let (end, _) = self.take_until(start, is_whitespace);
@@ -25,6 +25,9 @@ pub mod assemble;
 pub mod analyze;
 pub mod optimize;
 
+#[cfg(feature = "driver")]
+pub mod driver;
+
 pub mod token;
 pub mod ast;
 pub mod ty;
@@ -39,10 +39,62 @@ struct Opt {
     #[structopt(long = "o-no-cf")]
     disable_fold: bool,
 
+    /// Write copy-propagated IR to file.
+    #[structopt(long = "propagate")]
+    propagate: bool,
+
+    /// Disable copy propagation.
+    #[structopt(long = "o-no-cp")]
+    disable_propagate: bool,
+
+    /// Write IR with common subexpressions shared to file.
+    #[structopt(long = "share")]
+    share: bool,
+
+    /// Disable common subexpression elimination.
+    #[structopt(long = "o-no-cse")]
+    disable_share: bool,
+
+    /// Write dead-code-eliminated IR to file.
+    #[structopt(long = "eliminate")]
+    eliminate: bool,
+
+    /// Disable dead code elimination.
+    #[structopt(long = "o-no-dce")]
+    disable_eliminate: bool,
+
+    /// Disable clearing runs of zeroed record fields with memset.
+    #[structopt(long = "o-no-memset")]
+    disable_clear: bool,
+
+    /// Disable lowering dense chains of equality tests to jump tables.
+    #[structopt(long = "o-no-jt")]
+    disable_switch: bool,
+
     /// Disable move coalescing.
     #[structopt(long = "o-no-mc")]
     disable_coalesce: bool,
 
+    /// Disable instruction scheduling.
+    #[structopt(long = "o-no-is")]
+    disable_schedule: bool,
+
+    /// Disable merging identical block tails.
+    #[structopt(long = "o-no-tm")]
+    disable_merge: bool,
+
+    /// Disable zeroing registers with xor.
+    #[structopt(long = "o-no-xor")]
+    disable_zero: bool,
+
+    /// Disable frame pointer omission in leaf functions.
+    #[structopt(long = "o-no-fpo")]
+    disable_omit: bool,
+
+    /// Disable pruning saves of unused callee-saved registers.
+    #[structopt(long = "o-no-csr")]
+    disable_prune: bool,
+
     /// Write intermediate reordered IR to file.
     #[structopt(long = "reorder")]
     reorder: bool, 
@@ -59,6 +111,35 @@ struct Opt {
     #[structopt(long = "tile")]
     tile: bool,
 
+    /// Emit weak stubs for runtime functions instead of linking the runtime.
+    #[structopt(long = "no-runtime")]
+    no_runtime: bool,
+
+    /// Guard each stack frame with a canary checked before returning,
+    /// and check every array index and allocation.
+    #[structopt(long = "checked")]
+    checked: bool,
+
+    /// Exit explicitly at the end of main instead of returning the result.
+    #[structopt(long = "freestanding")]
+    freestanding: bool,
+
+    /// Label for the top-level expression's function.
+    #[structopt(long = "entry", default_value = "main")]
+    entry: String,
+
+    /// Allocate through a garbage-collected runtime, registering heap-typed variables as roots.
+    #[structopt(long = "gc")]
+    gc: bool,
+
+    /// Allocate records with the zeroing `calloc`, e.g. for a garbage-collected runtime.
+    #[structopt(long = "zeroed")]
+    zeroed: bool,
+
+    /// Reject constant arithmetic that overflows a 32-bit integer.
+    #[structopt(long = "strict")]
+    strict: bool,
+
     /// Files to compile.
     #[structopt(name = "FILE", parse(from_os_str))]
     files: Vec<PathBuf>,
@@ -70,20 +151,51 @@ fn main() {
 
     for file in &opt.files {
         let mut compiler = Compiler::with_path(file)
+            .with_entry(&opt.entry)
             .with_phase(Lex::new(opt.lex))
             .with_phase(Parse::new(opt.parse))
+            .with_phase(Overflow::maybe(false, !opt.strict))
             .with_phase(Type::new(opt.type_check))
+            .with_phase(Collect::maybe(false, !opt.gc))
+            .with_phase(Zeroed::maybe(false, !opt.zeroed))
+            .with_phase(Clear::maybe(false, opt.disable_clear))
+            .with_phase(Bounds::maybe(false, !opt.checked))
+            .with_phase(NonNull::maybe(false, !opt.checked))
+            .with_phase(Exit::maybe(false, !opt.freestanding))
             .with_phase(Canonize::new(opt.canonize))
             .with_phase(Fold::maybe(opt.fold, opt.disable_fold))
+            .with_phase(Propagate::maybe(opt.propagate, opt.disable_propagate))
+            .with_phase(Share::maybe(opt.share, opt.disable_share))
+            .with_phase(Eliminate::maybe(opt.eliminate, opt.disable_eliminate))
             .with_phase(Reorder::new(opt.reorder))
+            .with_phase(Switch::maybe(false, opt.disable_switch))
+            .with_phase(Validate::new(false))
             .with_phase(Tile::new(opt.tile))
             .with_phase(CoalesceAbstract::maybe(opt.coalesce_abstract, opt.disable_coalesce))
+            .with_phase(Canary::maybe(false, !opt.checked))
             .with_phase(Trivial::new(true))
-            .with_phase(CoalesceAssembly::maybe(true, opt.disable_coalesce));
+            .with_phase(CoalesceAssembly::maybe(true, opt.disable_coalesce))
+            .with_phase(Prune::maybe(true, opt.disable_prune))
+            .with_phase(Spills::new(false))
+            .with_phase(Schedule::maybe(true, opt.disable_schedule))
+            .with_phase(Merge::maybe(true, opt.disable_merge))
+            .with_phase(Zero::maybe(true, opt.disable_zero))
+            .with_phase(Omit::maybe(true, opt.disable_omit))
+            .with_phase(Stub::maybe(true, !opt.no_runtime));
+
+        let result = compiler.run();
+
+        for warning in compiler.warnings() {
+            emit(&mut stdout, compiler.code(), &warning.into()).expect("Internal error: IO");
+        }
 
-        match compiler.run() {
+        match result {
         | Err(err) => emit(&mut stdout, compiler.code(), &err.into()).expect("Internal error: IO"),
         | _ => (),
         }
+
+        for error in compiler.errors() {
+            emit(&mut stdout, compiler.code(), &error.into()).expect("Internal error: IO");
+        }
     }
 }
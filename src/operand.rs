@@ -104,26 +104,99 @@ impl Reg {
         !self.is_callee_saved()
     }
 
-    pub fn get_argument(i: usize) -> Self {
-        match i {
-        | 0 => Reg::RDI,
-        | 1 => Reg::RSI,
-        | 2 => Reg::RDX,
-        | 3 => Reg::RCX,
-        | 4 => Reg::R8,
-        | 5 => Reg::R9,
-        | _ => panic!("Internal error: can only pass 6 arguments in registers"),
+    pub fn get_return() -> Self {
+        Reg::RAX
+    }
+}
+
+/// How string values are laid out in memory, shared with the runtime.
+/// Either way, a string points at its first character and ends in a NUL,
+/// so runtime functions can treat it as a C string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Strings {
+
+    /// Length is stored in the word before the first character, as in Appel's runtime.
+    Prefixed,
+
+    /// Length is only known by scanning for the NUL, through the runtime's `size`.
+    Terminated,
+}
+
+/// Calling convention shared with the functions we call and are called by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Convention {
+    SystemV,
+    Microsoft,
+}
+
+impl Convention {
+
+    /// Registers holding the leading arguments, in order.
+    pub fn arguments(&self) -> &'static [Reg] {
+        match self {
+        | Convention::SystemV   => &[Reg::RDI, Reg::RSI, Reg::RDX, Reg::RCX, Reg::R8, Reg::R9],
+        | Convention::Microsoft => &[Reg::RCX, Reg::RDX, Reg::R8, Reg::R9],
         }
     }
 
-    pub fn get_return() -> Self {
-        Reg::RAX
+    /// Words the caller reserves below any stack arguments,
+    /// where the callee may spill its register arguments.
+    pub fn shadow_space(&self) -> usize {
+        match self {
+        | Convention::SystemV   => 0,
+        | Convention::Microsoft => 4,
+        }
     }
+
+    pub fn is_callee_saved(&self, reg: Reg) -> bool {
+        match (self, reg) {
+        | (Convention::Microsoft, Reg::RDI)
+        | (Convention::Microsoft, Reg::RSI) => true,
+        | _                                 => reg.is_callee_saved(),
+        }
+    }
+}
+
+pub trait Operand: fmt::Display + Copy + Clone + fmt::Debug + PartialEq + Eq + hash::Hash {
+    /// Name of the operand's lowest byte, as written by `setcc`.
+    fn byte(&self) -> String;
+
+    /// Machine register the operand is fixed to, if any.
+    fn reg(&self) -> Option<Reg>;
 }
 
-pub trait Operand: fmt::Display + Copy + Clone + fmt::Debug + PartialEq + Eq + hash::Hash {}
-impl Operand for Temp {}
-impl Operand for Reg {}
+impl Operand for Temp {
+    fn byte(&self) -> String {
+        self.to_string()
+    }
+
+    fn reg(&self) -> Option<Reg> {
+        match self {
+        | Temp::Reg(reg)    => Some(*reg),
+        | Temp::Temp { .. } => None,
+        }
+    }
+}
+
+impl Operand for Reg {
+    fn reg(&self) -> Option<Reg> {
+        Some(*self)
+    }
+
+    fn byte(&self) -> String {
+        match self {
+        | Reg::RAX => "%al".to_string(),
+        | Reg::RBX => "%bl".to_string(),
+        | Reg::RCX => "%cl".to_string(),
+        | Reg::RDX => "%dl".to_string(),
+        | Reg::RBP => "%bpl".to_string(),
+        | Reg::RSP => "%spl".to_string(),
+        | Reg::RSI => "%sil".to_string(),
+        | Reg::RDI => "%dil".to_string(),
+        | reg      => format!("{}b", reg),
+        }
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Mem<T: Operand> {
@@ -25,26 +25,33 @@ pub fn coalesce_function<T: Operand>(asm: Function<T>) -> Function<T> {
             break;
         }
 
+        // Fusing a pair drops the write to the operand they share,
+        // so only do it if nothing reads that operand afterward
+        let dead = |operand| is_dead(&asm.body[i + 2..], operand);
+
         match (asm.body[i], asm.body[i + 1]) {
-        | (Asm::Mov(IR(imm, reg_a)), Asm::Mov(RM(reg_b, mem))) if reg_a == reg_b => {
+        | (Asm::Mov(IR(imm, reg_a)), Asm::Mov(RM(reg_b, mem))) if reg_a == reg_b && dead(reg_a) => {
             coalesced.push(Asm::Mov(IM(imm, mem)));
         }
-        | (Asm::Mov(IR(imm, reg_a)), Asm::Mov(RR(reg_b, reg_c))) if reg_a == reg_b => {
+        | (Asm::Mov(IR(imm, reg_a)), Asm::Mov(RR(reg_b, reg_c))) if reg_a == reg_b && dead(reg_a) => {
             coalesced.push(Asm::Mov(IR(imm, reg_c)));
         }
+        // Stores may be read later, so only the reload can be replaced
         | (Asm::Mov(IM(imm, mem_a)), Asm::Mov(MR(mem_b, reg))) if mem_a == mem_b => {
+            coalesced.push(Asm::Mov(IM(imm, mem_a)));
             coalesced.push(Asm::Mov(IR(imm, reg)));
         }
-        | (Asm::Mov(MR(mem, reg_a)), Asm::Mov(RR(reg_b, reg_c))) if reg_a == reg_b => {
+        | (Asm::Mov(MR(mem, reg_a)), Asm::Mov(RR(reg_b, reg_c))) if reg_a == reg_b && dead(reg_a) => {
             coalesced.push(Asm::Mov(MR(mem, reg_c)));
         }
         | (Asm::Mov(RM(reg_a, mem_a)), Asm::Mov(MR(mem_b, reg_b))) if mem_a == mem_b => {
+            coalesced.push(Asm::Mov(RM(reg_a, mem_a)));
             coalesced.push(Asm::Mov(RR(reg_a, reg_b)));
         }
-        | (Asm::Mov(RR(reg_a, reg_b)), Asm::Mov(RR(reg_c, reg_d))) if reg_b == reg_c => {
+        | (Asm::Mov(RR(reg_a, reg_b)), Asm::Mov(RR(reg_c, reg_d))) if reg_b == reg_c && dead(reg_b) => {
             coalesced.push(Asm::Mov(RR(reg_a, reg_d)));
         }
-        | (Asm::Mov(RR(reg_a, reg_b)), Asm::Mov(RM(reg_c, mem))) if reg_b == reg_c => {
+        | (Asm::Mov(RR(reg_a, reg_b)), Asm::Mov(RM(reg_c, mem))) if reg_b == reg_c && dead(reg_b) => {
             coalesced.push(Asm::Mov(RM(reg_a, mem)));
         }
         | _ => {
@@ -63,3 +70,90 @@ pub fn coalesce_function<T: Operand>(asm: Function<T>) -> Function<T> {
         stack_info: asm.stack_info,
     }
 }
+
+/// Whether `operand` is overwritten before being read in `rest` of its block.
+/// Fixed registers are also read by calls, returns, and division, while
+/// temps that outlive their block may be read anywhere in the function.
+fn is_dead<T: Operand>(rest: &[Asm<T>], operand: T) -> bool {
+    for asm in rest {
+        if defines(asm, operand) { return true }
+        if mentions(asm, operand) { return false }
+        match (asm, operand.reg()) {
+        | (Asm::Call(_), Some(reg)) => return reg.is_caller_saved() && !is_argument(reg),
+        | (Asm::Call(_), None)      => (),
+        | (Asm::Ret, Some(reg))     => return reg.is_caller_saved() && reg != Reg::get_return(),
+        | (Asm::Ret, None)
+        | (Asm::Trap, _)            => return true,
+        | (Asm::Mul(_), Some(reg))
+        | (Asm::Div(_, _), Some(reg))
+        | (Asm::Cqo, Some(reg))     => if reg == Reg::RAX || reg == Reg::RDX { return false },
+        | (Asm::Jmp(_), _)
+        | (Asm::Jmpi(_), _)
+        | (Asm::Jcc(_, _), _)
+        | (Asm::Label(_), _)        => return false,
+        | _                         => (),
+        }
+    }
+    true
+}
+
+/// Registers that pass arguments under any supported convention.
+fn is_argument(reg: Reg) -> bool {
+    [Convention::SystemV, Convention::Microsoft].iter()
+        .any(|convention| convention.arguments().contains(&reg))
+}
+
+fn in_mem<T: Operand>(mem: &Mem<T>, operand: T) -> bool {
+    match mem {
+    | Mem::R(reg) | Mem::RO(reg, _) => *reg == operand,
+    }
+}
+
+fn in_binary<T: Operand>(binary: &Binary<T>, operand: T) -> bool {
+    match binary {
+    | Binary::IR(_, reg)       => *reg == operand,
+    | Binary::IM(_, mem)       => in_mem(mem, operand),
+    | Binary::RM(reg, mem)
+    | Binary::MR(mem, reg)     => *reg == operand || in_mem(mem, operand),
+    | Binary::RR(reg_a, reg_b) => *reg_a == operand || *reg_b == operand,
+    }
+}
+
+fn in_unary<T: Operand>(unary: &Unary<T>, operand: T) -> bool {
+    match unary {
+    | Unary::R(reg) => *reg == operand,
+    | Unary::M(mem) => in_mem(mem, operand),
+    }
+}
+
+/// Whether `asm` names `operand` anywhere.
+fn mentions<T: Operand>(asm: &Asm<T>, operand: T) -> bool {
+    match asm {
+    | Asm::Mov(binary)
+    | Asm::Bin(_, binary)
+    | Asm::Cmp(binary)     => in_binary(binary, operand),
+    | Asm::Mul(unary)
+    | Asm::Div(_, unary)
+    | Asm::Un(_, unary)
+    | Asm::Pop(unary)
+    | Asm::Push(unary)
+    | Asm::Jmpi(unary)     => in_unary(unary, operand),
+    | Asm::Lea(mem, reg)   => *reg == operand || in_mem(mem, operand),
+    | Asm::Set(_, reg)     => *reg == operand,
+    | Asm::Movzx(src, dst) => *src == operand || *dst == operand,
+    | _                    => false,
+    }
+}
+
+/// Whether `asm` overwrites `operand` without reading it first.
+fn defines<T: Operand>(asm: &Asm<T>, operand: T) -> bool {
+    match asm {
+    | Asm::Mov(Binary::IR(_, dst))      => *dst == operand,
+    | Asm::Mov(Binary::MR(mem, dst))    => *dst == operand && !in_mem(mem, operand),
+    | Asm::Mov(Binary::RR(src, dst))
+    | Asm::Movzx(src, dst)              => *dst == operand && *src != operand,
+    | Asm::Lea(mem, dst)                => *dst == operand && !in_mem(mem, operand),
+    | Asm::Pop(Unary::R(dst))           => *dst == operand,
+    | _                                 => false,
+    }
+}
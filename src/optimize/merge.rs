@@ -0,0 +1,120 @@
+use asm::*;
+use operand::*;
+
+/// Merges identical instruction sequences at the ends of blocks that
+/// continue to the same label, e.g. the two branches of an if-else.
+/// One copy of the tail is labeled and kept in place, and the other
+/// block jumps to it instead of repeating it.
+pub fn merge(unit: Unit<Reg>) -> Unit<Reg> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(merge_function)
+            .collect()
+    }
+}
+
+pub fn merge_function(mut asm: Function<Reg>) -> Function<Reg> {
+    while let Some((keep, jump, length)) = find_tail(&asm.body) {
+        let tail = Label::from_str("TAIL");
+
+        // Edit the later position first so the earlier one stays valid
+        let jump_edit = (jump - length, jump + 1, vec![Asm::Jmp(tail)]);
+        let keep_edit = (keep - length, keep - length, vec![Asm::Label(tail)]);
+        let mut edits = vec![jump_edit, keep_edit];
+        edits.sort_by_key(|(start, _, _)| *start);
+
+        for (start, end, replacement) in edits.into_iter().rev() {
+            asm.body.splice(start..end, replacement);
+        }
+    }
+    asm
+}
+
+/// Instructions that can be moved between blocks without changing
+/// what they do: anything that doesn't mark or transfer control.
+fn is_mergeable(asm: &Asm<Reg>) -> bool {
+    match asm {
+    | Asm::Jmp(_)
+    | Asm::Jmpi(_)
+    | Asm::Jcc(_, _)
+    | Asm::Label(_)
+    | Asm::Comment(_)
+    | Asm::Loc(_)
+    | Asm::Direct(_)
+    | Asm::Ret
+    | Asm::Trap => false,
+    | _         => true,
+    }
+}
+
+/// Finds the longest common tail between two blocks continuing to the same
+/// label, as the end of the block that keeps it, the index of the other's
+/// jump, and the tail's length.
+fn find_tail(body: &[Asm<Reg>]) -> Option<(usize, usize, usize)> {
+    let mut best: Option<(usize, usize, usize)> = None;
+
+    for (index, asm) in body.iter().enumerate() {
+        let label = match asm {
+        | Asm::Label(label) => *label,
+        | _                 => continue,
+        };
+
+        // Blocks jumping to the label, which end just before their jump
+        let jumps = body.iter()
+            .enumerate()
+            .filter(|(_, asm)| **asm == Asm::Jmp(label))
+            .map(|(jump, _)| jump)
+            .collect::<Vec<_>>();
+
+        // Block falling through into the label keeps its tail in place if it can
+        let falls = index > 0 && match body[index - 1] {
+        | Asm::Jmp(_) | Asm::Jmpi(_) | Asm::Direct(_) | Asm::Ret | Asm::Trap => false,
+        | _                                                                => true,
+        };
+
+        let mut ends = jumps.clone();
+        if falls { ends.insert(0, index) }
+
+        for keep in &ends {
+            for jump in jumps.iter().filter(|jump| *jump != keep) {
+                let length = common_tail(body, *keep, *jump);
+                if length > 0 && best.is_none_or(|(_, _, best)| length > best) {
+                    best = Some((*keep, *jump, length));
+                }
+            }
+        }
+    }
+
+    best
+}
+
+/// Length of the common mergeable suffix of the blocks ending at `a` and `b`.
+/// Each block's end is a label or jump, so the two suffixes never overlap.
+/// Later passes assume flags don't survive a jump, so the suffix can't read
+/// flags before writing them.
+fn common_tail(body: &[Asm<Reg>], a: usize, b: usize) -> usize {
+    let mut length = 0;
+    let mut longest = 0;
+    let mut reads_flags = false;
+    while length < a && length < b {
+        let (x, y) = (&body[a - length - 1], &body[b - length - 1]);
+        if x != y || !is_mergeable(x) { break }
+        length += 1;
+
+        match x {
+        | Asm::Set(_, _)            => reads_flags = true,
+        | Asm::Un(Unop::Not, _)     => (),
+        | Asm::Cmp(_)
+        | Asm::Bin(_, _)
+        | Asm::Mul(_)
+        | Asm::Div(_, _)
+        | Asm::Un(_, _)
+        | Asm::Call(_)              => reads_flags = false,
+        | _                         => (),
+        }
+
+        if !reads_flags { longest = length }
+    }
+    longest
+}
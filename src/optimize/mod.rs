@@ -1,3 +1,13 @@
 mod coalesce;
+mod merge;
+mod omit;
+mod prune;
+mod schedule;
+mod zero;
 
 pub use self::coalesce::coalesce;
+pub use self::merge::merge;
+pub use self::omit::omit;
+pub use self::prune::prune;
+pub use self::schedule::schedule;
+pub use self::zero::zero;
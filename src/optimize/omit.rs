@@ -0,0 +1,123 @@
+use simple_symbol::{store, Symbol};
+
+use asm::*;
+use config::WORD_SIZE;
+use operand::*;
+
+/// Omits the frame pointer from leaf functions, addressing the frame
+/// relative to RSP instead. Without calls or pushes in the body, RSP stays
+/// put between the prologue's adjustment and the epilogue's, so every
+/// RBP offset is a fixed distance from it.
+pub fn omit(unit: Unit<Reg>) -> Unit<Reg> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(omit_function)
+            .collect()
+    }
+}
+
+pub fn omit_function(asm: Function<Reg>) -> Function<Reg> {
+
+    let frame = asm.body.iter()
+        .filter_map(|stm| match stm {
+            | Asm::Bin(Binop::Sub, Binary::IR(Imm::Int(size), Reg::RSP)) => Some(*size),
+            | _ => None,
+        })
+        .next()
+        .unwrap_or(0);
+
+    // The frame grows by a word to take the saved RBP's place, which keeps
+    // RSP aligned and every slot at the same distance from the return address
+    let offset = if frame > 0 { frame } else { -WORD_SIZE };
+    let mut body = Vec::with_capacity(asm.body.len());
+
+    for stm in &asm.body {
+        let stm = match stm {
+        | Asm::Bin(op, Binary::IR(Imm::Int(size), Reg::RSP)) if *size == frame => {
+            Some(Asm::Bin(*op, Binary::IR(Imm::Int(size + WORD_SIZE), Reg::RSP)))
+        },
+        | Asm::Push(Unary::R(Reg::RBP))
+        | Asm::Pop(Unary::R(Reg::RBP))
+        | Asm::Mov(Binary::RR(Reg::RSP, Reg::RBP))
+        | Asm::Mov(Binary::RR(Reg::RBP, Reg::RSP)) => continue,
+        | Asm::Call(_)
+        | Asm::Push(_)
+        | Asm::Pop(_) => return asm,
+        | _ => omit_stm(stm, offset),
+        };
+
+        match stm {
+        | Some(stm) => body.push(stm),
+        | None      => return asm,
+        }
+    }
+
+    Function {
+        body,
+        stack_info: asm.stack_info,
+    }
+}
+
+/// Rewrites RBP-relative memory to the same slot relative to RSP.
+fn omit_mem(mem: Mem<Reg>, offset: i32) -> Mem<Reg> {
+    match mem {
+    | Mem::R(Reg::RBP)     => Mem::RO(Reg::RSP, offset),
+    | Mem::RO(Reg::RBP, o) => Mem::RO(Reg::RSP, o + offset),
+    | _                    => mem,
+    }
+}
+
+/// Rewrites the allocator's `name in offset(%rbp)` slot annotations to match.
+fn omit_comment(comment: Symbol, offset: i32) -> Symbol {
+    let text = comment.to_string();
+    let slot = text.rfind(" in ").and_then(|index| {
+        let (name, mem) = (&text[..index], &text[index + 4..]);
+        let rbp = mem.strip_suffix("(%rbp)")?.parse::<i32>().ok()?;
+        Some(format!("{} in {}", name, omit_mem(Mem::RO(Reg::RBP, rbp), offset)))
+    });
+    slot.map(|slot| store(&slot)).unwrap_or(comment)
+}
+
+fn omit_reg(reg: Reg) -> Option<Reg> {
+    if reg == Reg::RBP { None } else { Some(reg) }
+}
+
+fn omit_unary(unary: Unary<Reg>, offset: i32) -> Option<Unary<Reg>> {
+    match unary {
+    | Unary::R(reg) => omit_reg(reg).map(Unary::R),
+    | Unary::M(mem) => Some(Unary::M(omit_mem(mem, offset))),
+    }
+}
+
+fn omit_binary(binary: Binary<Reg>, offset: i32) -> Option<Binary<Reg>> {
+    match binary {
+    | Binary::IR(imm, reg)   => omit_reg(reg).map(|reg| Binary::IR(imm, reg)),
+    | Binary::IM(imm, mem)   => Some(Binary::IM(imm, omit_mem(mem, offset))),
+    | Binary::RM(reg, mem)   => omit_reg(reg).map(|reg| Binary::RM(reg, omit_mem(mem, offset))),
+    | Binary::MR(mem, reg)   => omit_reg(reg).map(|reg| Binary::MR(omit_mem(mem, offset), reg)),
+    | Binary::RR(reg_a, reg_b) => {
+        let reg_a = omit_reg(reg_a)?;
+        let reg_b = omit_reg(reg_b)?;
+        Some(Binary::RR(reg_a, reg_b))
+    },
+    }
+}
+
+fn omit_stm(stm: &Asm<Reg>, offset: i32) -> Option<Asm<Reg>> {
+    let stm = match *stm {
+    | Asm::Mov(binary)     => Asm::Mov(omit_binary(binary, offset)?),
+    | Asm::Bin(op, binary) => Asm::Bin(op, omit_binary(binary, offset)?),
+    | Asm::Cmp(binary)     => Asm::Cmp(omit_binary(binary, offset)?),
+    | Asm::Mul(unary)      => Asm::Mul(omit_unary(unary, offset)?),
+    | Asm::Div(op, unary)  => Asm::Div(op, omit_unary(unary, offset)?),
+    | Asm::Un(op, unary)   => Asm::Un(op, omit_unary(unary, offset)?),
+    | Asm::Jmpi(unary)     => Asm::Jmpi(omit_unary(unary, offset)?),
+    | Asm::Lea(mem, reg)   => Asm::Lea(omit_mem(mem, offset), omit_reg(reg)?),
+    | Asm::Set(op, reg)    => Asm::Set(op, omit_reg(reg)?),
+    | Asm::Movzx(src, dst) => Asm::Movzx(omit_reg(src)?, omit_reg(dst)?),
+    | Asm::Comment(text)   => Asm::Comment(omit_comment(text, offset)),
+    | stm                  => stm,
+    };
+    Some(stm)
+}
@@ -0,0 +1,83 @@
+use simple_symbol::store;
+
+use asm::*;
+use config::CONVENTION;
+use operand::*;
+
+/// Prunes the prologue's saves and the epilogue's restores of callee-saved
+/// registers that the body never writes to. Tiling saves every one of them
+/// up front, but only registers the allocator actually assigned need it.
+pub fn prune(unit: Unit<Reg>) -> Unit<Reg> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(prune_function)
+            .collect()
+    }
+}
+
+pub fn prune_function(asm: Function<Reg>) -> Function<Reg> {
+
+    let (prologue, body, epilogue) = (store("prologue"), store("body"), store("epilogue"));
+    let start = asm.body.iter().position(|stm| *stm == Asm::Comment(body));
+    let end = asm.body.iter().position(|stm| *stm == Asm::Comment(epilogue));
+
+    let (start, end) = match (start, end) {
+    | (Some(start), Some(end)) if start < end => (start, end),
+    | _                                       => return asm,
+    };
+
+    let clobbered = asm.body[start..end].iter()
+        .filter_map(clobbers)
+        .filter(|reg| is_saved(*reg))
+        .collect::<Vec<_>>();
+
+    let mut section = None;
+    let mut pruned = Vec::with_capacity(asm.body.len());
+
+    for stm in asm.body {
+        match stm {
+        | Asm::Comment(symbol) if symbol == prologue || symbol == body || symbol == epilogue => {
+            section = Some(symbol);
+        },
+        | Asm::Mov(Binary::RR(reg, _))
+        | Asm::Mov(Binary::RM(reg, _)) if section == Some(prologue) && is_saved(reg) => {
+            if !clobbered.contains(&reg) { continue }
+        },
+        | Asm::Mov(Binary::RR(_, reg))
+        | Asm::Mov(Binary::MR(_, reg)) if section == Some(epilogue) && is_saved(reg) => {
+            if !clobbered.contains(&reg) { continue }
+        },
+        | _ => (),
+        }
+        pruned.push(stm);
+    }
+
+    Function {
+        body: pruned,
+        stack_info: asm.stack_info,
+    }
+}
+
+/// Callee-saved registers that the prologue saves, i.e. all but RSP and RBP.
+fn is_saved(reg: Reg) -> bool {
+    CONVENTION.is_callee_saved(reg) && reg != Reg::RSP && reg != Reg::RBP
+}
+
+/// Register written to by `stm`, if any. Implicit destinations of
+/// multiplication and division are caller-saved, so they're left out.
+fn clobbers(stm: &Asm<Reg>) -> Option<Reg> {
+    match stm {
+    | Asm::Mov(binary)
+    | Asm::Bin(_, binary) => match binary.dest() {
+        | Value::Reg(reg) => Some(reg),
+        | _               => None,
+    },
+    | Asm::Un(_, Unary::R(reg))
+    | Asm::Pop(Unary::R(reg))
+    | Asm::Lea(_, reg)
+    | Asm::Set(_, reg)
+    | Asm::Movzx(_, reg) => Some(*reg),
+    | _                  => None,
+    }
+}
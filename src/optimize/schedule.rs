@@ -0,0 +1,249 @@
+use asm::*;
+use operand::*;
+
+/// Machine state an instruction can read or write.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Resource {
+    Reg(Reg),
+    Flags,
+    Memory,
+}
+
+struct Effect {
+    uses: Vec<Resource>,
+    defs: Vec<Resource>,
+}
+
+impl Effect {
+
+    fn use_mem(&mut self, mem: &Mem<Reg>) {
+        match mem {
+        | Mem::R(reg)
+        | Mem::RO(reg, _) => self.uses.push(Resource::Reg(*reg)),
+        }
+    }
+
+    fn use_unary(&mut self, unary: &Unary<Reg>) {
+        match unary {
+        | Unary::R(reg) => self.uses.push(Resource::Reg(*reg)),
+        | Unary::M(mem) => {
+            self.use_mem(mem);
+            self.uses.push(Resource::Memory);
+        },
+        }
+    }
+
+    fn def_unary(&mut self, unary: &Unary<Reg>) {
+        match unary {
+        | Unary::R(reg) => self.defs.push(Resource::Reg(*reg)),
+        | Unary::M(mem) => {
+            self.use_mem(mem);
+            self.defs.push(Resource::Memory);
+        },
+        }
+    }
+
+    fn use_binary(&mut self, binary: &Binary<Reg>) {
+        match binary {
+        | Binary::IR(_, _)
+        | Binary::IM(_, _)     => (),
+        | Binary::RM(reg, _)
+        | Binary::RR(reg, _)   => self.uses.push(Resource::Reg(*reg)),
+        | Binary::MR(mem, _)   => {
+            self.use_mem(mem);
+            self.uses.push(Resource::Memory);
+        },
+        }
+    }
+
+    fn def_binary(&mut self, binary: &Binary<Reg>) {
+        match binary {
+        | Binary::IR(_, reg)
+        | Binary::MR(_, reg)
+        | Binary::RR(_, reg) => self.defs.push(Resource::Reg(*reg)),
+        | Binary::IM(_, mem)
+        | Binary::RM(_, mem) => {
+            self.use_mem(mem);
+            self.defs.push(Resource::Memory);
+        },
+        }
+    }
+
+    /// Reads and writes of the destination operand, as in `addq`.
+    fn use_def_binary(&mut self, binary: &Binary<Reg>) {
+        match binary.dest() {
+        | Value::Reg(reg) => self.uses.push(Resource::Reg(reg)),
+        | Value::Mem(_)   => self.uses.push(Resource::Memory),
+        | Value::Imm(_)   => (),
+        }
+        self.use_binary(binary);
+        self.def_binary(binary);
+    }
+
+    /// Returns `None` for instructions that end or begin a basic block.
+    fn of(asm: &Asm<Reg>) -> Option<Self> {
+        let mut effect = Effect { uses: Vec::new(), defs: Vec::new() };
+        match asm {
+        | Asm::Mov(binary) => {
+            effect.use_binary(binary);
+            effect.def_binary(binary);
+        },
+        | Asm::Bin(_, binary) => {
+            effect.use_def_binary(binary);
+            effect.defs.push(Resource::Flags);
+        },
+        | Asm::Cmp(binary) => {
+            effect.use_binary(binary);
+            match binary.dest() {
+            | Value::Reg(reg) => effect.uses.push(Resource::Reg(reg)),
+            | Value::Mem(mem) => {
+                effect.use_mem(&mem);
+                effect.uses.push(Resource::Memory);
+            },
+            | Value::Imm(_)   => (),
+            }
+            effect.defs.push(Resource::Flags);
+        },
+        | Asm::Mul(unary) => {
+            effect.use_unary(unary);
+            effect.uses.push(Resource::Reg(Reg::RAX));
+            effect.defs.extend(&[Resource::Reg(Reg::RAX), Resource::Reg(Reg::RDX), Resource::Flags]);
+        },
+        | Asm::Div(_, unary) => {
+            effect.use_unary(unary);
+            effect.uses.extend(&[Resource::Reg(Reg::RAX), Resource::Reg(Reg::RDX)]);
+            effect.defs.extend(&[Resource::Reg(Reg::RAX), Resource::Reg(Reg::RDX), Resource::Flags]);
+        },
+        | Asm::Un(_, unary) => {
+            effect.use_unary(unary);
+            effect.def_unary(unary);
+            effect.defs.push(Resource::Flags);
+        },
+        | Asm::Push(unary) => {
+            effect.use_unary(unary);
+            effect.uses.push(Resource::Reg(Reg::RSP));
+            effect.defs.extend(&[Resource::Reg(Reg::RSP), Resource::Memory]);
+        },
+        | Asm::Pop(unary) => {
+            effect.uses.extend(&[Resource::Reg(Reg::RSP), Resource::Memory]);
+            effect.def_unary(unary);
+            effect.defs.push(Resource::Reg(Reg::RSP));
+        },
+        | Asm::Lea(mem, reg) => {
+            effect.use_mem(mem);
+            effect.defs.push(Resource::Reg(*reg));
+        },
+        | Asm::Set(_, reg) => {
+            effect.uses.extend(&[Resource::Reg(*reg), Resource::Flags]);
+            effect.defs.push(Resource::Reg(*reg));
+        },
+        | Asm::Movzx(src, dst) => {
+            effect.uses.push(Resource::Reg(*src));
+            effect.defs.push(Resource::Reg(*dst));
+        },
+        | Asm::Cqo => {
+            effect.uses.push(Resource::Reg(Reg::RAX));
+            effect.defs.push(Resource::Reg(Reg::RDX));
+        },
+        | Asm::Jmp(_)
+        | Asm::Jmpi(_)
+        | Asm::Jcc(_, _)
+        | Asm::Call(_)
+        | Asm::Label(_)
+        | Asm::Comment(_)
+        | Asm::Loc(_)
+        | Asm::Direct(_)
+        | Asm::Ret
+        | Asm::Trap => return None,
+        }
+
+        // Memory below the stack pointer isn't safe to touch
+        if effect.defs.contains(&Resource::Reg(Reg::RSP)) {
+            effect.defs.push(Resource::Memory);
+        }
+
+        Some(effect)
+    }
+
+    /// Whether `later` must stay after `self`.
+    fn conflicts(&self, later: &Effect) -> bool {
+        self.defs.iter().any(|def| later.uses.contains(def) || later.defs.contains(def))
+        || self.uses.iter().any(|used| later.defs.contains(used))
+    }
+}
+
+pub fn schedule(unit: Unit<Reg>) -> Unit<Reg> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(schedule_function)
+            .collect()
+    }
+}
+
+pub fn schedule_function(asm: Function<Reg>) -> Function<Reg> {
+
+    let mut scheduled = Vec::new();
+    let mut block = Vec::new();
+
+    for stm in asm.body {
+        match Effect::of(&stm) {
+        | Some(effect) => block.push((stm, effect)),
+        | None => {
+            scheduled.append(&mut schedule_block(block));
+            scheduled.push(stm);
+            block = Vec::new();
+        },
+        }
+    }
+
+    scheduled.append(&mut schedule_block(block));
+
+    Function {
+        body: scheduled,
+        stack_info: asm.stack_info,
+    }
+}
+
+/// List scheduling by longest path to the end of the block,
+/// breaking ties with the original order.
+fn schedule_block(block: Vec<(Asm<Reg>, Effect)>) -> Vec<Asm<Reg>> {
+
+    let len = block.len();
+
+    // Dependency edges from earlier to later instructions
+    let mut preds = vec![Vec::new(); len];
+    let mut succs = vec![Vec::new(); len];
+    for j in 0..len {
+        for i in 0..j {
+            if block[i].1.conflicts(&block[j].1) {
+                preds[j].push(i);
+                succs[i].push(j);
+            }
+        }
+    }
+
+    let mut height = vec![0; len];
+    for i in (0..len).rev() {
+        height[i] = 1 + succs[i].iter()
+            .map(|j| height[*j])
+            .max()
+            .unwrap_or(0);
+    }
+
+    let mut done = vec![false; len];
+    let mut order = Vec::with_capacity(len);
+    while order.len() < len {
+        let next = (0..len)
+            .filter(|i| !done[*i] && preds[*i].iter().all(|j| done[*j]))
+            .max_by_key(|i| (height[*i], len - i))
+            .expect("Internal error: cyclic dependency in basic block");
+
+        done[next] = true;
+        order.push(next);
+    }
+
+    order.into_iter()
+        .map(|i| block[i].0)
+        .collect()
+}
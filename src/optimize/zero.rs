@@ -0,0 +1,59 @@
+use asm::*;
+use operand::*;
+
+/// Lowers moves of zero into a register to `xor reg, reg`, which is
+/// shorter and breaks the dependency on the register's old value.
+/// Unlike `mov`, `xor` clobbers the flags, so moves between a flag-setting
+/// instruction and the `Jcc` that reads it are left alone.
+pub fn zero(unit: Unit<Reg>) -> Unit<Reg> {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(zero_function)
+            .collect()
+    }
+}
+
+pub fn zero_function(asm: Function<Reg>) -> Function<Reg> {
+
+    // Walk backwards so we know whether a later Jcc reads the flags
+    let mut live = false;
+    let mut body = Vec::with_capacity(asm.body.len());
+
+    for stm in asm.body.into_iter().rev() {
+        let stm = match stm {
+        | Asm::Mov(Binary::IR(Imm::Int(0), reg)) if !live => {
+            Asm::Bin(Binop::XOr, Binary::RR(reg, reg))
+        },
+        | _ => stm,
+        };
+
+        match stm {
+        | Asm::Jcc(_, _)
+        | Asm::Set(_, _) => live = true,
+
+        // Unlike the other unary operators, not leaves the flags alone
+        | Asm::Un(Unop::Not, _) => (),
+        | Asm::Cmp(_)
+        | Asm::Bin(_, _)
+        | Asm::Mul(_)
+        | Asm::Div(_, _)
+        | Asm::Un(_, _)
+        | Asm::Call(_)
+        | Asm::Jmp(_)
+        | Asm::Jmpi(_)
+        | Asm::Ret
+        | Asm::Trap => live = false,
+        | _ => (),
+        }
+
+        body.push(stm);
+    }
+
+    body.reverse();
+
+    Function {
+        body,
+        stack_info: asm.stack_info,
+    }
+}
@@ -0,0 +1,10955 @@
+// auto-generated: "lalrpop 0.15.2"
+// sha256: 913da472ae54c166409bdeddbdadbf9a2e8e36db623219ba240923715d3972c
+use codespan::ByteIndex;
+use lalrpop_util::ErrorRecovery;
+use simple_symbol::Symbol;
+use ast;
+use error::Error;
+use token::Token;
+use span::IntoSpan;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Program {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens)]
+
+    use codespan::ByteIndex;
+    use lalrpop_util::ErrorRecovery;
+    use simple_symbol::Symbol;
+    use ast;
+    use error::Error;
+    use token::Token;
+    use span::IntoSpan;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub enum __Symbol<>
+     {
+        Variant0(Token),
+        Variant1(Symbol),
+        Variant2(i32),
+        Variant3(String),
+        Variant4(__lalrpop_util::ErrorRecovery<ByteIndex, Token, Error>),
+        Variant5(ast::Exp),
+        Variant6(::std::vec::Vec<ast::Exp>),
+        Variant7(ast::Field),
+        Variant8(::std::vec::Vec<ast::Field>),
+        Variant9(ast::FieldDec),
+        Variant10(::std::vec::Vec<ast::FieldDec>),
+        Variant11(ByteIndex),
+        Variant12(ast::Binop),
+        Variant13(Vec<ast::Exp>),
+        Variant14(Vec<ast::Field>),
+        Variant15(Vec<ast::FieldDec>),
+        Variant16(ast::Var),
+        Variant17(Vec<ast::Dec>),
+        Variant18(::std::option::Option<ast::Exp>),
+        Variant19(::std::option::Option<ast::Field>),
+        Variant20(::std::option::Option<ast::FieldDec>),
+        Variant21(ast::FunDec),
+        Variant22(::std::vec::Vec<ast::FunDec>),
+        Variant23(ast::Type),
+        Variant24(ast::TypeDec),
+        Variant25(::std::vec::Vec<ast::TypeDec>),
+        Variant26(ast::Dec),
+    }
+    const __ACTION: &'static [i16] = &[
+        // State 0
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 1
+        0, 0, 0, 0, 0, -40, 0, -40, 0, -40, -40, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, -40, -40, -40, 0, 0, -40, -40, -40, -40, 0, 0,
+        // State 2
+        -124, 0, 0, 0, 0, -124, -124, -124, 0, -124, -124, -124, 0, -124, -124, -124, 0, 0, -124, 0, -124, 0, 0, -124, 0, -124, 0, -124, -124, -124, -124, 0, 0, -124, -124, -124, -124, 0, -124, -124, -124, -124, -124, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, -23, 0, -23, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, -23, 0, 34, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, -23, -23, -23, -23, 0, 0, -23, -23, -23, -23, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, -60, 0, -60, 0, -60, -60, 36, 0, -60, 37, 38, 0, 0, -60, 0, -60, 0, 0, 39, 0, -60, 0, 40, 0, 0, 41, 0, 0, -60, -60, -60, -60, 0, 0, -60, -60, -60, -60, 0, 0,
+        // State 5
+        -79, 0, 0, 0, 0, -79, 43, -79, 0, -79, -79, -79, 0, -79, -79, -79, 0, 0, -79, 0, -79, 0, 0, -79, 0, -79, 0, -79, 44, 45, -79, 0, 0, -79, -79, -79, -79, 0, -79, -79, -79, -79, -79, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, -100, 0, -100, 0, -100, -100, 0, 0, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, -100, 0, 0, -100, -100, -100, -100, 0, 0,
+        // State 7
+        49, 0, 0, 0, 0, -111, 0, -111, 0, -111, -111, -111, 0, -111, -111, -111, 0, 0, -111, 0, -111, 0, 0, -111, 0, -111, 0, -111, 0, 0, -111, 0, 0, -111, -111, -111, -111, 0, 50, -111, -111, -111, -111, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, 0, 0, 0, 0, -108, -108, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, -34, 0, -34, 0, -34, -34, 0, 0, -34, 0, 0, 0, 0, -34, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, -34, -34, -34, -34, 0, 0, -34, -34, -34, -34, 0, 0,
+        // State 10
+        -126, 0, -126, 0, 0, -126, -126, -126, -126, -126, -126, -126, 0, -126, -126, -126, 0, 0, -126, 0, -126, 51, 0, -126, 0, -126, 0, -126, -126, -126, -126, 0, 0, -126, -126, -126, -126, 0, -126, -126, -126, -126, -126, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, -47, 0, -47, 0, -47, -47, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, -47, -47, 0, 0, -47, -47, -47, -47, 0, 0,
+        // State 12
+        -42, 0, 0, 0, 0, -42, 0, -42, 0, -42, -42, -42, 0, -42, -42, -42, 0, 0, -42, 0, -42, 0, 0, -42, 0, -42, 0, -42, 0, 0, -42, 0, 0, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, 0, 0, 0, 0, -107, -107, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, -76, 0, -76, 0, -76, -76, 0, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, -76, 0, 0, -76, -76, -76, -76, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, -36, 0, -36, 0, -36, -36, -36, 0, -36, -36, -36, 0, 0, -36, 0, -36, 0, 0, -36, 0, -36, 0, -36, 0, 0, -36, 0, 0, -36, -36, -36, -36, 0, 0, -36, -36, -36, -36, 0, 0,
+        // State 18
+        -38, 0, 0, 0, 0, -38, -38, -38, 0, -38, -38, -38, 0, -38, -38, -38, 0, 0, -38, 0, -38, 0, 0, -38, 0, -38, 0, -38, -38, -38, -38, 0, 0, -38, -38, -38, -38, 0, -38, -38, -38, -38, -38, 0, 0,
+        // State 19
+        -25, 0, 52, 0, 0, -25, -25, -25, 53, -25, -25, -25, 0, -25, -25, -25, 0, 0, -25, 0, -25, 0, 0, -25, 0, -25, 0, -25, -25, -25, -25, 0, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, 0, 0,
+        // State 20
+        -32, 0, 0, 0, 0, -32, -32, -32, 0, -32, -32, -32, 0, -32, -32, -32, 0, 0, -32, 0, -32, 0, 0, -32, 0, -32, 0, -32, -32, -32, -32, 0, 0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        -125, 0, -125, 0, 0, -125, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, 0, 0, -125, 0, -125, 55, 56, -125, 0, -125, 57, -125, -125, -125, -125, 0, 0, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, 0, 0,
+        // State 23
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 24
+        -26, 0, 0, 0, 0, -26, -26, -26, 0, -26, -26, -26, 0, -26, -26, -26, 0, 0, -26, 0, -26, 0, 0, -26, 0, -26, 0, -26, -26, -26, -26, 0, 0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 0,
+        // State 26
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, -104, 76, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 27
+        -28, 0, 0, 0, 0, -28, -28, -28, 0, -28, -28, -28, 0, -28, -28, -28, 0, 0, -28, 0, -28, 0, 0, -28, 0, -28, 0, -28, -28, -28, -28, 0, 0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, 0, 0,
+        // State 28
+        -27, 0, 0, 0, 0, -27, -27, -27, 0, -27, -27, -27, 0, -27, -27, -27, 0, 0, -27, 0, -27, 0, 0, -27, 0, -27, 0, -27, -27, -27, -27, 0, 0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, 0, 0,
+        // State 29
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, -46, 0, -46, 0, -46, -46, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, -46, -46, 0, 0, -46, -46, -46, -46, 0, 0,
+        // State 32
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, -24, -24, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, -61, -61, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, -62, -62, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, -63, -63, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, -64, -64, 0, 0, 0, 0, 0, 0,
+        // State 40
+        0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, -80, -80, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, -81, -81, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, -112, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, -112, -112, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, -113, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 21, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, -49, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0,
+        // State 58
+        -25, 0, 0, 0, 0, -25, -25, -25, 53, -25, -25, -25, 0, -25, -25, -25, 0, 0, -25, 0, -25, 0, 0, -25, 0, -25, 0, -25, -25, -25, -25, 0, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, 0, 0,
+        // State 59
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, -91, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 112, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 76, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 76
+        -123, 0, 0, 0, 0, -123, -123, -123, 0, -123, -123, -123, 0, -123, -123, -123, 0, 0, -123, 0, -123, 0, 0, -123, 0, -123, 0, -123, -123, -123, -123, 0, 0, -123, -123, -123, -123, 0, -123, -123, -123, -123, -123, 0, 0,
+        // State 77
+        -125, 0, 0, 0, 0, -125, -125, -125, -125, -125, -125, -125, 0, -125, -125, -125, 0, 0, -125, 0, -125, 116, 56, -125, 0, -125, 57, -125, -125, -125, -125, 0, 0, -125, -125, -125, -125, 0, -125, -125, -125, -125, -125, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, -33, 0, -33, 0, -33, -33, 0, 0, -33, 0, 0, 0, 0, -33, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, -33, -33, -33, -33, 0, 0, -33, -33, -33, -33, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, -35, 0, -35, 0, -35, -35, -35, 0, -35, -35, -35, 0, 0, -35, 0, -35, 0, 0, -35, 0, -35, 0, -35, 0, 0, -35, 0, 0, -35, -35, -35, -35, 0, 0, -35, -35, -35, -35, 0, 0,
+        // State 81
+        -37, 0, 0, 0, 0, -37, -37, -37, 0, -37, -37, -37, 0, -37, -37, -37, 0, 0, -37, 0, -37, 0, 0, -37, 0, -37, 0, -37, -37, -37, -37, 0, 0, -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, -39, 0, -39, 0, -39, -39, 0, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, -39, -39, -39, -39, 0, 0, -39, -39, -39, -39, 0, 0,
+        // State 83
+        -41, 0, 0, 0, 0, -41, 0, -41, 0, -41, -41, -41, 0, -41, -41, -41, 0, 0, -41, 0, -41, 0, 0, -41, 0, -41, 0, -41, 0, 0, -41, 0, 0, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, -45, 0, -45, 0, -45, -45, 0, 0, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, -45, -45, 0, 0, -45, -45, -45, -45, 0, 0,
+        // State 86
+        -67, 0, -67, 0, 0, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, 0, 0, -67, 0, -67, -67, 0, -67, 0, -67, 0, -67, -67, -67, -67, 0, 0, -67, -67, -67, -67, 0, -67, -67, -67, -67, -67, 0, 0,
+        // State 87
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, -104, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 76, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 112
+        -31, 0, 0, 0, 0, -31, -31, -31, 0, -31, -31, -31, 0, -31, -31, -31, 0, 0, -31, 0, -31, 0, 0, -31, 0, -31, 0, -31, -31, -31, -31, 0, 0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 112, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, -19, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 117
+        -69, 0, -69, 0, 0, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, 0, 0, -69, 0, -69, -69, 0, -69, 0, -69, 0, -69, -69, -69, -69, 0, 0, -69, -69, -69, -69, 0, -69, -69, -69, -69, -69, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0,
+        // State 119
+        -68, 0, -68, 0, 0, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, 0, -68, 0, -68, -68, 0, -68, 0, -68, 0, -68, -68, -68, -68, 0, 143, -68, -68, -68, -68, 0, -68, -68, -68, -68, -68, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        -29, 0, 0, 0, 0, -29, -29, -29, 0, -29, -29, -29, 0, -29, -29, -29, 0, 0, -29, 0, -29, 0, 0, -29, 0, -29, 0, -29, -29, -29, -29, 0, 0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 126
+        -30, 0, 0, 0, 0, -30, -30, -30, 0, -30, -30, -30, 0, -30, -30, -30, 0, 0, -30, 0, -30, 0, 0, -30, 0, -30, 0, -30, -30, -30, -30, 0, 0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 147, -108, 0, 0, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, 0, 0, 0, 0, -108, -108, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, 0, 0, 0, 0, -96, -96, 0, 0,
+        // State 131
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 150, 32,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, -20, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, -43, 0, -43, 0, -43, -43, 0, 0, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, -43, -43, -43, 0, 0, -43, -43, -43, -43, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, 0, 0, 0, 0, -98, -98, 0, 0,
+        // State 141
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 25, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, -74, 0, -74, 0, -74, -74, 0, 0, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, -74, -74, 0, 0, -74, -74, -74, -74, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, 0, 0,
+        // State 161
+        0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        -68, 0, 0, 0, 0, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, 0, -68, 0, -68, -68, 0, -68, 0, -68, 0, -68, -68, -68, -68, 0, 0, -68, -68, -68, -68, 0, -68, -68, -68, -68, -68, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, -75, 0, -75, 0, -75, -75, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, -75, -75, 0, 0, -75, -75, -75, -75, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, -73, 0, -73, 0, -73, -73, 0, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, -73, -73, 0, 0, -73, -73, -73, -73, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, 0, 0, 0, 0, -97, -97, 0, 0,
+        // State 167
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 150, 32,
+        // State 168
+        0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 179
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 150, 32,
+        // State 181
+        0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 184
+        0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, -116, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, -115, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, -44, 0, -44, 0, -44, -44, 0, 0, -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, -44, -44, -44, 0, 0, -44, -44, -44, -44, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, 0, 0, 0, 0, -99, -99, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, 0,
+        // State 193
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23, 24, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 31, 32,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, 0,
+        // State 197
+        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 23, 60, 0, 25, 0, 0, 0, 0, 26, 0, 27, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 29, 30, 0, 0, 0, 0, 150, 32,
+    ];
+    const __EOF_ACTION: &'static [i16] = &[
+        // State 0
+        0,
+        // State 1
+        -40,
+        // State 2
+        -124,
+        // State 3
+        -23,
+        // State 4
+        -60,
+        // State 5
+        -79,
+        // State 6
+        -100,
+        // State 7
+        -111,
+        // State 8
+        -108,
+        // State 9
+        -34,
+        // State 10
+        -126,
+        // State 11
+        -47,
+        // State 12
+        -42,
+        // State 13
+        -107,
+        // State 14
+        -76,
+        // State 15
+        -131,
+        // State 16
+        -102,
+        // State 17
+        -36,
+        // State 18
+        -38,
+        // State 19
+        -25,
+        // State 20
+        -32,
+        // State 21
+        0,
+        // State 22
+        -125,
+        // State 23
+        0,
+        // State 24
+        -26,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        -28,
+        // State 28
+        -27,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        -46,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        -25,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        0,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        -123,
+        // State 77
+        -125,
+        // State 78
+        0,
+        // State 79
+        -33,
+        // State 80
+        -35,
+        // State 81
+        -37,
+        // State 82
+        -39,
+        // State 83
+        -41,
+        // State 84
+        0,
+        // State 85
+        -45,
+        // State 86
+        -67,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        0,
+        // State 102
+        0,
+        // State 103
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        -31,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        -69,
+        // State 118
+        0,
+        // State 119
+        -68,
+        // State 120
+        0,
+        // State 121
+        -29,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        -30,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        -108,
+        // State 130
+        -96,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        -43,
+        // State 140
+        -98,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        -74,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        0,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        -68,
+        // State 163
+        0,
+        // State 164
+        -75,
+        // State 165
+        -73,
+        // State 166
+        -97,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        0,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        -44,
+        // State 189
+        -99,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+    ];
+    const __GOTO: &'static [i16] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 16, 0, 17, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 58, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 62, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 0, 0, 68, 69, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 74, 75, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 59, 0, 0, 0,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 79, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 5, 6, 0, 8, 0, 0, 0, 0, 80, 0, 11, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 6, 0, 8, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 59, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 3, 4, 5, 6, 0, 8, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 85, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 51
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 86, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 89, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 96, 0, 0, 10, 0, 11, 0, 97, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 100, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 66, 103, 0, 0, 68, 104, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 63, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 68, 107, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 62, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 66, 67, 0, 0, 68, 69, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 115, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 119, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 129, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 130, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 131, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 133, 75, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 138, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 139, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 140, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 141, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 145, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 146, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 148, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 161, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 164, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 166, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 167, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 170, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 166, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 188, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 189, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 190, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 191, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 140, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 193, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 10, 0, 11, 0, 196, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 59, 0, 0, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 197, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 4, 5, 6, 7, 8, 189, 0, 0, 0, 10, 0, 11, 0, 12, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###"ADD"###,
+            r###"ARRAY"###,
+            r###"ASSIGN"###,
+            r###"BREAK"###,
+            r###"COLON"###,
+            r###"COMMA"###,
+            r###"DIV"###,
+            r###"DO"###,
+            r###"DOT"###,
+            r###"ELSE"###,
+            r###"END"###,
+            r###"EQ"###,
+            r###"FOR"###,
+            r###"FUNCTION"###,
+            r###"GE"###,
+            r###"GT"###,
+            r###"ID"###,
+            r###"IF"###,
+            r###"IN"###,
+            r###"INT"###,
+            r###"LAND"###,
+            r###"LBRACE"###,
+            r###"LBRACK"###,
+            r###"LE"###,
+            r###"LET"###,
+            r###"LOR"###,
+            r###"LPAREN"###,
+            r###"LT"###,
+            r###"MOD"###,
+            r###"MUL"###,
+            r###"NEQ"###,
+            r###"NIL"###,
+            r###"OF"###,
+            r###"RBRACE"###,
+            r###"RBRACK"###,
+            r###"RPAREN"###,
+            r###"SEMICOLON"###,
+            r###"STR"###,
+            r###"SUB"###,
+            r###"THEN"###,
+            r###"TO"###,
+            r###"TYPE"###,
+            r###"VAR"###,
+            r###"WHILE"###,
+        ];
+        __ACTION[(__state * 45)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub struct ProgramParser {
+        _priv: (),
+    }
+
+    impl ProgramParser {
+        pub fn new() -> ProgramParser {
+            ProgramParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'err,
+            __TOKEN: __ToTriple<'err, Error=Error>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+            __tokens0: __TOKENS,
+        ) -> Result<ast::Exp, __lalrpop_util::ParseError<ByteIndex, Token, Error>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __states = vec![0_i16];
+            let mut __symbols = vec![];
+            let mut __integer;
+            let mut __lookahead;
+            let __last_location = &mut Default::default();
+            '__shift: loop {
+                __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => v,
+                    None => break '__shift,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                *__last_location = __lookahead.2.clone();
+                __integer = match __lookahead.1 {
+                    Token::Add if true => 0,
+                    Token::Array if true => 1,
+                    Token::Assign if true => 2,
+                    Token::Break if true => 3,
+                    Token::Colon if true => 4,
+                    Token::Comma if true => 5,
+                    Token::Div if true => 6,
+                    Token::Do if true => 7,
+                    Token::Dot if true => 8,
+                    Token::Else if true => 9,
+                    Token::End if true => 10,
+                    Token::Eq if true => 11,
+                    Token::For if true => 12,
+                    Token::Function if true => 13,
+                    Token::Ge if true => 14,
+                    Token::Gt if true => 15,
+                    Token::Ident(_) if true => 16,
+                    Token::If if true => 17,
+                    Token::In if true => 18,
+                    Token::Int(_) if true => 19,
+                    Token::LAnd if true => 20,
+                    Token::LBrace if true => 21,
+                    Token::LBrack if true => 22,
+                    Token::Le if true => 23,
+                    Token::Let if true => 24,
+                    Token::LOr if true => 25,
+                    Token::LParen if true => 26,
+                    Token::Lt if true => 27,
+                    Token::Mod if true => 28,
+                    Token::Mul if true => 29,
+                    Token::Neq if true => 30,
+                    Token::Nil if true => 31,
+                    Token::Of if true => 32,
+                    Token::RBrace if true => 33,
+                    Token::RBrack if true => 34,
+                    Token::RParen if true => 35,
+                    Token::Semicolon if true => 36,
+                    Token::Str(_) if true => 37,
+                    Token::Sub if true => 38,
+                    Token::Then if true => 39,
+                    Token::To if true => 40,
+                    Token::Type if true => 41,
+                    Token::Var if true => 42,
+                    Token::While if true => 43,
+                    _ => {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                            token: Some(__lookahead),
+                            expected: __expected_tokens(__state),
+                        };
+                        return Err(__error);
+                    }
+                };
+                '__inner: loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __ACTION[__state * 45 + __integer];
+                    if __action > 0 {
+                        let __symbol = match __integer {
+                            0 => match __lookahead.1 {
+                                __tok @ Token::Add => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            1 => match __lookahead.1 {
+                                __tok @ Token::Array => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            2 => match __lookahead.1 {
+                                __tok @ Token::Assign => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            3 => match __lookahead.1 {
+                                __tok @ Token::Break => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            4 => match __lookahead.1 {
+                                __tok @ Token::Colon => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            5 => match __lookahead.1 {
+                                __tok @ Token::Comma => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            6 => match __lookahead.1 {
+                                __tok @ Token::Div => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            7 => match __lookahead.1 {
+                                __tok @ Token::Do => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            8 => match __lookahead.1 {
+                                __tok @ Token::Dot => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            9 => match __lookahead.1 {
+                                __tok @ Token::Else => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            10 => match __lookahead.1 {
+                                __tok @ Token::End => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            11 => match __lookahead.1 {
+                                __tok @ Token::Eq => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            12 => match __lookahead.1 {
+                                __tok @ Token::For => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            13 => match __lookahead.1 {
+                                __tok @ Token::Function => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            14 => match __lookahead.1 {
+                                __tok @ Token::Ge => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            15 => match __lookahead.1 {
+                                __tok @ Token::Gt => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            16 => match __lookahead.1 {
+                                Token::Ident(__tok0) => __Symbol::Variant1((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            17 => match __lookahead.1 {
+                                __tok @ Token::If => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            18 => match __lookahead.1 {
+                                __tok @ Token::In => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            19 => match __lookahead.1 {
+                                Token::Int(__tok0) => __Symbol::Variant2((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            20 => match __lookahead.1 {
+                                __tok @ Token::LAnd => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            21 => match __lookahead.1 {
+                                __tok @ Token::LBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            22 => match __lookahead.1 {
+                                __tok @ Token::LBrack => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            23 => match __lookahead.1 {
+                                __tok @ Token::Le => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            24 => match __lookahead.1 {
+                                __tok @ Token::Let => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            25 => match __lookahead.1 {
+                                __tok @ Token::LOr => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            26 => match __lookahead.1 {
+                                __tok @ Token::LParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            27 => match __lookahead.1 {
+                                __tok @ Token::Lt => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            28 => match __lookahead.1 {
+                                __tok @ Token::Mod => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            29 => match __lookahead.1 {
+                                __tok @ Token::Mul => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            30 => match __lookahead.1 {
+                                __tok @ Token::Neq => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            31 => match __lookahead.1 {
+                                __tok @ Token::Nil => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            32 => match __lookahead.1 {
+                                __tok @ Token::Of => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            33 => match __lookahead.1 {
+                                __tok @ Token::RBrace => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            34 => match __lookahead.1 {
+                                __tok @ Token::RBrack => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            35 => match __lookahead.1 {
+                                __tok @ Token::RParen => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            36 => match __lookahead.1 {
+                                __tok @ Token::Semicolon => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            37 => match __lookahead.1 {
+                                Token::Str(__tok0) => __Symbol::Variant3((__tok0)),
+                                _ => unreachable!(),
+                            },
+                            38 => match __lookahead.1 {
+                                __tok @ Token::Sub => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            39 => match __lookahead.1 {
+                                __tok @ Token::Then => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            40 => match __lookahead.1 {
+                                __tok @ Token::To => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            41 => match __lookahead.1 {
+                                __tok @ Token::Type => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            42 => match __lookahead.1 {
+                                __tok @ Token::Var => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            43 => match __lookahead.1 {
+                                __tok @ Token::While => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            _ => unreachable!(),
+                        };
+                        __states.push(__action - 1);
+                        __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                        continue '__shift;
+                    } else if __action < 0 {
+                        if let Some(r) = __reduce(errors, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            if r.is_err() {
+                                return r;
+                            }
+                            return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead });
+                        }
+                    } else {
+                        let mut __err_lookahead = Some(__lookahead);
+                        let mut __err_integer: Option<usize> = Some(__integer);
+                        match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, ::std::marker::PhantomData::<()>) {
+                            Err(__e) => return Err(__e),
+                            Ok(Some(__v)) => return Ok(__v),
+                            Ok(None) => (),
+                        }
+                        match (__err_lookahead, __err_integer) {
+                            (Some(__l), Some(__i)) => {
+                                __lookahead = __l;
+                                __integer = __i;
+                                continue '__inner;
+                            }
+                            _ => break '__shift,
+                        }
+                    }
+                }
+            }
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __EOF_ACTION[__state];
+                if __action < 0 {
+                    if let Some(r) = __reduce(errors, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let mut __err_lookahead = None;
+                    let mut __err_integer: Option<usize> = None;
+                    match __error_recovery(errors,  &mut __tokens, &mut __states, &mut __symbols, __last_location, &mut __err_lookahead, &mut __err_integer, ::std::marker::PhantomData::<()>) {
+                        Err(__e) => return Err(__e),
+                        Ok(Some(__v)) => return Ok(__v),
+                        Ok(None) => (),
+                    }
+                }
+            }
+        }
+    }
+    fn __error_recovery<
+        'err,
+        __I,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __tokens: &mut __I,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        __last_location: &mut ByteIndex,
+        __opt_lookahead: &mut Option<(ByteIndex, Token, ByteIndex)>,
+        __opt_integer: &mut Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Result<Option<ast::Exp>, __lalrpop_util::ParseError<ByteIndex, Token, Error>> where
+      __I: Iterator<Item = Result<(ByteIndex, Token, ByteIndex), Error>>,
+    {
+        let __state = *__states.last().unwrap() as usize;
+        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+            token: __opt_lookahead.clone(),
+            expected: __expected_tokens(__state),
+        };
+        let mut __dropped_tokens = vec![];
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __ACTION[__state * 45 + 44];
+            if __action >= 0 {
+                break;
+            }
+            let __lookahead_start = __opt_lookahead.as_ref().map(|l| &l.0);
+            if let Some(r) = __reduce( errors,  __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()> ) {
+                return Ok(Some(r?));
+            }
+        }
+        let __states_len = __states.len();
+        let __top0;
+        '__find_state: loop {
+            for __top in (0..__states_len).rev() {
+                let __state = __states[__top] as usize;
+                let __action = __ACTION[__state * 45 + 44];
+                if __action <= 0 { continue; }
+                let __error_state = __action - 1;
+                if __accepts(errors,  __error_state, &__states[..__top + 1], *__opt_integer, ::std::marker::PhantomData::<()>,) {
+                    __top0 = __top;
+                    break '__find_state;
+                }
+            }
+            '__eof: loop {
+                match __opt_lookahead.take() {
+                    None => {
+                        return Err(__error)
+                    }
+                    Some(mut __lookahead) => {
+                        __dropped_tokens.push(__lookahead);
+                        __lookahead = match __tokens.next() {
+                            Some(Ok(v)) => v,
+                            None => break '__eof,
+                            Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                        };
+                        *__last_location = __lookahead.2.clone();
+                        let __integer;
+                        __integer = match __lookahead.1 {
+                            Token::Add if true => 0,
+                            Token::Array if true => 1,
+                            Token::Assign if true => 2,
+                            Token::Break if true => 3,
+                            Token::Colon if true => 4,
+                            Token::Comma if true => 5,
+                            Token::Div if true => 6,
+                            Token::Do if true => 7,
+                            Token::Dot if true => 8,
+                            Token::Else if true => 9,
+                            Token::End if true => 10,
+                            Token::Eq if true => 11,
+                            Token::For if true => 12,
+                            Token::Function if true => 13,
+                            Token::Ge if true => 14,
+                            Token::Gt if true => 15,
+                            Token::Ident(_) if true => 16,
+                            Token::If if true => 17,
+                            Token::In if true => 18,
+                            Token::Int(_) if true => 19,
+                            Token::LAnd if true => 20,
+                            Token::LBrace if true => 21,
+                            Token::LBrack if true => 22,
+                            Token::Le if true => 23,
+                            Token::Let if true => 24,
+                            Token::LOr if true => 25,
+                            Token::LParen if true => 26,
+                            Token::Lt if true => 27,
+                            Token::Mod if true => 28,
+                            Token::Mul if true => 29,
+                            Token::Neq if true => 30,
+                            Token::Nil if true => 31,
+                            Token::Of if true => 32,
+                            Token::RBrace if true => 33,
+                            Token::RBrack if true => 34,
+                            Token::RParen if true => 35,
+                            Token::Semicolon if true => 36,
+                            Token::Str(_) if true => 37,
+                            Token::Sub if true => 38,
+                            Token::Then if true => 39,
+                            Token::To if true => 40,
+                            Token::Type if true => 41,
+                            Token::Var if true => 42,
+                            Token::While if true => 43,
+                            _ => {
+                                let __state = *__states.last().unwrap() as usize;
+                                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                                    token: Some(__lookahead),
+                                    expected: __expected_tokens(__state),
+                                };
+                                return Err(__error);
+                            }
+                        };
+                        *__opt_lookahead = Some(__lookahead);
+                        *__opt_integer = Some(__integer);
+                        continue '__find_state;
+                    }
+                }
+            }
+            *__opt_lookahead = None;
+            *__opt_integer = None;
+        };
+        let __top = __top0;
+        let __start = if let Some(__popped_sym) = __symbols.get(__top) {
+            __popped_sym.0.clone()
+        } else if let Some(__dropped_token) = __dropped_tokens.first() {
+            __dropped_token.0.clone()
+        } else if __top > 0 {
+            __symbols[__top - 1].2.clone()
+        } else {
+            Default::default()
+        };
+        let __end = if let Some(__dropped_token) = __dropped_tokens.last() {
+            __dropped_token.2.clone()
+        } else if __states_len - 1 > __top {
+            __symbols.last().unwrap().2.clone()
+        } else if let Some(__lookahead) = __opt_lookahead.as_ref() {
+            __lookahead.0.clone()
+        } else {
+            __start.clone()
+        };
+        __states.truncate(__top + 1);
+        __symbols.truncate(__top);
+        let __recover_state = __states[__top] as usize;
+        let __error_action = __ACTION[__recover_state * 45 + 44];
+        let __error_state = __error_action - 1;
+        __states.push(__error_state);
+        let __recovery = __lalrpop_util::ErrorRecovery {
+            error: __error,
+            dropped_tokens: __dropped_tokens,
+        };
+        __symbols.push((__start, __Symbol::Variant4(__recovery), __end));
+        Ok(None)
+    }
+    fn __accepts<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1] as usize;
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __ACTION[__top * 45 + __integer],
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match -__action {
+                1 => {
+                    (2, 0)
+                }
+                2 => {
+                    (0, 1)
+                }
+                3 => {
+                    (1, 1)
+                }
+                4 => {
+                    (2, 2)
+                }
+                5 => {
+                    (3, 2)
+                }
+                6 => {
+                    (2, 3)
+                }
+                7 => {
+                    (0, 4)
+                }
+                8 => {
+                    (1, 4)
+                }
+                9 => {
+                    (2, 5)
+                }
+                10 => {
+                    (3, 5)
+                }
+                11 => {
+                    (2, 6)
+                }
+                12 => {
+                    (0, 7)
+                }
+                13 => {
+                    (1, 7)
+                }
+                14 => {
+                    (2, 8)
+                }
+                15 => {
+                    (3, 8)
+                }
+                16 => {
+                    (2, 9)
+                }
+                17 => {
+                    (0, 10)
+                }
+                18 => {
+                    (1, 10)
+                }
+                19 => {
+                    (2, 11)
+                }
+                20 => {
+                    (3, 11)
+                }
+                21 => {
+                    (0, 12)
+                }
+                22 => {
+                    (0, 13)
+                }
+                23 => {
+                    (1, 14)
+                }
+                24 => {
+                    (1, 15)
+                }
+                25 => {
+                    (1, 16)
+                }
+                26 => {
+                    (1, 16)
+                }
+                27 => {
+                    (1, 16)
+                }
+                28 => {
+                    (1, 16)
+                }
+                29 => {
+                    (4, 16)
+                }
+                30 => {
+                    (4, 16)
+                }
+                31 => {
+                    (3, 16)
+                }
+                32 => {
+                    (1, 16)
+                }
+                33 => {
+                    (3, 17)
+                }
+                34 => {
+                    (1, 17)
+                }
+                35 => {
+                    (3, 18)
+                }
+                36 => {
+                    (1, 18)
+                }
+                37 => {
+                    (3, 19)
+                }
+                38 => {
+                    (1, 19)
+                }
+                39 => {
+                    (3, 20)
+                }
+                40 => {
+                    (1, 20)
+                }
+                41 => {
+                    (3, 21)
+                }
+                42 => {
+                    (1, 21)
+                }
+                43 => {
+                    (4, 22)
+                }
+                44 => {
+                    (8, 22)
+                }
+                45 => {
+                    (3, 22)
+                }
+                46 => {
+                    (1, 22)
+                }
+                47 => {
+                    (1, 22)
+                }
+                48 => {
+                    (1, 23)
+                }
+                49 => {
+                    (0, 23)
+                }
+                50 => {
+                    (2, 23)
+                }
+                51 => {
+                    (1, 23)
+                }
+                52 => {
+                    (1, 24)
+                }
+                53 => {
+                    (0, 24)
+                }
+                54 => {
+                    (2, 24)
+                }
+                55 => {
+                    (1, 24)
+                }
+                56 => {
+                    (1, 25)
+                }
+                57 => {
+                    (0, 25)
+                }
+                58 => {
+                    (2, 25)
+                }
+                59 => {
+                    (1, 25)
+                }
+                60 => {
+                    (1, 26)
+                }
+                61 => {
+                    (1, 27)
+                }
+                62 => {
+                    (1, 27)
+                }
+                63 => {
+                    (1, 27)
+                }
+                64 => {
+                    (1, 27)
+                }
+                65 => {
+                    (1, 27)
+                }
+                66 => {
+                    (1, 27)
+                }
+                67 => {
+                    (3, 28)
+                }
+                68 => {
+                    (4, 28)
+                }
+                69 => {
+                    (4, 28)
+                }
+                70 => {
+                    (1, 29)
+                }
+                71 => {
+                    (1, 29)
+                }
+                72 => {
+                    (1, 29)
+                }
+                73 => {
+                    (6, 30)
+                }
+                74 => {
+                    (5, 30)
+                }
+                75 => {
+                    (6, 30)
+                }
+                76 => {
+                    (1, 30)
+                }
+                77 => {
+                    (1, 31)
+                }
+                78 => {
+                    (0, 31)
+                }
+                79 => {
+                    (1, 32)
+                }
+                80 => {
+                    (1, 33)
+                }
+                81 => {
+                    (1, 33)
+                }
+                82 => {
+                    (1, 33)
+                }
+                83 => {
+                    (3, 34)
+                }
+                84 => {
+                    (1, 35)
+                }
+                85 => {
+                    (0, 35)
+                }
+                86 => {
+                    (3, 36)
+                }
+                87 => {
+                    (1, 37)
+                }
+                88 => {
+                    (0, 37)
+                }
+                89 => {
+                    (7, 38)
+                }
+                90 => {
+                    (9, 38)
+                }
+                91 => {
+                    (1, 39)
+                }
+                92 => {
+                    (2, 39)
+                }
+                93 => {
+                    (2, 40)
+                }
+                94 => {
+                    (2, 40)
+                }
+                95 => {
+                    (1, 40)
+                }
+                96 => {
+                    (4, 41)
+                }
+                97 => {
+                    (6, 41)
+                }
+                98 => {
+                    (4, 41)
+                }
+                99 => {
+                    (8, 41)
+                }
+                100 => {
+                    (1, 42)
+                }
+                101 => {
+                    (1, 43)
+                }
+                102 => {
+                    (1, 44)
+                }
+                103 => {
+                    (1, 45)
+                }
+                104 => {
+                    (0, 45)
+                }
+                105 => {
+                    (2, 45)
+                }
+                106 => {
+                    (1, 45)
+                }
+                107 => {
+                    (1, 46)
+                }
+                108 => {
+                    (1, 46)
+                }
+                109 => {
+                    (1, 47)
+                }
+                110 => {
+                    (0, 47)
+                }
+                111 => {
+                    (1, 48)
+                }
+                112 => {
+                    (1, 49)
+                }
+                113 => {
+                    (1, 49)
+                }
+                114 => {
+                    (1, 50)
+                }
+                115 => {
+                    (3, 50)
+                }
+                116 => {
+                    (3, 50)
+                }
+                117 => {
+                    (4, 51)
+                }
+                118 => {
+                    (1, 52)
+                }
+                119 => {
+                    (2, 52)
+                }
+                120 => {
+                    (2, 53)
+                }
+                121 => {
+                    (2, 53)
+                }
+                122 => {
+                    (1, 53)
+                }
+                123 => {
+                    (2, 54)
+                }
+                124 => {
+                    (1, 54)
+                }
+                125 => {
+                    (1, 55)
+                }
+                126 => {
+                    (1, 55)
+                }
+                127 => {
+                    (4, 56)
+                }
+                128 => {
+                    (6, 56)
+                }
+                129 => {
+                    (2, 57)
+                }
+                130 => {
+                    (1, 57)
+                }
+                131 => return true,
+                _ => panic!("invalid action code {}", __action)
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1] as usize;
+            let __next_state = __GOTO[__top * 59 + __nt] - 1;
+            __states.push(__next_state);
+        }
+    }
+    pub(crate) fn __reduce<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<ast::Exp,__lalrpop_util::ParseError<ByteIndex, Token, Error>>>
+    {
+        let (__pop_states, __symbol, __nonterminal) = match -__action {
+            1 => {
+                __reduce1(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            2 => {
+                __reduce2(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            3 => {
+                __reduce3(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            4 => {
+                __reduce4(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            5 => {
+                __reduce5(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            6 => {
+                __reduce6(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            7 => {
+                __reduce7(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            8 => {
+                __reduce8(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            9 => {
+                __reduce9(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            10 => {
+                __reduce10(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            11 => {
+                __reduce11(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            12 => {
+                __reduce12(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            13 => {
+                __reduce13(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            14 => {
+                __reduce14(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            15 => {
+                __reduce15(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            16 => {
+                __reduce16(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            17 => {
+                __reduce17(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            18 => {
+                __reduce18(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            19 => {
+                __reduce19(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            20 => {
+                __reduce20(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            21 => {
+                __reduce21(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            22 => {
+                __reduce22(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            23 => {
+                __reduce23(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            24 => {
+                __reduce24(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            25 => {
+                __reduce25(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            26 => {
+                __reduce26(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            27 => {
+                __reduce27(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            28 => {
+                __reduce28(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            29 => {
+                __reduce29(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            30 => {
+                __reduce30(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            31 => {
+                __reduce31(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            32 => {
+                __reduce32(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            33 => {
+                __reduce33(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            34 => {
+                __reduce34(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            35 => {
+                __reduce35(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            36 => {
+                __reduce36(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            37 => {
+                __reduce37(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            38 => {
+                __reduce38(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            39 => {
+                __reduce39(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            40 => {
+                __reduce40(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            41 => {
+                __reduce41(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            42 => {
+                __reduce42(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            43 => {
+                __reduce43(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            44 => {
+                __reduce44(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            45 => {
+                __reduce45(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            46 => {
+                __reduce46(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            47 => {
+                __reduce47(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            48 => {
+                __reduce48(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            49 => {
+                __reduce49(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            50 => {
+                __reduce50(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            57 => {
+                __reduce57(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            58 => {
+                __reduce58(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            59 => {
+                __reduce59(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            60 => {
+                __reduce60(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            61 => {
+                __reduce61(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            62 => {
+                __reduce62(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            63 => {
+                __reduce63(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            64 => {
+                __reduce64(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            65 => {
+                __reduce65(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            66 => {
+                __reduce66(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            67 => {
+                __reduce67(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            68 => {
+                __reduce68(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            69 => {
+                __reduce69(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            70 => {
+                __reduce70(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            71 => {
+                __reduce71(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            72 => {
+                __reduce72(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            73 => {
+                __reduce73(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            74 => {
+                __reduce74(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            75 => {
+                __reduce75(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            76 => {
+                __reduce76(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            77 => {
+                __reduce77(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            78 => {
+                __reduce78(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            79 => {
+                __reduce79(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            80 => {
+                __reduce80(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            81 => {
+                __reduce81(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            82 => {
+                __reduce82(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            83 => {
+                __reduce83(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            111 => {
+                __reduce111(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            112 => {
+                __reduce112(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            113 => {
+                __reduce113(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            114 => {
+                __reduce114(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            115 => {
+                __reduce115(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            116 => {
+                __reduce116(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            117 => {
+                __reduce117(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            118 => {
+                __reduce118(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            119 => {
+                __reduce119(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            120 => {
+                __reduce120(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            121 => {
+                __reduce121(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            122 => {
+                __reduce122(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            123 => {
+                __reduce123(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            124 => {
+                __reduce124(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            125 => {
+                __reduce125(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            126 => {
+                __reduce126(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            127 => {
+                __reduce127(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            128 => {
+                __reduce128(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            129 => {
+                __reduce129(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            130 => {
+                __reduce130(errors, __action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            131 => {
+                // __Program = Program => ActionFn(0);
+                let __sym0 = __pop_Variant5(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        __symbols.push(__symbol);
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 59 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ByteIndex, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant11(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, String, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Symbol, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Token, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Vec<ast::Dec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant17(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Vec<ast::Exp>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant13(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Vec<ast::Field>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant14(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, Vec<ast::FieldDec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant15(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, __lalrpop_util::ErrorRecovery<ByteIndex, Token, Error>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Binop, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant12(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant26<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Dec, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant26(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Exp, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Field, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::FieldDec, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant21<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::FunDec, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant21(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant23<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Type, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant24<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::TypeDec, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant24(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ast::Var, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant16(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, i32, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant18<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant18(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant19<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::option::Option<ast::Field>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant19(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant20<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::option::Option<ast::FieldDec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant20(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant10(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant25<
+    >(
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>
+    ) -> (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant25(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    pub(crate) fn __reduce1<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Exp>) = COMMA, Exp => ActionFn(108);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action108::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (2, __symbol, 0)
+    }
+    pub(crate) fn __reduce2<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Exp>)* =  => ActionFn(106);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action106::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (0, __symbol, 1)
+    }
+    pub(crate) fn __reduce3<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Exp>)* = (COMMA <Exp>)+ => ActionFn(107);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (1, __symbol, 1)
+    }
+    pub(crate) fn __reduce4<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Exp>)+ = COMMA, Exp => ActionFn(119);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action119::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (2, __symbol, 2)
+    }
+    pub(crate) fn __reduce5<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Exp>)+ = (COMMA <Exp>)+, COMMA, Exp => ActionFn(120);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action120::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (3, __symbol, 2)
+    }
+    pub(crate) fn __reduce6<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Field>) = COMMA, Field => ActionFn(103);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action103::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (2, __symbol, 3)
+    }
+    pub(crate) fn __reduce7<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Field>)* =  => ActionFn(101);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action101::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (0, __symbol, 4)
+    }
+    pub(crate) fn __reduce8<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Field>)* = (COMMA <Field>)+ => ActionFn(102);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action102::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 4)
+    }
+    pub(crate) fn __reduce9<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Field>)+ = COMMA, Field => ActionFn(123);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action123::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (2, __symbol, 5)
+    }
+    pub(crate) fn __reduce10<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <Field>)+ = (COMMA <Field>)+, COMMA, Field => ActionFn(124);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action124::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (3, __symbol, 5)
+    }
+    pub(crate) fn __reduce11<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <FieldDec>) = COMMA, FieldDec => ActionFn(93);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action93::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (2, __symbol, 6)
+    }
+    pub(crate) fn __reduce12<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <FieldDec>)* =  => ActionFn(91);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action91::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (0, __symbol, 7)
+    }
+    pub(crate) fn __reduce13<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <FieldDec>)* = (COMMA <FieldDec>)+ => ActionFn(92);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action92::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (1, __symbol, 7)
+    }
+    pub(crate) fn __reduce14<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <FieldDec>)+ = COMMA, FieldDec => ActionFn(127);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action127::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (2, __symbol, 8)
+    }
+    pub(crate) fn __reduce15<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (COMMA <FieldDec>)+ = (COMMA <FieldDec>)+, COMMA, FieldDec => ActionFn(128);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action128::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (3, __symbol, 8)
+    }
+    pub(crate) fn __reduce16<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (SEMICOLON <Stm>) = SEMICOLON, Stm => ActionFn(98);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action98::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (2, __symbol, 9)
+    }
+    pub(crate) fn __reduce17<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (SEMICOLON <Stm>)* =  => ActionFn(96);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action96::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (0, __symbol, 10)
+    }
+    pub(crate) fn __reduce18<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (SEMICOLON <Stm>)* = (SEMICOLON <Stm>)+ => ActionFn(97);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (1, __symbol, 10)
+    }
+    pub(crate) fn __reduce19<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (SEMICOLON <Stm>)+ = SEMICOLON, Stm => ActionFn(131);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action131::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (2, __symbol, 11)
+    }
+    pub(crate) fn __reduce20<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // (SEMICOLON <Stm>)+ = (SEMICOLON <Stm>)+, SEMICOLON, Stm => ActionFn(132);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action132::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (3, __symbol, 11)
+    }
+    pub(crate) fn __reduce21<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // @L =  => ActionFn(90);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action90::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (0, __symbol, 12)
+    }
+    pub(crate) fn __reduce22<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // @R =  => ActionFn(87);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action87::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (0, __symbol, 13)
+    }
+    pub(crate) fn __reduce23<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // And = Binary<AndOp, Compare> => ActionFn(44);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 14)
+    }
+    pub(crate) fn __reduce24<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // AndOp = LAND => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 15)
+    }
+    pub(crate) fn __reduce25<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = Var => ActionFn(180);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action180::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce26<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = INT => ActionFn(181);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action181::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce27<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = STR => ActionFn(182);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action182::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce28<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = NIL => ActionFn(183);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action183::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce29<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = ID, LBRACK, Comma<Field>, RBRACK => ActionFn(184);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action184::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (4, __symbol, 16)
+    }
+    pub(crate) fn __reduce30<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = ID, LPAREN, Comma<Exp>, RPAREN => ActionFn(185);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action185::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (4, __symbol, 16)
+    }
+    pub(crate) fn __reduce31<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = LPAREN, Semicolon<Stm>, RPAREN => ActionFn(186);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action186::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 16)
+    }
+    pub(crate) fn __reduce32<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Atom = BREAK => ActionFn(187);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action187::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 16)
+    }
+    pub(crate) fn __reduce33<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<AndOp, Compare> = Binary<AndOp, Compare>, AndOp, Compare => ActionFn(188);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action188::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 17)
+    }
+    pub(crate) fn __reduce34<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<AndOp, Compare> = Compare => ActionFn(80);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 17)
+    }
+    pub(crate) fn __reduce35<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<CompareOp, Term> = Binary<CompareOp, Term>, CompareOp, Term => ActionFn(189);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action189::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 18)
+    }
+    pub(crate) fn __reduce36<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<CompareOp, Term> = Term => ActionFn(78);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action78::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 18)
+    }
+    pub(crate) fn __reduce37<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<FactorOp, Unary> = Binary<FactorOp, Unary>, FactorOp, Unary => ActionFn(190);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action190::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 19)
+    }
+    pub(crate) fn __reduce38<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<FactorOp, Unary> = Unary => ActionFn(74);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 19)
+    }
+    pub(crate) fn __reduce39<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<OrOp, And> = Binary<OrOp, And>, OrOp, And => ActionFn(191);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action191::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 20)
+    }
+    pub(crate) fn __reduce40<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<OrOp, And> = And => ActionFn(82);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 20)
+    }
+    pub(crate) fn __reduce41<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<TermOp, Factor> = Binary<TermOp, Factor>, TermOp, Factor => ActionFn(192);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action192::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 21)
+    }
+    pub(crate) fn __reduce42<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Binary<TermOp, Factor> = Factor => ActionFn(76);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 21)
+    }
+    pub(crate) fn __reduce43<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ClosedStm = WHILE, Exp, DO, ClosedStm => ActionFn(193);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action193::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (4, __symbol, 22)
+    }
+    pub(crate) fn __reduce44<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ClosedStm = FOR, ID, ASSIGN, Exp, TO, Exp, DO, ClosedStm => ActionFn(194);
+        let __sym7 = __pop_Variant5(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action194::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (8, __symbol, 22)
+    }
+    pub(crate) fn __reduce45<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ClosedStm = Var, ASSIGN, Exp => ActionFn(195);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action195::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 22)
+    }
+    pub(crate) fn __reduce46<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ClosedStm = error => ActionFn(196);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action196::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 22)
+    }
+    pub(crate) fn __reduce47<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ClosedStm = Exp => ActionFn(37);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 22)
+    }
+    pub(crate) fn __reduce48<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Exp> = Exp => ActionFn(225);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action225::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce49<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Exp> =  => ActionFn(226);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action226::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (0, __symbol, 23)
+    }
+    pub(crate) fn __reduce50<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Exp> = Exp, (COMMA <Exp>)+ => ActionFn(227);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action227::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (2, __symbol, 23)
+    }
+    pub(crate) fn __reduce51<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Exp> = (COMMA <Exp>)+ => ActionFn(228);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action228::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce52<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Field> = Field => ActionFn(229);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action229::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (1, __symbol, 24)
+    }
+    pub(crate) fn __reduce53<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Field> =  => ActionFn(230);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action230::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (0, __symbol, 24)
+    }
+    pub(crate) fn __reduce54<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Field> = Field, (COMMA <Field>)+ => ActionFn(231);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action231::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (2, __symbol, 24)
+    }
+    pub(crate) fn __reduce55<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<Field> = (COMMA <Field>)+ => ActionFn(232);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action232::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (1, __symbol, 24)
+    }
+    pub(crate) fn __reduce56<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<FieldDec> = FieldDec => ActionFn(233);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action233::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 25)
+    }
+    pub(crate) fn __reduce57<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<FieldDec> =  => ActionFn(234);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action234::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (0, __symbol, 25)
+    }
+    pub(crate) fn __reduce58<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<FieldDec> = FieldDec, (COMMA <FieldDec>)+ => ActionFn(235);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action235::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (2, __symbol, 25)
+    }
+    pub(crate) fn __reduce59<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Comma<FieldDec> = (COMMA <FieldDec>)+ => ActionFn(236);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action236::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (1, __symbol, 25)
+    }
+    pub(crate) fn __reduce60<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Compare = Binary<CompareOp, Term> => ActionFn(46);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action46::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 26)
+    }
+    pub(crate) fn __reduce61<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = GE => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce62<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = GT => ActionFn(48);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action48::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce63<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = LE => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce64<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = LT => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action50::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce65<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = NEQ => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action51::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce66<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // CompareOp = EQ => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 27)
+    }
+    pub(crate) fn __reduce67<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ComplexVar = Var, DOT, ID => ActionFn(197);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action197::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (3, __symbol, 28)
+    }
+    pub(crate) fn __reduce68<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ComplexVar = ID, LBRACE, Exp, RBRACE => ActionFn(198);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action198::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (4, __symbol, 28)
+    }
+    pub(crate) fn __reduce69<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // ComplexVar = ComplexVar, LBRACE, Exp, RBRACE => ActionFn(199);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action199::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (4, __symbol, 28)
+    }
+    pub(crate) fn __reduce70<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Decs = TypeDecRec => ActionFn(2);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 29)
+    }
+    pub(crate) fn __reduce71<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Decs = VarDecRec => ActionFn(3);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 29)
+    }
+    pub(crate) fn __reduce72<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Decs = FunDecRec => ActionFn(4);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 29)
+    }
+    pub(crate) fn __reduce73<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp = IF, Exp, THEN, ClosedStm, ELSE, ClosedStm => ActionFn(200);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action200::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (6, __symbol, 30)
+    }
+    pub(crate) fn __reduce74<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp = LET, Decs, IN, Semicolon<Stm>, END => ActionFn(201);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant13(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action201::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (5, __symbol, 30)
+    }
+    pub(crate) fn __reduce75<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp = ID, LBRACE, Exp, RBRACE, OF, Or => ActionFn(202);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action202::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (6, __symbol, 30)
+    }
+    pub(crate) fn __reduce76<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp = Or => ActionFn(41);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 30)
+    }
+    pub(crate) fn __reduce77<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp? = Exp => ActionFn(109);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (1, __symbol, 31)
+    }
+    pub(crate) fn __reduce78<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Exp? =  => ActionFn(110);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action110::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (0, __symbol, 31)
+    }
+    pub(crate) fn __reduce79<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Factor = Binary<FactorOp, Unary> => ActionFn(56);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 32)
+    }
+    pub(crate) fn __reduce80<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FactorOp = DIV => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action57::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce81<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FactorOp = MUL => ActionFn(58);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action58::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce82<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FactorOp = MOD => ActionFn(59);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce83<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Field = ID, EQ, Exp => ActionFn(203);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action203::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (3, __symbol, 34)
+    }
+    pub(crate) fn __reduce84<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Field? = Field => ActionFn(104);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action104::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant19(__nt), __end);
+        (1, __symbol, 35)
+    }
+    pub(crate) fn __reduce85<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Field? =  => ActionFn(105);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action105::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant19(__nt), __end);
+        (0, __symbol, 35)
+    }
+    pub(crate) fn __reduce86<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FieldDec = ID, COLON, ID => ActionFn(204);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action204::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (3, __symbol, 36)
+    }
+    pub(crate) fn __reduce87<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FieldDec? = FieldDec => ActionFn(94);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action94::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant20(__nt), __end);
+        (1, __symbol, 37)
+    }
+    pub(crate) fn __reduce88<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FieldDec? =  => ActionFn(95);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action95::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant20(__nt), __end);
+        (0, __symbol, 37)
+    }
+    pub(crate) fn __reduce89<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDec = FUNCTION, ID, LPAREN, Comma<FieldDec>, RPAREN, EQ, Stm => ActionFn(205);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action205::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (7, __symbol, 38)
+    }
+    pub(crate) fn __reduce90<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDec = FUNCTION, ID, LPAREN, Comma<FieldDec>, RPAREN, COLON, ID, EQ, Stm => ActionFn(206);
+        let __sym8 = __pop_Variant5(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant1(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym8.2.clone();
+        let __nt = super::__action206::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (9, __symbol, 38)
+    }
+    pub(crate) fn __reduce91<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDec+ = FunDec => ActionFn(84);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action84::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (1, __symbol, 39)
+    }
+    pub(crate) fn __reduce92<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDec+ = FunDec+, FunDec => ActionFn(85);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action85::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (2, __symbol, 39)
+    }
+    pub(crate) fn __reduce93<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDecRec = FunDec+, TypeDecRec => ActionFn(207);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action207::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 40)
+    }
+    pub(crate) fn __reduce94<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDecRec = FunDec+, VarDecRec => ActionFn(208);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action208::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 40)
+    }
+    pub(crate) fn __reduce95<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // FunDecRec = FunDec+ => ActionFn(209);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action209::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 40)
+    }
+    pub(crate) fn __reduce96<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // OpenStm = IF, Exp, THEN, Stm => ActionFn(210);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action210::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (4, __symbol, 41)
+    }
+    pub(crate) fn __reduce97<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // OpenStm = IF, Exp, THEN, ClosedStm, ELSE, OpenStm => ActionFn(211);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action211::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (6, __symbol, 41)
+    }
+    pub(crate) fn __reduce98<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // OpenStm = WHILE, Exp, DO, OpenStm => ActionFn(212);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action212::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (4, __symbol, 41)
+    }
+    pub(crate) fn __reduce99<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // OpenStm = FOR, ID, ASSIGN, Exp, TO, Exp, DO, OpenStm => ActionFn(213);
+        let __sym7 = __pop_Variant5(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action213::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (8, __symbol, 41)
+    }
+    pub(crate) fn __reduce100<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Or = Binary<OrOp, And> => ActionFn(42);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 42)
+    }
+    pub(crate) fn __reduce101<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // OrOp = LOR => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 43)
+    }
+    pub(crate) fn __reduce102<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Program = Stm => ActionFn(1);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 44)
+    }
+    pub(crate) fn __reduce103<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Semicolon<Stm> = Stm => ActionFn(237);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action237::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce104<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Semicolon<Stm> =  => ActionFn(238);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action238::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (0, __symbol, 45)
+    }
+    pub(crate) fn __reduce105<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Semicolon<Stm> = Stm, (SEMICOLON <Stm>)+ => ActionFn(239);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action239::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (2, __symbol, 45)
+    }
+    pub(crate) fn __reduce106<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Semicolon<Stm> = (SEMICOLON <Stm>)+ => ActionFn(240);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action240::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (1, __symbol, 45)
+    }
+    pub(crate) fn __reduce107<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Stm = OpenStm => ActionFn(27);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 46)
+    }
+    pub(crate) fn __reduce108<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Stm = ClosedStm => ActionFn(28);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 46)
+    }
+    pub(crate) fn __reduce109<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Stm? = Stm => ActionFn(99);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (1, __symbol, 47)
+    }
+    pub(crate) fn __reduce110<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Stm? =  => ActionFn(100);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action100::<>(errors, &__start, &__end);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (0, __symbol, 47)
+    }
+    pub(crate) fn __reduce111<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Term = Binary<TermOp, Factor> => ActionFn(53);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 48)
+    }
+    pub(crate) fn __reduce112<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TermOp = ADD => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 49)
+    }
+    pub(crate) fn __reduce113<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TermOp = SUB => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (1, __symbol, 49)
+    }
+    pub(crate) fn __reduce114<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Type = ID => ActionFn(214);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action214::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant23(__nt), __end);
+        (1, __symbol, 50)
+    }
+    pub(crate) fn __reduce115<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Type = LBRACK, Comma<FieldDec>, RBRACK => ActionFn(215);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action215::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant23(__nt), __end);
+        (3, __symbol, 50)
+    }
+    pub(crate) fn __reduce116<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Type = ARRAY, OF, ID => ActionFn(216);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action216::<>(errors, __sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant23(__nt), __end);
+        (3, __symbol, 50)
+    }
+    pub(crate) fn __reduce117<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDec = TYPE, ID, EQ, Type => ActionFn(217);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action217::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant24(__nt), __end);
+        (4, __symbol, 51)
+    }
+    pub(crate) fn __reduce118<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDec+ = TypeDec => ActionFn(88);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action88::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant25(__nt), __end);
+        (1, __symbol, 52)
+    }
+    pub(crate) fn __reduce119<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDec+ = TypeDec+, TypeDec => ActionFn(89);
+        let __sym1 = __pop_Variant24(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action89::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant25(__nt), __end);
+        (2, __symbol, 52)
+    }
+    pub(crate) fn __reduce120<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDecRec = TypeDec+, VarDecRec => ActionFn(218);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action218::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 53)
+    }
+    pub(crate) fn __reduce121<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDecRec = TypeDec+, FunDecRec => ActionFn(219);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action219::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 53)
+    }
+    pub(crate) fn __reduce122<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // TypeDecRec = TypeDec+ => ActionFn(220);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action220::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 53)
+    }
+    pub(crate) fn __reduce123<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Unary = SUB, Unary => ActionFn(221);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action221::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (2, __symbol, 54)
+    }
+    pub(crate) fn __reduce124<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Unary = Atom => ActionFn(61);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 54)
+    }
+    pub(crate) fn __reduce125<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Var = ID => ActionFn(222);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action222::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 55)
+    }
+    pub(crate) fn __reduce126<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // Var = ComplexVar => ActionFn(18);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action18::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 55)
+    }
+    pub(crate) fn __reduce127<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // VarDec = VAR, ID, ASSIGN, Exp => ActionFn(223);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action223::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant26(__nt), __end);
+        (4, __symbol, 56)
+    }
+    pub(crate) fn __reduce128<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // VarDec = VAR, ID, COLON, ID, ASSIGN, Exp => ActionFn(224);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action224::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant26(__nt), __end);
+        (6, __symbol, 56)
+    }
+    pub(crate) fn __reduce129<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // VarDecRec = VarDec, Decs => ActionFn(13);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action13::<>(errors, __sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 57)
+    }
+    pub(crate) fn __reduce130<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+        __action: i16,
+        __lookahead_start: Option<&ByteIndex>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(ByteIndex,__Symbol<>,ByteIndex)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (ByteIndex,__Symbol<>,ByteIndex), usize)
+    {
+        // VarDecRec = VarDec => ActionFn(14);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(errors, __sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 57)
+    }
+}
+pub use self::__parse__Program::ProgramParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rest, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        let mut rest = rest;
+        rest.insert(0, ast::Dec::Type(decs, (l, r).into_span()));
+        rest
+    }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rest, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        let mut rest = rest;
+        rest.insert(0, ast::Dec::Type(decs, (l, r).into_span()));
+        rest
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        vec![ast::Dec::Type(decs, (l, r).into_span())]
+    }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ty, _): (ByteIndex, ast::Type, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::TypeDec
+{
+    {
+        ast::TypeDec { name, name_span: (ln, rn).into_span(), ty, span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    {
+        ast::Type::Name(name, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, fields, _): (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    {
+        ast::Type::Rec(fields, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    {
+        ast::Type::Arr(name, (ln, r).into_span(), (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lt, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, ty, _): (ByteIndex, Symbol, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FieldDec
+{
+    {
+        ast::FieldDec {
+          name,
+          name_span: (l, rn).into_span(),
+          escape: true,
+          ty,
+          ty_span: (lt, r).into_span(),
+          span: (l, r).into_span()
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, dec, _): (ByteIndex, ast::Dec, ByteIndex),
+    (_, rest, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        let mut rest = rest;
+        rest.insert(0, dec);
+        rest
+    }
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, dec, _): (ByteIndex, ast::Dec, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        vec![dec]
+    }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, init, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Dec
+{
+    {
+        ast::Dec::Var {
+            name,
+            name_span: (ln, rn).into_span(),
+            escape: true,
+            ty: None,
+            ty_span: None,
+            init,
+            span: (l, r).into_span()
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lt, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, ty, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rt, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, init, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Dec
+{
+    {
+        ast::Dec::Var {
+            name,
+            name_span: (ln, rn).into_span(),
+            escape: true,
+            ty: Some(ty),
+            ty_span: Some((lt, rt).into_span()),
+            init,
+            span: (l, r).into_span()
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    {
+        ast::Var::Simple(name, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Var, ByteIndex),
+) -> ast::Var
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, ast::Var, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lf, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, field, _): (ByteIndex, Symbol, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    {
+        ast::Var::Field(Box::new(name), field, (lf, r).into_span(), (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, idx, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    {
+        let var = ast::Var::Simple(name, (l, rn).into_span());
+        ast::Var::Index(Box::new(var), Box::new(idx), (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, ast::Var, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, idx, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    {
+        ast::Var::Index(Box::new(name), Box::new(idx), (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rest, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        let mut rest = rest;
+        rest.insert(0, ast::Dec::Fun(decs, (l, r).into_span()));
+        rest
+    }
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rest, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        let mut rest = rest;
+        rest.insert(0, ast::Dec::Fun(decs, (l, r).into_span()));
+        rest
+    }
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, decs, _): (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    {
+        vec![ast::Dec::Fun(decs, (l, r).into_span())]
+    }
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, args, _): (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FunDec
+{
+    {
+        ast::FunDec {
+            name,
+            name_span: (ln, rn).into_span(),
+            args,
+            rets: None,
+            rets_span: None,
+            body,
+            span: (l, r).into_span()
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, ln, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, args, _): (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lr, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rets, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rr, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FunDec
+{
+    {
+        ast::FunDec {
+            name,
+            name_span: (ln, rn).into_span(),
+            args,
+            rets: Some(rets),
+            rets_span: Some((lr, rr).into_span()),
+            body,
+            span: (l, r).into_span()
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, guard, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, then, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::If { guard: Box::new(guard), then: Box::new(then), or: None, span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, guard, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, then, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, or, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::If { guard: Box::new(guard), then: Box::new(then), or: Some(Box::new(or)), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, guard, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::While { guard: Box::new(guard), body: Box::new(body), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lo, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, hi, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::For { name, escape: true, lo: Box::new(lo), hi: Box::new(hi), body: Box::new(body), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, guard, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::While { guard: Box::new(guard), body: Box::new(body), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lo, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, hi, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::For { name, escape: true, lo: Box::new(lo), hi: Box::new(hi), body: Box::new(body), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, ast::Var, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, exp, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Ass { name, exp: Box::new(exp), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, error, _): (ByteIndex, __lalrpop_util::ErrorRecovery<ByteIndex, Token, Error>, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        errors.push(error);
+        ast::Exp::Nil((l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, guard, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, body, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, or, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::If { guard: Box::new(guard), then: Box::new(body), or: Some(Box::new(or)), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, decs, _): (ByteIndex, Vec<ast::Dec>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, lb, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, body, _): (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    (_, rb, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Let { decs, body: Box::new(ast::Exp::Seq(body, (lb, rb).into_span())), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, size, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, init, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Arr { name, name_span: (l, rn).into_span(), size: Box::new(size), init: Box::new(init), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::LOr
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::LAnd
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Ge
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Gt
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Le
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Lt
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Neq
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Eq
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Add
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Sub
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Div
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Mul
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, Token, ByteIndex),
+) -> ast::Binop
+{
+    ast::Binop::Mod
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, exp, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Neg(Box::new(exp), (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, v, _): (ByteIndex, ast::Var, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Var(v, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, val, _): (ByteIndex, i32, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Int(val, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, val, _): (ByteIndex, String, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Str(val, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Nil((l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, fields, _): (ByteIndex, Vec<ast::Field>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Rec { name, name_span: (l, rn).into_span(), fields, span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, args, _): (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Call { name, name_span: (l, rn).into_span(), args, span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, exps, _): (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Seq(exps, (l, r).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, __1, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Break((__0, __1).into_span())
+    }
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, name, _): (ByteIndex, Symbol, ByteIndex),
+    (_, rn, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, exp, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Field
+{
+    {
+        ast::Field { name, name_span: (l, rn).into_span(), exp: Box::new(exp), span: (l, r).into_span() }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, h, _): (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+    (_, t, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    {
+        match h {
+        | None        => t,
+        | Some(e) => {
+            let mut v = vec![e];
+            v.extend(t);
+            v
+        }}
+    }
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, h, _): (ByteIndex, ::std::option::Option<ast::Field>, ByteIndex),
+    (_, t, _): (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+) -> Vec<ast::Field>
+{
+    {
+        match h {
+        | None        => t,
+        | Some(e) => {
+            let mut v = vec![e];
+            v.extend(t);
+            v
+        }}
+    }
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, lhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, lo, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, op, _): (ByteIndex, ast::Binop, ByteIndex),
+    (_, ro, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Bin {
+            lhs: Box::new(lhs),
+            op,
+            op_span: (lo, ro).into_span(),
+            rhs: Box::new(rhs),
+            span: (l, r).into_span(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, lhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, lo, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, op, _): (ByteIndex, ast::Binop, ByteIndex),
+    (_, ro, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Bin {
+            lhs: Box::new(lhs),
+            op,
+            op_span: (lo, ro).into_span(),
+            rhs: Box::new(rhs),
+            span: (l, r).into_span(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, lhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, lo, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, op, _): (ByteIndex, ast::Binop, ByteIndex),
+    (_, ro, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Bin {
+            lhs: Box::new(lhs),
+            op,
+            op_span: (lo, ro).into_span(),
+            rhs: Box::new(rhs),
+            span: (l, r).into_span(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, lhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, lo, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, op, _): (ByteIndex, ast::Binop, ByteIndex),
+    (_, ro, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Bin {
+            lhs: Box::new(lhs),
+            op,
+            op_span: (lo, ro).into_span(),
+            rhs: Box::new(rhs),
+            span: (l, r).into_span(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, l, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, lhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, lo, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, op, _): (ByteIndex, ast::Binop, ByteIndex),
+    (_, ro, _): (ByteIndex, ByteIndex, ByteIndex),
+    (_, rhs, _): (ByteIndex, ast::Exp, ByteIndex),
+    (_, r, _): (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    {
+        ast::Exp::Bin {
+            lhs: Box::new(lhs),
+            op,
+            op_span: (lo, ro).into_span(),
+            rhs: Box::new(rhs),
+            span: (l, r).into_span(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, h, _): (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+    (_, t, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    {
+        match h {
+        | None        => t,
+        | Some(e) => {
+            let mut v = vec![e];
+            v.extend(t);
+            v
+        }}
+    }
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::FunDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FunDec>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::FunDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FunDec>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, h, _): (ByteIndex, ::std::option::Option<ast::FieldDec>, ByteIndex),
+    (_, t, _): (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    {
+        match h {
+        | None        => t,
+        | Some(e) => {
+            let mut v = vec![e];
+            v.extend(t);
+            v
+        }}
+    }
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ByteIndex
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::TypeDec, ByteIndex),
+) -> ::std::vec::Vec<ast::TypeDec>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::TypeDec, ByteIndex),
+) -> ::std::vec::Vec<ast::TypeDec>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ByteIndex
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, __0, _): (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ast::FieldDec
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ::std::option::Option<ast::FieldDec>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::option::Option<ast::FieldDec>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::vec::Vec<ast::Exp>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::option::Option<ast::Exp>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::option::Option<ast::Exp>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::vec::Vec<ast::Field>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+) -> ::std::vec::Vec<ast::Field>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, __0, _): (ByteIndex, ast::Field, ByteIndex),
+) -> ast::Field
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Field, ByteIndex),
+) -> ::std::option::Option<ast::Field>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::option::Option<ast::Field>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::vec::Vec<ast::Exp>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, _, _): (ByteIndex, Token, ByteIndex),
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::option::Option<ast::Exp>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> ::std::option::Option<ast::Exp>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Field, ByteIndex),
+) -> ::std::vec::Vec<ast::Field>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::Field, ByteIndex),
+) -> ::std::vec::Vec<ast::Field>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, __0, _): (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    (_, v, _): (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+    (_, e, _): (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action108(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action108(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action112(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action106(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action71(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action107(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action71(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Field, ByteIndex),
+) -> ::std::vec::Vec<ast::Field>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action103(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action113(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Field, ByteIndex),
+) -> ::std::vec::Vec<ast::Field>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action103(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action114(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action125<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Field>, ByteIndex),
+) -> Vec<ast::Field>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action101(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action126<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Field>, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+) -> Vec<ast::Field>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action102(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action127<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action93(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action128<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::FieldDec, ByteIndex),
+) -> ::std::vec::Vec<ast::FieldDec>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action93(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::FieldDec>, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action91(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::FieldDec>, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action92(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action98(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action115(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ::std::vec::Vec<ast::Exp>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action98(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action116(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action133<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action96(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action83(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action134<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::option::Option<ast::Exp>, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action97(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action83(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action135<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action136<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, i32, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action63(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action137<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, String, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action64(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action138<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action139<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::Field>, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action140<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action67(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action141<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action142<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action143<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action79(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action144<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action77(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action145<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action73(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action146<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action81(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action147<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action75(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action148<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action33(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action149<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+    __8: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action150<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+    __3: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action35(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action151<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, __lalrpop_util::ErrorRecovery<ByteIndex, Token, Error>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action36(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action152<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Symbol, ByteIndex),
+    __3: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __2.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __temp1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action153<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action154<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action155<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action38(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action156<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __3.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action39(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __temp1,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action157<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, ast::Exp, ByteIndex),
+    __7: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action40(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action158<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Field
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action159<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Symbol, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FieldDec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __3.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action12(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __temp1,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action160<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+    __8: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FunDec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action25(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action161<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, Symbol, ByteIndex),
+    __8: (ByteIndex, ByteIndex, ByteIndex),
+    __9: (ByteIndex, Token, ByteIndex),
+    __10: (ByteIndex, ast::Exp, ByteIndex),
+    __11: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::FunDec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __start2 = __6.2.clone();
+    let __end2 = __7.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    let __temp2 = __action90(
+        errors,
+        &__start2,
+        &__end2,
+    );
+    let __temp2 = (__start2, __temp2, __end2);
+    __action26(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp2,
+        __7,
+        __8,
+        __9,
+        __10,
+        __11,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action162<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action163<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action164<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action165<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action29(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action166<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action167<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action31(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action168<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+    __8: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action32(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action169<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action170<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action171<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Symbol, ByteIndex),
+    __3: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __1.2.clone();
+    let __end1 = __2.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action11(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __temp1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action172<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, ast::Type, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::TypeDec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action8(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action173<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action174<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action175<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action176<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action177<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action178<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, ast::Exp, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Dec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action15(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action179<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, ByteIndex, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, Symbol, ByteIndex),
+    __5: (ByteIndex, ByteIndex, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+    __8: (ByteIndex, ByteIndex, ByteIndex),
+) -> ast::Dec
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __start1 = __0.2.clone();
+    let __end1 = __1.0.clone();
+    let __start2 = __3.2.clone();
+    let __end2 = __4.0.clone();
+    let __temp0 = __action90(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    let __temp2 = __action90(
+        errors,
+        &__start2,
+        &__end2,
+    );
+    let __temp2 = (__start2, __temp2, __end2);
+    __action16(
+        errors,
+        __temp0,
+        __0,
+        __temp1,
+        __1,
+        __2,
+        __3,
+        __temp2,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action180<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action135(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action181<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, i32, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action182<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, String, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action137(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action183<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action184<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Field>, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action139(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action185<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action140(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action186<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action187<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action142(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action188<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action143(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action189<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action144(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action190<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action145(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action191<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action146(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action192<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ast::Binop, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action147(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action193<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action148(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action194<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action149(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action195<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action150(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action196<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, __lalrpop_util::ErrorRecovery<ByteIndex, Token, Error>, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action197<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Symbol, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action152(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action198<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action153(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action199<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Var, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action200<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action201<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::Exp>, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __start1 = __4.2.clone();
+    let __end1 = __4.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action156(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action202<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+    __3: (ByteIndex, Token, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __5.2.clone();
+    let __end1 = __5.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action157(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action203<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Field
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action158(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action204<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Symbol, ByteIndex),
+) -> ast::FieldDec
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action159(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action205<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::FunDec
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __6.2.clone();
+    let __end1 = __6.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action160(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action206<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, Token, ByteIndex),
+    __6: (ByteIndex, Symbol, ByteIndex),
+    __7: (ByteIndex, Token, ByteIndex),
+    __8: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::FunDec
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __6.2.clone();
+    let __end1 = __7.0.clone();
+    let __start2 = __8.2.clone();
+    let __end2 = __8.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    let __temp2 = __action87(
+        errors,
+        &__start2,
+        &__end2,
+    );
+    let __temp2 = (__start2, __temp2, __end2);
+    __action161(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp1,
+        __7,
+        __8,
+        __temp2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action207<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action208<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action209<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FunDec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action210<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action211<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action166(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action212<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action167(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action213<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+    __6: (ByteIndex, Token, ByteIndex),
+    __7: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action168(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action214<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action169(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action215<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Vec<ast::FieldDec>, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action170(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action216<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Token, ByteIndex),
+    __2: (ByteIndex, Symbol, ByteIndex),
+) -> ast::Type
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action171(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action217<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Type, ByteIndex),
+) -> ast::TypeDec
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action172(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action218<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action219<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+    __1: (ByteIndex, Vec<ast::Dec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action220<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::TypeDec>, ByteIndex),
+) -> Vec<ast::Dec>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action175(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action221<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Exp
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action176(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action222<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Symbol, ByteIndex),
+) -> ast::Var
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action223<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Dec
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action178(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action224<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, Token, ByteIndex),
+    __1: (ByteIndex, Symbol, ByteIndex),
+    __2: (ByteIndex, Token, ByteIndex),
+    __3: (ByteIndex, Symbol, ByteIndex),
+    __4: (ByteIndex, Token, ByteIndex),
+    __5: (ByteIndex, ast::Exp, ByteIndex),
+) -> ast::Dec
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __4.0.clone();
+    let __start2 = __5.2.clone();
+    let __end2 = __5.2.clone();
+    let __temp0 = __action87(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action87(
+        errors,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    let __temp2 = __action87(
+        errors,
+        &__start2,
+        &__end2,
+    );
+    let __temp2 = (__start2, __temp2, __end2);
+    __action179(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+        __4,
+        __5,
+        __temp2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action225<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action109(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action121(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action226<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> Vec<ast::Exp>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action110(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action121(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action227<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action109(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action122(
+        errors,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action228<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action110(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action122(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action229<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Field, ByteIndex),
+) -> Vec<ast::Field>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action104(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action125(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action230<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> Vec<ast::Field>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action105(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action125(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action231<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Field, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+) -> Vec<ast::Field>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action104(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        errors,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action232<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Field>, ByteIndex),
+) -> Vec<ast::Field>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action105(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action233<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::FieldDec, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action94(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action234<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action95(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action235<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::FieldDec, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action94(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action236<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::FieldDec>, ByteIndex),
+) -> Vec<ast::FieldDec>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action95(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action237<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action238<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __lookbehind: &ByteIndex,
+    __lookahead: &ByteIndex,
+) -> Vec<ast::Exp>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action100(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action239<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ast::Exp, ByteIndex),
+    __1: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action99(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        errors,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action240<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<ByteIndex, Token, Error>>,
+    __0: (ByteIndex, ::std::vec::Vec<ast::Exp>, ByteIndex),
+) -> Vec<ast::Exp>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action100(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+pub trait __ToTriple<'err, > {
+    type Error;
+    fn to_triple(value: Self) -> Result<(ByteIndex,Token,ByteIndex),Self::Error>;
+}
+
+impl<'err, > __ToTriple<'err, > for (ByteIndex, Token, ByteIndex) {
+    type Error = Error;
+    fn to_triple(value: Self) -> Result<(ByteIndex,Token,ByteIndex),Error> {
+        Ok(value)
+    }
+}
+impl<'err, > __ToTriple<'err, > for Result<(ByteIndex, Token, ByteIndex),Error> {
+    type Error = Error;
+    fn to_triple(value: Self) -> Result<(ByteIndex,Token,ByteIndex),Error> {
+        value
+    }
+}
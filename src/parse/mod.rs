@@ -1,10 +1,27 @@
+// Error recovery generates a loop that always exits on its first iteration
+#[allow(clippy::never_loop)]
 mod grammar;
 
 use ast::Exp;
 use lex::TokenStream;
 use error::Error;
 
-pub fn parse(input: TokenStream) -> Result<Exp, Error> {
+/// Parses `input`, recovering from syntax errors to report as many as
+/// possible. Fails with every error found, in order, if there are any.
+pub fn parse(input: TokenStream) -> Result<Exp, Vec<Error>> {
+    let mut recovered = Vec::new();
     let parser = grammar::ProgramParser::new();
-    parser.parse(input).map_err(|err| err.into())
+    let result = parser.parse(&mut recovered, input);
+
+    let mut errors = recovered.into_iter()
+        .map(|recovery| recovery.error.into())
+        .collect::<Vec<Error>>();
+
+    match result {
+    | Err(err) => errors.push(err.into()),
+    | Ok(exp) if errors.is_empty() => return Ok(exp),
+    | Ok(_) => (),
+    }
+
+    Err(errors)
 }
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::fmt;
 use std::fs::File;
 use std::io::Write;
@@ -6,6 +7,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use codespan::{CodeMap, FileMap};
+use simple_symbol::store;
 
 use ast;
 use ir;
@@ -17,9 +19,10 @@ use check;
 use translate;
 use assemble;
 use optimize;
+use config;
 
-use error::Error;
-use operand::{Temp, Reg};
+use error::{Error, Warning};
+use operand::{Label, Temp, Reg};
 
 pub enum Item {
     Source(Arc<FileMap>),
@@ -53,6 +56,9 @@ pub struct Compiler {
     phases: Vec<Box<Phase>>, 
     code: CodeMap,
     path: PathBuf,
+    entry: Label,
+    warnings: RefCell<Vec<Warning>>,
+    errors: RefCell<Vec<Error>>,
 }
 
 impl Compiler {
@@ -62,6 +68,9 @@ impl Compiler {
             phases: Vec::new(),
             code: CodeMap::default(),
             path: path.into(),
+            entry: Label::from_fixed(config::ENTRY),
+            warnings: RefCell::new(Vec::new()),
+            errors: RefCell::new(Vec::new()),
         }
     }
 
@@ -70,6 +79,12 @@ impl Compiler {
         self
     }
 
+    /// Names the top-level expression's function `entry` instead of `main`.
+    pub fn with_entry(mut self, entry: &str) -> Self {
+        self.entry = Label::Fixed(store(entry));
+        self
+    }
+
     pub fn run(&mut self) -> Result<Item, Error> {
         let map = self.code.add_filemap_from_disk(&self.path)
             .expect("Internal error: IO")
@@ -86,10 +101,32 @@ impl Compiler {
             })
     }
 
+    pub fn entry(&self) -> Label {
+        self.entry
+    }
+
     pub fn code(&self) -> &CodeMap {
         &self.code
     }
 
+    pub fn warn(&self, warnings: Vec<Warning>) {
+        self.warnings.borrow_mut().extend(warnings);
+    }
+
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Records errors found after the one a phase fails with,
+    /// to be reported along with it.
+    pub fn report(&self, errors: Vec<Error>) {
+        self.errors.borrow_mut().extend(errors);
+    }
+
+    pub fn errors(&self) -> Vec<Error> {
+        self.errors.borrow().clone()
+    }
+
     fn write(&self, ext: &'static str, item: &Result<Item, Error>) {
         let output = self.path.with_extension(ext);
         let mut outfile = File::create(output)
@@ -97,21 +134,29 @@ impl Compiler {
 
         match item {
         | Ok(item) => write!(outfile, "{}", item).expect("Internal error: IO"),
-        | Err(err) => write!(outfile, "{}", err.to_debug(&self.code)).expect("Internal error: IO"),
+        | Err(err) => {
+            write!(outfile, "{}", err.to_debug(&self.code)).expect("Internal error: IO");
+            for err in self.errors.borrow().iter() {
+                write!(outfile, "\n{}", err.to_debug(&self.code)).expect("Internal error: IO");
+            }
+        },
         };
     }
 }
 
 macro_rules! impl_phase {
     ($phase:ident, $ext:expr, $item:pat => $result:expr) => {
+        impl_phase!($phase, $ext, |compiler| $item => $result);
+    };
+    ($phase:ident, $ext:expr, |$compiler:ident| $item:pat => $result:expr) => {
         impl Phase for $phase {
-            fn process(&self, compiler: &Compiler, input: Item) -> Result<Item, Error> {
+            fn process(&self, $compiler: &Compiler, input: Item) -> Result<Item, Error> {
                 if self.1 { return Ok(input) }
 
                 match input {
                 | $item => {
                     let result = $result;
-                    if self.0 { compiler.write($ext, &result); }
+                    if self.0 { $compiler.write($ext, &result); }
                     result
                 }
                 | _ => panic!("Internal error: incorrect phase input"),
@@ -139,15 +184,66 @@ impl_phase! (Lex, "lexed", Item::Source(source) => {
 
 pub struct Parse(pub bool, pub bool);
 
-impl_phase! (Parse, "parsed", Item::Tokens(tokens) => {
-    parse::parse(tokens).map(|ast| Item::Syntax(ast))
+impl_phase! (Parse, "parsed", |compiler| Item::Tokens(tokens) => {
+    parse::parse(tokens)
+        .map(|ast| Item::Syntax(ast))
+        .map_err(|mut errors| {
+            let first = errors.remove(0);
+            compiler.report(errors);
+            first
+        })
 });
 
 
+pub struct Overflow(pub bool, pub bool);
+
+impl_phase! (Overflow, "strict", Item::Syntax(ast) => {
+    check::overflow(&ast).map(|_| Item::Syntax(ast))
+});
+
 pub struct Type(pub bool, pub bool);
 
-impl_phase! (Type, "typed", Item::Syntax(ast) => {
-    check::check(ast).map(|unit| Item::Typed(unit))
+impl_phase! (Type, "typed", |compiler| Item::Syntax(ast) => {
+    check::check_with_entry(ast, compiler.entry()).map(|(unit, warnings)| {
+        compiler.warn(warnings);
+        Item::Typed(unit)
+    })
+});
+
+pub struct Collect(pub bool, pub bool);
+
+impl_phase! (Collect, "collected", Item::Typed(unit) => {
+    Ok(Item::Typed(translate::collect(unit)))
+});
+
+pub struct Zeroed(pub bool, pub bool);
+
+impl_phase! (Zeroed, "zeroed", Item::Typed(unit) => {
+    Ok(Item::Typed(translate::zeroed(unit)))
+});
+
+pub struct Clear(pub bool, pub bool);
+
+impl_phase! (Clear, "cleared", Item::Typed(unit) => {
+    Ok(Item::Typed(translate::clear(unit)))
+});
+
+pub struct Bounds(pub bool, pub bool);
+
+impl_phase! (Bounds, "bounded", Item::Typed(unit) => {
+    Ok(Item::Typed(translate::bounds(unit)))
+});
+
+pub struct NonNull(pub bool, pub bool);
+
+impl_phase! (NonNull, "checked", Item::Typed(unit) => {
+    Ok(Item::Typed(translate::nonnull(unit)))
+});
+
+pub struct Exit(pub bool, pub bool);
+
+impl_phase! (Exit, "exited", |compiler| Item::Typed(unit) => {
+    Ok(Item::Typed(translate::exit_with(unit, compiler.entry())))
 });
 
 pub struct Canonize(pub bool, pub bool);
@@ -162,24 +258,66 @@ impl_phase! (Fold, "folded", Item::Intermediate(unit) => {
     Ok(Item::Intermediate(translate::fold(unit)))
 });
 
+pub struct Propagate(pub bool, pub bool);
+
+impl_phase! (Propagate, "propagated", Item::Intermediate(unit) => {
+    Ok(Item::Intermediate(translate::propagate(unit)))
+});
+
+pub struct Share(pub bool, pub bool);
+
+impl_phase! (Share, "shared", Item::Intermediate(unit) => {
+    Ok(Item::Intermediate(translate::share(unit)))
+});
+
+pub struct Eliminate(pub bool, pub bool);
+
+impl_phase! (Eliminate, "eliminated", Item::Intermediate(unit) => {
+    Ok(Item::Intermediate(translate::eliminate(unit)))
+});
+
 pub struct Reorder(pub bool, pub bool);
 
 impl_phase! (Reorder, "reordered", Item::Intermediate(unit) => {
     Ok(Item::Intermediate(translate::reorder(unit)))
 });
 
+pub struct Switch(pub bool, pub bool);
+
+impl_phase! (Switch, "switched", Item::Intermediate(unit) => {
+    Ok(Item::Intermediate(translate::switch(unit)))
+});
+
+pub struct Validate(pub bool, pub bool);
+
+impl_phase! (Validate, "validated", Item::Intermediate(unit) => {
+    translate::validate(&unit).map(|_| Item::Intermediate(unit))
+});
+
 pub struct Tile(pub bool, pub bool);
 
 impl_phase! (Tile, "tiled", Item::Intermediate(unit) => {
     Ok(Item::Abstract(assemble::tile(unit)))
 });
 
+pub struct Canary(pub bool, pub bool);
+
+impl_phase! (Canary, "guarded", Item::Abstract(unit) => {
+    Ok(Item::Abstract(assemble::canary(unit)))
+});
+
 pub struct Trivial(pub bool, pub bool);
 
 impl_phase! (Trivial, "s", Item::Abstract(unit) => {
     Ok(Item::Assembly(assemble::allocate::<assemble::Trivial>(unit)))
 });
 
+pub struct Spills(pub bool, pub bool);
+
+impl_phase! (Spills, "spilled", Item::Assembly(unit) => {
+    assemble::spills(&unit, config::MAX_SPILLS).map(|_| Item::Assembly(unit))
+});
+
 pub struct CoalesceAbstract(pub bool, pub bool);
 
 impl_phase! (CoalesceAbstract, "coalesced", Item::Abstract(unit) => {
@@ -192,3 +330,39 @@ pub struct CoalesceAssembly(pub bool, pub bool);
 impl_phase! (CoalesceAssembly, "s", Item::Assembly(unit) => {
     Ok(Item::Assembly(optimize::coalesce(unit)))
 });
+
+pub struct Prune(pub bool, pub bool);
+
+impl_phase! (Prune, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(optimize::prune(unit)))
+});
+
+pub struct Schedule(pub bool, pub bool);
+
+impl_phase! (Schedule, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(optimize::schedule(unit)))
+});
+
+pub struct Merge(pub bool, pub bool);
+
+impl_phase! (Merge, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(optimize::merge(unit)))
+});
+
+pub struct Zero(pub bool, pub bool);
+
+impl_phase! (Zero, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(optimize::zero(unit)))
+});
+
+pub struct Omit(pub bool, pub bool);
+
+impl_phase! (Omit, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(optimize::omit(unit)))
+});
+
+pub struct Stub(pub bool, pub bool);
+
+impl_phase! (Stub, "s", Item::Assembly(unit) => {
+    Ok(Item::Assembly(assemble::stub(unit)))
+});
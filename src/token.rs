@@ -1,9 +1,11 @@
 use std::fmt;
 
-use simple_symbol::Symbol;
+use simple_symbol::{store, Symbol};
+
+use util::escape;
 
 /// Represents all valid lexical tokens in the Tiger language.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Token {
 
     // Keywords
@@ -190,8 +192,122 @@ impl fmt::Display for Token {
         | Token::Colon         => write!(fmt, "SYMBOL :"),
         | Token::Comma         => write!(fmt, "SYMBOL ,"),
         | Token::Int(n)        => write!(fmt, "INTEGER {}", n),
-        | Token::Str(s)        => write!(fmt, "STRING \"{}\"", s),
+        | Token::Str(s)        => write!(fmt, "STRING \"{}\"", escape(s)),
         | Token::Ident(i)      => write!(fmt, "IDENTIFIER {}", i),
         }
     }
 }
+
+/// Human-friendly rendering of a token for diagnostics, e.g. `keyword 'then'`.
+/// The token's own `Display` is the format of the lexer's output instead.
+pub struct Description<'a>(&'a Token);
+
+impl Token {
+    pub fn describe<'a>(&'a self) -> Description<'a> {
+        Description(self)
+    }
+}
+
+impl <'a> fmt::Display for Description<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.0 {
+        | Token::Type          => write!(fmt, "keyword 'type'"),
+        | Token::Var           => write!(fmt, "keyword 'var'"),
+        | Token::Function      => write!(fmt, "keyword 'function'"),
+        | Token::Break         => write!(fmt, "keyword 'break'"),
+        | Token::Of            => write!(fmt, "keyword 'of'"),
+        | Token::End           => write!(fmt, "keyword 'end'"),
+        | Token::In            => write!(fmt, "keyword 'in'"),
+        | Token::Nil           => write!(fmt, "keyword 'nil'"),
+        | Token::Let           => write!(fmt, "keyword 'let'"),
+        | Token::Do            => write!(fmt, "keyword 'do'"),
+        | Token::To            => write!(fmt, "keyword 'to'"),
+        | Token::For           => write!(fmt, "keyword 'for'"),
+        | Token::While         => write!(fmt, "keyword 'while'"),
+        | Token::Else          => write!(fmt, "keyword 'else'"),
+        | Token::Then          => write!(fmt, "keyword 'then'"),
+        | Token::If            => write!(fmt, "keyword 'if'"),
+        | Token::Array         => write!(fmt, "keyword 'array'"),
+        | Token::Assign        => write!(fmt, "operator ':='"),
+        | Token::LOr           => write!(fmt, "operator '|'"),
+        | Token::LAnd          => write!(fmt, "operator '&'"),
+        | Token::Ge            => write!(fmt, "operator '>='"),
+        | Token::Gt            => write!(fmt, "operator '>'"),
+        | Token::Le            => write!(fmt, "operator '<='"),
+        | Token::Lt            => write!(fmt, "operator '<'"),
+        | Token::Neq           => write!(fmt, "operator '<>'"),
+        | Token::Eq            => write!(fmt, "operator '='"),
+        | Token::Div           => write!(fmt, "operator '/'"),
+        | Token::Mul           => write!(fmt, "operator '*'"),
+        | Token::Mod           => write!(fmt, "operator '%'"),
+        | Token::Sub           => write!(fmt, "operator '-'"),
+        | Token::Add           => write!(fmt, "operator '+'"),
+        | Token::Dot           => write!(fmt, "operator '.'"),
+        | Token::LBrace        => write!(fmt, "'['"),
+        | Token::RBrace        => write!(fmt, "']'"),
+        | Token::LBrack        => write!(fmt, "'{{'"),
+        | Token::RBrack        => write!(fmt, "'}}'"),
+        | Token::LParen        => write!(fmt, "'('"),
+        | Token::RParen        => write!(fmt, "')'"),
+        | Token::Semicolon     => write!(fmt, "';'"),
+        | Token::Colon         => write!(fmt, "':'"),
+        | Token::Comma         => write!(fmt, "','"),
+        | Token::Int(_)        => write!(fmt, "integer literal"),
+        | Token::Str(_)        => write!(fmt, "string literal"),
+        | Token::Ident(_)      => write!(fmt, "identifier"),
+        }
+    }
+}
+
+/// Describes the grammar terminal `name`, as listed in the expected set
+/// of a parse error, the same way as a token of that kind.
+pub fn describe(name: &str) -> String {
+    let token = match name {
+    | "TYPE"      => Token::Type,
+    | "VAR"       => Token::Var,
+    | "FUNCTION"  => Token::Function,
+    | "BREAK"     => Token::Break,
+    | "OF"        => Token::Of,
+    | "END"       => Token::End,
+    | "IN"        => Token::In,
+    | "NIL"       => Token::Nil,
+    | "LET"       => Token::Let,
+    | "DO"        => Token::Do,
+    | "TO"        => Token::To,
+    | "FOR"       => Token::For,
+    | "WHILE"     => Token::While,
+    | "ELSE"      => Token::Else,
+    | "THEN"      => Token::Then,
+    | "IF"        => Token::If,
+    | "ARRAY"     => Token::Array,
+    | "ASSIGN"    => Token::Assign,
+    | "LOR"       => Token::LOr,
+    | "LAND"      => Token::LAnd,
+    | "GE"        => Token::Ge,
+    | "GT"        => Token::Gt,
+    | "LE"        => Token::Le,
+    | "LT"        => Token::Lt,
+    | "NEQ"       => Token::Neq,
+    | "EQ"        => Token::Eq,
+    | "DIV"       => Token::Div,
+    | "MUL"       => Token::Mul,
+    | "MOD"       => Token::Mod,
+    | "SUB"       => Token::Sub,
+    | "ADD"       => Token::Add,
+    | "DOT"       => Token::Dot,
+    | "LBRACE"    => Token::LBrace,
+    | "RBRACE"    => Token::RBrace,
+    | "LBRACK"    => Token::LBrack,
+    | "RBRACK"    => Token::RBrack,
+    | "LPAREN"    => Token::LParen,
+    | "RPAREN"    => Token::RParen,
+    | "SEMICOLON" => Token::Semicolon,
+    | "COLON"     => Token::Colon,
+    | "COMMA"     => Token::Comma,
+    | "INT"       => Token::Int(0),
+    | "STR"       => Token::Str(String::new()),
+    | "ID"        => Token::Ident(store("")),
+    | _           => return name.to_string(),
+    };
+    token.describe().to_string()
+}
@@ -0,0 +1,168 @@
+use fnv::FnvHashMap;
+
+use config::WORD_SIZE;
+use ir::*;
+use operand::{Label, Temp};
+
+/// Runtime functions returning a new array, with its element count
+/// in the word before the first element.
+const ALLOCATORS: [&str; 2] = ["init_array", "__alloc_array__"];
+
+/// Checks every array index against the array's length before accessing it,
+/// calling the runtime's `__bounds__` if it's out of range. Constant indices
+/// into arrays of constant size are decided here instead: the check is
+/// dropped if the index is in range, and only the call is left if it isn't.
+pub fn bounds(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(|body| {
+            let sizes = sizes(&body);
+            body.into_iter()
+                .map(|stm| bounds_stm(&sizes, stm))
+                .collect()
+        })
+    })
+}
+
+/// Sizes of arrays held in temps assigned exactly once, from a constant-sized allocation.
+fn sizes(body: &[Stm]) -> FnvHashMap<Temp, i32> {
+    let mut defs = FnvHashMap::default();
+    let mut sizes = FnvHashMap::default();
+    for stm in body { collect_stm(stm, &mut defs, &mut sizes) }
+    sizes.retain(|temp, _| defs[temp] == 1);
+    sizes
+}
+
+fn collect_stm(stm: &Stm, defs: &mut FnvHashMap<Temp, usize>, sizes: &mut FnvHashMap<Temp, i32>) {
+    match stm {
+    | Stm::Move(src, Exp::Temp(temp)) => {
+        *defs.entry(*temp).or_insert(0) += 1;
+        if let Exp::Call(box Exp::Name(label), args) = src {
+            match args.first() {
+            | Some(Exp::Const(size)) if is_allocator(*label) => { sizes.insert(*temp, *size); },
+            | _                                               => (),
+            }
+        }
+        collect_exp(src, defs, sizes);
+    },
+    | Stm::Move(src, dst) => {
+        collect_exp(src, defs, sizes);
+        collect_exp(dst, defs, sizes);
+    },
+    | Stm::Exp(exp)
+    | Stm::Jump(exp, _)           => collect_exp(exp, defs, sizes),
+    | Stm::CJump(l, _, r, _, _)   => {
+        collect_exp(l, defs, sizes);
+        collect_exp(r, defs, sizes);
+    },
+    | Stm::Seq(stms)              => for stm in stms { collect_stm(stm, defs, sizes) },
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                 => (),
+    }
+}
+
+fn collect_exp(exp: &Exp, defs: &mut FnvHashMap<Temp, usize>, sizes: &mut FnvHashMap<Temp, i32>) {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)             => (),
+    | Exp::Binop(lhs, _, rhs)  => {
+        collect_exp(lhs, defs, sizes);
+        collect_exp(rhs, defs, sizes);
+    },
+    | Exp::Mem(addr)           => collect_exp(addr, defs, sizes),
+    | Exp::Call(name, args)    => {
+        collect_exp(name, defs, sizes);
+        for arg in args { collect_exp(arg, defs, sizes) }
+    },
+    | Exp::ESeq(stm, exp)      => {
+        collect_stm(stm, defs, sizes);
+        collect_exp(exp, defs, sizes);
+    },
+    }
+}
+
+fn is_allocator(label: Label) -> bool {
+    ALLOCATORS.iter().any(|allocator| label == Label::from_fixed(allocator))
+}
+
+fn out_of_bounds() -> Stm {
+    Stm::Exp(Exp::Call(Box::new(Exp::Name(Label::from_fixed("__bounds__"))), vec![]))
+}
+
+fn element(array: Exp, index: Exp) -> Exp {
+    Exp::Binop(
+        Box::new(array),
+        Binop::Add,
+        Box::new(Exp::Binop(Box::new(index), Binop::Mul, Box::new(Exp::Const(WORD_SIZE)))),
+    )
+}
+
+/// Address of `array[index]`, evaluating both once and checking the index first.
+fn check(sizes: &FnvHashMap<Temp, i32>, array: Exp, index: Exp) -> Exp {
+    if let (Exp::Temp(temp), Exp::Const(index)) = (&array, &index) {
+        match sizes.get(temp) {
+        | Some(size) if *index >= 0 && index < size => return element(array, Exp::Const(*index)),
+        | Some(_) => {
+            let element = element(array, Exp::Const(*index));
+            return Exp::ESeq(Box::new(out_of_bounds()), Box::new(element))
+        },
+        | None => (),
+        }
+    }
+
+    let array_temp = Temp::from_str("BOUNDS_ARRAY");
+    let index_temp = Temp::from_str("BOUNDS_INDEX");
+    let below = Label::from_str("BOUNDS_BELOW");
+    let fail = Label::from_str("BOUNDS_FAIL");
+    let ok = Label::from_str("BOUNDS_OK");
+
+    let length = Exp::Mem(Box::new(Exp::Binop(
+        Box::new(Exp::Temp(array_temp)),
+        Binop::Sub,
+        Box::new(Exp::Const(WORD_SIZE)),
+    )));
+
+    let checks = Stm::Seq(vec![
+        Stm::Move(array, Exp::Temp(array_temp)),
+        Stm::Move(index, Exp::Temp(index_temp)),
+        Stm::CJump(Exp::Temp(index_temp), Relop::Lt, Exp::Const(0), fail, below),
+        Stm::Label(below),
+        Stm::CJump(Exp::Temp(index_temp), Relop::Ge, length, fail, ok),
+        Stm::Label(fail),
+        out_of_bounds(),
+        Stm::Label(ok),
+    ]);
+
+    Exp::ESeq(Box::new(checks), Box::new(element(Exp::Temp(array_temp), Exp::Temp(index_temp))))
+}
+
+fn bounds_stm(sizes: &FnvHashMap<Temp, i32>, stm: Stm) -> Stm {
+    match stm {
+    | Stm::Exp(exp)              => Stm::Exp(bounds_exp(sizes, exp)),
+    | Stm::Move(src, dst)        => Stm::Move(bounds_exp(sizes, src), bounds_exp(sizes, dst)),
+    | Stm::Jump(exp, labels)     => Stm::Jump(bounds_exp(sizes, exp), labels),
+    | Stm::CJump(l, op, r, t, f) => Stm::CJump(bounds_exp(sizes, l), op, bounds_exp(sizes, r), t, f),
+    | Stm::Seq(stms)             => Stm::Seq(stms.into_iter().map(|stm| bounds_stm(sizes, stm)).collect()),
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                => stm,
+    }
+}
+
+fn bounds_exp(sizes: &FnvHashMap<Temp, i32>, exp: Exp) -> Exp {
+    match exp {
+    | Exp::Mem(box Exp::Binop(array, Binop::Add, box Exp::Binop(index, Binop::Mul, box Exp::Const(WORD_SIZE)))) => {
+        let array = bounds_exp(sizes, *array);
+        let index = bounds_exp(sizes, *index);
+        Exp::Mem(Box::new(check(sizes, array, index)))
+    },
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)             => exp,
+    | Exp::Binop(lhs, op, rhs) => Exp::Binop(Box::new(bounds_exp(sizes, *lhs)), op, Box::new(bounds_exp(sizes, *rhs))),
+    | Exp::Mem(addr)           => Exp::Mem(Box::new(bounds_exp(sizes, *addr))),
+    | Exp::Call(name, args)    => Exp::Call(name, args.into_iter().map(|arg| bounds_exp(sizes, arg)).collect()),
+    | Exp::ESeq(stm, exp)      => Exp::ESeq(Box::new(bounds_stm(sizes, *stm)), Box::new(bounds_exp(sizes, *exp))),
+    }
+}
@@ -196,7 +196,8 @@ fn canonize_stm(stm: Stm) -> (Purity, Vec<Stm>) {
 
     match stm {
     | Stm::Label(_)
-    | Stm::Comment(_) => (Purity::Pure, vec![stm]),
+    | Stm::Comment(_)
+    | Stm::Loc(_) => (Purity::Pure, vec![stm]),
     | Stm::Move(src_exp, dst_exp) => {
 
         let (src_purity, src_exp, mut src_stms) = canonize_exp(src_exp);
@@ -0,0 +1,119 @@
+use config::WORD_SIZE;
+use ir::*;
+use operand::{Label, Temp};
+
+/// Shortest run of zeroed fields worth a call to `memset`.
+const MIN_RUN: usize = 4;
+
+/// Replaces runs of consecutive record fields initialized to zero,
+/// like `nil` pointers, with a single call to `memset`, which is
+/// smaller than storing each field separately.
+pub fn clear(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(|body| {
+            body.into_iter()
+                .map(clear_stm)
+                .collect()
+        })
+    })
+}
+
+fn clear_stm(stm: Stm) -> Stm {
+    match stm {
+    | Stm::Exp(exp)                => Stm::Exp(clear_exp(exp)),
+    | Stm::Move(src, dst)          => Stm::Move(clear_exp(src), clear_exp(dst)),
+    | Stm::Jump(exp, labels)       => Stm::Jump(clear_exp(exp), labels),
+    | Stm::CJump(l, op, r, t, f)   => Stm::CJump(clear_exp(l), op, clear_exp(r), t, f),
+    | Stm::Seq(stms)               => Stm::Seq(stms.into_iter().map(clear_stm).collect()),
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                  => stm,
+    }
+}
+
+fn clear_exp(exp: Exp) -> Exp {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)                 => exp,
+    | Exp::Binop(lhs, op, rhs)     => Exp::Binop(Box::new(clear_exp(*lhs)), op, Box::new(clear_exp(*rhs))),
+    | Exp::Mem(addr)               => Exp::Mem(Box::new(clear_exp(*addr))),
+    | Exp::Call(name, args)        => Exp::Call(name, args.into_iter().map(clear_exp).collect()),
+    | Exp::ESeq(stm, exp)          => {
+        let stm = clear_stm(*stm);
+        let exp = clear_exp(*exp);
+        match (stm, exp) {
+        | (Stm::Seq(seq), Exp::Temp(pointer)) => clear_rec(seq, pointer),
+        | (stm, exp)                          => Exp::ESeq(Box::new(stm), Box::new(exp)),
+        }
+    },
+    }
+}
+
+/// Offset of the field `stm` zeroes in the record at `pointer`, if it does.
+fn zeroed_field(stm: &Stm, pointer: Temp) -> Option<i32> {
+    match stm {
+    | Stm::Move(Exp::Const(0), Exp::Mem(box Exp::Binop(box Exp::Temp(temp), Binop::Add, box Exp::Const(offset)))) => {
+        if *temp == pointer { Some(*offset) } else { None }
+    },
+    | _ => None,
+    }
+}
+
+/// Rewrites the record initialization produced by `translate_rec`, if `seq` is one.
+fn clear_rec(seq: Vec<Stm>, pointer: Temp) -> Exp {
+
+    let malloc = Label::from_fixed("malloc");
+    let allocates = match seq.first() {
+    | Some(Stm::Move(Exp::Call(box Exp::Name(label), _), Exp::Temp(temp))) => {
+        *label == malloc && *temp == pointer
+    },
+    | _ => false,
+    };
+
+    if !allocates {
+        return Exp::ESeq(Box::new(Stm::Seq(seq)), Box::new(Exp::Temp(pointer)))
+    }
+
+    let mut cleared = Vec::with_capacity(seq.len());
+    let mut run: Vec<(i32, Stm)> = Vec::new();
+
+    for stm in seq {
+        match zeroed_field(&stm, pointer) {
+        | Some(offset) if run.last().map_or(true, |(last, _)| offset == last + WORD_SIZE) => {
+            run.push((offset, stm));
+        },
+        | offset => {
+            flush(&mut run, &mut cleared, pointer);
+            match offset {
+            | Some(offset) => run.push((offset, stm)),
+            | None         => cleared.push(stm),
+            }
+        },
+        }
+    }
+
+    flush(&mut run, &mut cleared, pointer);
+    Exp::ESeq(Box::new(Stm::Seq(cleared)), Box::new(Exp::Temp(pointer)))
+}
+
+/// Emits `run` as a single `memset` if it's long enough, or as is otherwise.
+fn flush(run: &mut Vec<(i32, Stm)>, cleared: &mut Vec<Stm>, pointer: Temp) {
+    if run.len() < MIN_RUN {
+        cleared.extend(run.drain(..).map(|(_, stm)| stm));
+        return
+    }
+
+    let start = run[0].0;
+    let size = run.len() as i32 * WORD_SIZE;
+    run.clear();
+
+    cleared.push(Stm::Exp(Exp::Call(
+        Box::new(Exp::Name(Label::from_fixed("memset"))),
+        vec![
+            Exp::Binop(Box::new(Exp::Temp(pointer)), Binop::Add, Box::new(Exp::Const(start))),
+            Exp::Const(0),
+            Exp::Const(size),
+        ],
+    )));
+}
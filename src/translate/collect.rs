@@ -0,0 +1,125 @@
+use fnv::FnvHashMap;
+
+use config::WORD_SIZE;
+use ir::*;
+use operand::{Label, Reg, Temp};
+use translate::Root;
+
+/// Targets a garbage-collected runtime: records and arrays are allocated
+/// through `__alloc__` and `__alloc_array__`, and every heap-typed variable
+/// is kept on the stack and registered with `__gc_push__` for the duration
+/// of its frame, then unregistered with `__gc_pop__` before returning.
+pub fn collect(unit: Unit) -> Unit {
+    Unit {
+        data: unit.data,
+        functions: unit.functions.into_iter()
+            .map(collect_function)
+            .collect(),
+    }
+}
+
+fn collect_function(mut function: Function) -> Function {
+
+    // Move roots held in temps onto the stack, where the collector can see them
+    let mut spilled = FnvHashMap::default();
+    for root in &mut function.layout.roots {
+        if let Root::Temp(temp) = *root {
+            function.escapes += 1;
+            let offset = -(function.escapes as i32) * WORD_SIZE;
+            spilled.insert(temp, offset);
+            *root = Root::Frame(offset);
+        }
+    }
+    function.layout.size = function.escapes as i32 * WORD_SIZE;
+
+    let offsets = function.layout.roots.iter()
+        .map(|root| match root {
+            | Root::Frame(offset) => *offset,
+            | Root::Temp(_)       => panic!("Internal error: root left in temp"),
+        })
+        .collect::<Vec<_>>();
+
+    let mut body = function.body.into_iter()
+        .map(|stm| collect_stm(&spilled, stm))
+        .collect::<Vec<_>>();
+
+    if offsets.is_empty() {
+        function.body = body;
+        return function
+    }
+
+    let push = Label::from_fixed("__gc_push__");
+    let pop = Label::from_fixed("__gc_pop__");
+
+    // Body is the prologue followed by a move into the return register
+    let (ret_exp, ret_temp) = match body.pop() {
+    | Some(Stm::Move(exp, Exp::Temp(temp))) => (exp, temp),
+    | _ => panic!("Internal error: expected function return"),
+    };
+
+    let mut collected = Vec::new();
+
+    // Locals may not be initialized yet, so clear every slot before registering
+    for offset in &offsets {
+        collected.push(Stm::Move(Exp::Const(0), Exp::Mem(Box::new(slot(*offset)))));
+    }
+
+    collected.append(&mut body);
+
+    for offset in &offsets {
+        collected.push(Stm::Exp(Exp::Call(Box::new(Exp::Name(push)), vec![slot(*offset)])));
+    }
+
+    // Unregistering clobbers the return register, so hold the result aside
+    let result = Temp::from_str("GC_RESULT");
+    collected.push(Stm::Move(ret_exp, Exp::Temp(result)));
+    collected.push(Stm::Exp(Exp::Call(Box::new(Exp::Name(pop)), vec![Exp::Const(offsets.len() as i32)])));
+    collected.push(Stm::Move(Exp::Temp(result), Exp::Temp(ret_temp)));
+
+    function.body = collected;
+    function
+}
+
+/// Address of the stack slot at `offset` from RBP.
+fn slot(offset: i32) -> Exp {
+    Exp::Binop(
+        Box::new(Exp::Temp(Temp::Reg(Reg::RBP))),
+        Binop::Sub,
+        Box::new(Exp::Const(-offset)),
+    )
+}
+
+fn collect_stm(spilled: &FnvHashMap<Temp, i32>, stm: Stm) -> Stm {
+    match stm {
+    | Stm::Exp(exp)                => Stm::Exp(collect_exp(spilled, exp)),
+    | Stm::Move(src, dst)          => Stm::Move(collect_exp(spilled, src), collect_exp(spilled, dst)),
+    | Stm::Jump(exp, labels)       => Stm::Jump(collect_exp(spilled, exp), labels),
+    | Stm::CJump(l, op, r, t, f)   => Stm::CJump(collect_exp(spilled, l), op, collect_exp(spilled, r), t, f),
+    | Stm::Seq(stms)               => Stm::Seq(stms.into_iter().map(|stm| collect_stm(spilled, stm)).collect()),
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                  => stm,
+    }
+}
+
+fn collect_exp(spilled: &FnvHashMap<Temp, i32>, exp: Exp) -> Exp {
+    match exp {
+    | Exp::Temp(temp) if spilled.contains_key(&temp) => {
+        Exp::Mem(Box::new(slot(spilled[&temp])))
+    },
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)                 => exp,
+    | Exp::Binop(lhs, op, rhs)     => Exp::Binop(Box::new(collect_exp(spilled, *lhs)), op, Box::new(collect_exp(spilled, *rhs))),
+    | Exp::Mem(addr)               => Exp::Mem(Box::new(collect_exp(spilled, *addr))),
+    | Exp::ESeq(stm, exp)          => Exp::ESeq(Box::new(collect_stm(spilled, *stm)), Box::new(collect_exp(spilled, *exp))),
+    | Exp::Call(name, args)        => {
+        let name = match *name {
+        | Exp::Name(label) if label == Label::from_fixed("malloc")     => Exp::Name(Label::from_fixed("__alloc__")),
+        | Exp::Name(label) if label == Label::from_fixed("init_array") => Exp::Name(Label::from_fixed("__alloc_array__")),
+        | name                                                         => collect_exp(spilled, name),
+        };
+        Exp::Call(Box::new(name), args.into_iter().map(|arg| collect_exp(spilled, arg)).collect())
+    },
+    }
+}
@@ -0,0 +1,70 @@
+use fnv::FnvHashSet;
+
+use ir::*;
+use operand::Temp;
+
+pub fn eliminate(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(eliminate_body)
+    })
+}
+
+fn eliminate_body(mut body: Vec<Stm>) -> Vec<Stm> {
+
+    // Removing a move can make its sources dead, so iterate to a fixed point
+    loop {
+        let mut used = FnvHashSet::default();
+        for stm in &body { collect_stm(stm, &mut used); }
+
+        let len = body.len();
+        body.retain(|stm| match stm {
+            | Stm::Move(src, Exp::Temp(dst @ Temp::Temp { .. })) => {
+                used.contains(dst) || src.has_side_effects()
+            },
+            | _ => true,
+        });
+
+        if body.len() == len { return body }
+    }
+}
+
+fn collect_stm(stm: &Stm, used: &mut FnvHashSet<Temp>) {
+    match stm {
+    | Stm::Move(src, Exp::Temp(_)) => collect_exp(src, used),
+    | Stm::Move(src, dst) => {
+        collect_exp(src, used);
+        collect_exp(dst, used);
+    },
+    | Stm::Exp(exp)
+    | Stm::Jump(exp, _) => collect_exp(exp, used),
+    | Stm::CJump(lhs, _, rhs, _, _) => {
+        collect_exp(lhs, used);
+        collect_exp(rhs, used);
+    },
+    | Stm::Seq(stms) => for stm in stms { collect_stm(stm, used) },
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_) => (),
+    }
+}
+
+fn collect_exp(exp: &Exp, used: &mut FnvHashSet<Temp>) {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_) => (),
+    | Exp::Temp(temp) => { used.insert(*temp); },
+    | Exp::Binop(lhs, _, rhs) => {
+        collect_exp(lhs, used);
+        collect_exp(rhs, used);
+    },
+    | Exp::Mem(addr) => collect_exp(addr, used),
+    | Exp::Call(name, args) => {
+        collect_exp(name, used);
+        for arg in args { collect_exp(arg, used); }
+    },
+    | Exp::ESeq(stm, exp) => {
+        collect_stm(stm, used);
+        collect_exp(exp, used);
+    },
+    }
+}
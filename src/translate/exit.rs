@@ -0,0 +1,28 @@
+use config::ENTRY;
+use ir::*;
+use operand::Label;
+
+/// Ends the entry point with a call to the runtime's `exit`, for targets where
+/// returning from `main` doesn't terminate the program.
+pub fn exit(unit: Unit) -> Unit {
+    exit_with(unit, Label::from_fixed(ENTRY))
+}
+
+/// Same as `exit`, but takes the entry point's label as an argument.
+pub fn exit_with(unit: Unit, entry: Label) -> Unit {
+    let exit = Label::from_fixed("__exit__");
+
+    unit.map(|function| {
+        if function.label != entry { return function }
+
+        function.map(|mut body| {
+            body.push(Stm::Exp(
+                Exp::Call(
+                    Box::new(Exp::Name(exit)),
+                    vec![Exp::Const(0)],
+                )
+            ));
+            body
+        })
+    })
+}
@@ -45,6 +45,15 @@ fn fold_binop(lhs_exp: &Exp, op: &Binop, rhs_exp: &Exp) -> Exp {
     let rhs_exp = fold_exp(rhs_exp);
 
     match (lhs_exp, op, rhs_exp) {
+
+    // Leave division by zero to trap at runtime. Division runs on full words,
+    // so MIN / -1 doesn't trap, but its quotient doesn't fit in a constant.
+    | (lhs, Binop::Div, Exp::Const(0))
+    | (lhs, Binop::Mod, Exp::Const(0)) => Exp::Binop(Box::new(lhs), *op, Box::new(Exp::Const(0))),
+    | (Exp::Const(lhs), Binop::Div, Exp::Const(rhs))
+    | (Exp::Const(lhs), Binop::Mod, Exp::Const(rhs)) if lhs.checked_div(rhs).is_none() => {
+        Exp::Binop(Box::new(Exp::Const(lhs)), *op, Box::new(Exp::Const(rhs)))
+    },
     | (Exp::Const(0),   Binop::Add,     rhs          )
     | (Exp::Const(0),   Binop::Or,      rhs          ) => rhs,
     | (lhs,             Binop::Add,     Exp::Const(0))
@@ -58,18 +67,23 @@ fn fold_binop(lhs_exp: &Exp, op: &Binop, rhs_exp: &Exp) -> Exp {
     | (_            ,   Binop::And,     Exp::Const(0)) => Exp::Const(0),
     | (Exp::Const(lhs), op,             Exp::Const(rhs)) => {
 
+        // Arithmetic runs on full words, so a result that doesn't fit
+        // in 32 bits is left to be computed at runtime instead of wrapping
         let result = match op {
-        | Binop::Add => lhs + rhs,
-        | Binop::Sub => lhs - rhs,
-        | Binop::Mul => lhs * rhs,
-        | Binop::Div => lhs / rhs,
-        | Binop::Mod => lhs % rhs,
-        | Binop::And => lhs & rhs,
-        | Binop::Or  => lhs | rhs,
-        | Binop::XOr => lhs ^ rhs,
+        | Binop::Add => lhs.checked_add(rhs),
+        | Binop::Sub => lhs.checked_sub(rhs),
+        | Binop::Mul => lhs.checked_mul(rhs),
+        | Binop::Div => Some(lhs / rhs),
+        | Binop::Mod => Some(lhs % rhs),
+        | Binop::And => Some(lhs & rhs),
+        | Binop::Or  => Some(lhs | rhs),
+        | Binop::XOr => Some(lhs ^ rhs),
         };
 
-        Exp::Const(result)
+        match result {
+        | Some(result) => Exp::Const(result),
+        | None         => Exp::Binop(Box::new(Exp::Const(lhs)), *op, Box::new(Exp::Const(rhs))),
+        }
     },
     | (lhs_exp, op, rhs_exp) => Exp::Binop(Box::new(lhs_exp), *op, Box::new(rhs_exp)),
     }
@@ -79,7 +93,8 @@ fn fold_stm(stm: &Stm) -> Stm {
 
     match stm {
     | Stm::Label(_)
-    | Stm::Comment(_) => stm.clone(),
+    | Stm::Comment(_)
+    | Stm::Loc(_) => stm.clone(),
     | Stm::Move(src_exp, dst_exp) => {
         Stm::Move(
             fold_exp(src_exp),
@@ -1,9 +1,11 @@
+use std::fmt;
+
 use fnv::FnvHashMap;
 use simple_symbol::Symbol;
 
 use ir;
 use config::WORD_SIZE;
-use operand::{Label, Temp, Reg};
+use operand::{Convention, Label, Temp, Reg};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Access {
@@ -41,17 +43,65 @@ pub struct Frame {
     pub prologue: Vec<ir::Stm>,
     pub escapes: usize,
     map: FnvHashMap<Symbol, Access>,
+    slots: Vec<(Symbol, Access)>,
+    roots: Vec<Access>,
+    links: Vec<Temp>,
+}
+
+/// Where a variable pointing into the heap lives, so a collector can find it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Root {
+    Frame(i32),
+    Temp(Temp),
+}
+
+/// Where each argument and local of a frame lives, in declaration order:
+/// escaping variables at an offset from RBP, and the rest in temps.
+#[derive(Clone, Debug, Default)]
+pub struct Layout {
+    pub slots: Vec<(Symbol, Option<i32>)>,
+    pub roots: Vec<Root>,
+    pub size: i32,
+}
+
+impl Layout {
+    /// Offset of the first variable declared as `name`, if it escapes.
+    pub fn offset(&self, name: Symbol) -> Option<i32> {
+        self.slots.iter()
+            .find(|(slot, _)| *slot == name)
+            .and_then(|(_, offset)| *offset)
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let width = self.slots.iter()
+            .map(|(name, _)| name.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        for (name, offset) in &self.slots {
+            match offset {
+            | Some(offset) => writeln!(fmt, "    {:<2$} {}(%rbp)", name.to_string(), offset, width)?,
+            | None         => writeln!(fmt, "    {:<1$} temp", name.to_string(), width)?,
+            }
+        }
+
+        write!(fmt, "    {} bytes", self.size)
+    }
 }
 
 impl Frame {
-    pub fn new(label: Label, args: Vec<(Symbol, bool)>) -> Self {
+    /// Frame for a function taking `args` in order, as passed by `convention`.
+    pub fn new(label: Label, args: Vec<(Symbol, bool)>, convention: Convention) -> Self {
         let rbp = ir::Exp::Temp(Temp::Reg(Reg::RBP));
         let mut map = FnvHashMap::default();
         let mut prologue = Vec::new();
+        let mut slots = Vec::new();
         let mut escapes = 0;
 
         for (i, (name, escape)) in args.iter().enumerate() {
-            let from = Frame::get_argument(i);
+            let from = Frame::get_argument(i, convention);
             let to = if *escape {
                 escapes += 1;
                 Access::Frame(escapes)
@@ -61,6 +111,7 @@ impl Frame {
 
             prologue.push(ir::Stm::Move(from, to.from_base(rbp.clone())));
             map.insert(*name, to);
+            slots.push((*name, to));
         }
 
         Frame {
@@ -68,6 +119,9 @@ impl Frame {
             prologue,
             map,
             escapes,
+            slots,
+            roots: Vec::new(),
+            links: Vec::new(),
         }
     }
 
@@ -86,10 +140,47 @@ impl Frame {
             )
         };
 
-        self.map.insert(name, access);;
+        self.map.insert(name, access);
+        self.slots.push((name, access));
         access.from_base(rbp)
     }
 
+    /// Temp holding the frame pointer `depth + 1` static links out. The first
+    /// request loads it with `load` in the prologue, and later ones reuse it,
+    /// since static links never change during a call.
+    pub fn link(&mut self, depth: usize, load: ir::Exp) -> Temp {
+        if let Some(temp) = self.links.get(depth) { return *temp }
+        let temp = Temp::from_str("LINK");
+        self.prologue.push(ir::Stm::Move(load, ir::Exp::Temp(temp)));
+        self.links.push(temp);
+        temp
+    }
+
+    /// Marks the most recent variable declared as `name` as a heap pointer.
+    pub fn root(&mut self, name: Symbol) {
+        self.roots.push(self.map[&name]);
+    }
+
+    /// Where every variable declared so far lives. Shadowed variables
+    /// keep their own entries, since frame slots are never reused.
+    pub fn layout(&self) -> Layout {
+        Layout {
+            slots: self.slots.iter()
+                .map(|(name, access)| match access {
+                    | Access::Frame(n) => (*name, Some(-(*n as i32) * WORD_SIZE)),
+                    | Access::Reg(_)   => (*name, None),
+                })
+                .collect(),
+            roots: self.roots.iter()
+                .map(|access| match access {
+                    | Access::Frame(n)  => Root::Frame(-(*n as i32) * WORD_SIZE),
+                    | Access::Reg(temp) => Root::Temp(*temp),
+                })
+                .collect(),
+            size: self.escapes as i32 * WORD_SIZE,
+        }
+    }
+
     pub fn contains(&self, name: Symbol) -> bool {
         self.map.contains_key(&name)
     }
@@ -98,20 +189,20 @@ impl Frame {
         self.map[&name].from_base(base)
     }
 
-    fn get_argument(i: usize) -> ir::Exp {
-        if i < 6 {
+    fn get_argument(i: usize, convention: Convention) -> ir::Exp {
+        let registers = convention.arguments();
+        if i < registers.len() {
             ir::Exp::Temp(
-                Temp::from_reg(
-                    Reg::get_argument(i)
-                )
+                Temp::from_reg(registers[i])
             )
         } else {
             let fp = ir::Exp::Temp(
                 Temp::from_reg(Reg::RBP)
             );
 
+            // Skip saved RBP, return address, and shadow space
             let offset = ir::Exp::Const(
-                (i - 4) as i32 * WORD_SIZE
+                (i - registers.len() + convention.shadow_space() + 2) as i32 * WORD_SIZE
             );
 
             ir::Exp::Mem(
@@ -126,3 +217,12 @@ impl Frame {
         }
     }
 }
+
+/// Renders the layout of every function's frame, for debugging calling
+/// conventions and frame offsets.
+pub fn layout(unit: &ir::Unit) -> String {
+    unit.functions.iter()
+        .map(|function| format!("{}:\n{}\n", function.label, function.layout))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
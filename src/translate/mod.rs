@@ -1,11 +1,33 @@
+mod bounds;
 mod canonize;
+mod clear;
+mod collect;
+mod eliminate;
+mod exit;
 mod fold;
 mod frame;
+mod nonnull;
+mod propagate;
 mod reorder;
+mod share;
+mod switch;
 mod translate;
+mod validate;
+mod zeroed;
 
 pub use self::translate::*;
-pub use self::frame::Frame;
+pub use self::frame::{layout, Frame, Layout, Root};
 pub use self::fold::fold;
+pub use self::bounds::bounds;
 pub use self::canonize::canonize;
+pub use self::clear::clear;
+pub use self::collect::collect;
+pub use self::eliminate::eliminate;
+pub use self::exit::{exit, exit_with};
+pub use self::nonnull::nonnull;
+pub use self::propagate::propagate;
 pub use self::reorder::reorder;
+pub use self::share::share;
+pub use self::switch::switch;
+pub use self::validate::validate;
+pub use self::zeroed::zeroed;
@@ -0,0 +1,85 @@
+use ir::*;
+use operand::{Label, Temp};
+
+/// Runtime functions returning freshly allocated memory, under every
+/// allocation strategy.
+const ALLOCATORS: [&str; 5] = ["malloc", "calloc", "init_array", "__alloc__", "__alloc_array__"];
+
+/// Follows every allocation with a check that the returned pointer is
+/// non-null, calling the runtime's `__oom__` otherwise, so running out of
+/// memory fails deterministically instead of on the pointer's first use.
+pub fn nonnull(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(|body| {
+            body.into_iter()
+                .map(nonnull_stm)
+                .collect()
+        })
+    })
+}
+
+fn is_allocator(name: &Exp) -> bool {
+    match name {
+    | Exp::Name(label) => ALLOCATORS.iter().any(|allocator| *label == Label::from_fixed(allocator)),
+    | _                => false,
+    }
+}
+
+/// Jumps past a call to `__oom__` if `pointer` is non-null.
+fn check(pointer: Temp) -> Vec<Stm> {
+    let ok = Label::from_str("ALLOC_OK");
+    let fail = Label::from_str("ALLOC_FAIL");
+    vec![
+        Stm::CJump(Exp::Temp(pointer), Relop::Ne, Exp::Const(0), ok, fail),
+        Stm::Label(fail),
+        Stm::Exp(Exp::Call(Box::new(Exp::Name(Label::from_fixed("__oom__"))), vec![])),
+        Stm::Label(ok),
+    ]
+}
+
+fn nonnull_stm(stm: Stm) -> Stm {
+    match stm {
+    | Stm::Move(Exp::Call(name, args), Exp::Temp(pointer @ Temp::Temp { .. })) if is_allocator(&name) => {
+        let args = args.into_iter().map(nonnull_exp).collect();
+        let mut seq = vec![Stm::Move(Exp::Call(name, args), Exp::Temp(pointer))];
+        seq.append(&mut check(pointer));
+        Stm::Seq(seq)
+    },
+    | Stm::Exp(exp)                => Stm::Exp(nonnull_exp(exp)),
+    | Stm::Move(src, dst)          => Stm::Move(nonnull_exp(src), nonnull_exp(dst)),
+    | Stm::Jump(exp, labels)       => Stm::Jump(nonnull_exp(exp), labels),
+    | Stm::CJump(l, op, r, t, f)   => Stm::CJump(nonnull_exp(l), op, nonnull_exp(r), t, f),
+    | Stm::Seq(stms)               => {
+        // Keep record initialization flat, with the check right after the allocation
+        let stms = stms.into_iter()
+            .flat_map(|stm| match nonnull_stm(stm) {
+                | Stm::Seq(seq) => seq,
+                | stm           => vec![stm],
+            })
+            .collect();
+        Stm::Seq(stms)
+    },
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                  => stm,
+    }
+}
+
+fn nonnull_exp(exp: Exp) -> Exp {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)                 => exp,
+    | Exp::Binop(lhs, op, rhs)     => Exp::Binop(Box::new(nonnull_exp(*lhs)), op, Box::new(nonnull_exp(*rhs))),
+    | Exp::Mem(addr)               => Exp::Mem(Box::new(nonnull_exp(*addr))),
+    | Exp::ESeq(stm, exp)          => Exp::ESeq(Box::new(nonnull_stm(*stm)), Box::new(nonnull_exp(*exp))),
+    | Exp::Call(name, args)        => {
+        let args = args.into_iter().map(nonnull_exp).collect();
+        if !is_allocator(&name) { return Exp::Call(name, args) }
+        let pointer = Temp::from_str("ALLOC");
+        let mut seq = vec![Stm::Move(Exp::Call(name, args), Exp::Temp(pointer))];
+        seq.append(&mut check(pointer));
+        Exp::ESeq(Box::new(Stm::Seq(seq)), Box::new(Exp::Temp(pointer)))
+    },
+    }
+}
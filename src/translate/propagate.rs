@@ -0,0 +1,93 @@
+use fnv::FnvHashMap;
+
+use ir::*;
+use operand::Temp;
+
+pub fn propagate(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(propagate_body)
+    })
+}
+
+fn propagate_body(body: Vec<Stm>) -> Vec<Stm> {
+
+    // Maps each copied temp to its original within the current block
+    let mut copies = FnvHashMap::default();
+    let mut propagated = Vec::with_capacity(body.len());
+
+    for stm in body {
+        match stm {
+        | Stm::Label(_) => {
+            copies.clear();
+            propagated.push(stm);
+        },
+        | Stm::Jump(_, _) => {
+            propagated.push(stm);
+            copies.clear();
+        },
+        | Stm::CJump(lhs, op, rhs, t, f) => {
+            propagated.push(Stm::CJump(
+                propagate_exp(&copies, lhs),
+                op,
+                propagate_exp(&copies, rhs),
+                t,
+                f,
+            ));
+            copies.clear();
+        },
+        | Stm::Move(src, Exp::Temp(dst)) => {
+            let src = propagate_exp(&copies, src);
+
+            // Redefinition invalidates copies to and from this temp
+            copies.retain(|to, from| *to != dst && *from != dst);
+
+            // Fixed registers can be clobbered implicitly, e.g. by calls
+            match src {
+            | Exp::Temp(from @ Temp::Temp { .. }) if from != dst => {
+                if let Temp::Temp { .. } = dst { copies.insert(dst, from); }
+            },
+            | _ => (),
+            }
+
+            propagated.push(Stm::Move(src, Exp::Temp(dst)));
+        },
+        | Stm::Move(src, dst) => {
+            propagated.push(Stm::Move(
+                propagate_exp(&copies, src),
+                propagate_exp(&copies, dst),
+            ));
+        },
+        | Stm::Exp(exp) => propagated.push(Stm::Exp(propagate_exp(&copies, exp))),
+        | Stm::Seq(_)
+        | Stm::Comment(_)
+        | Stm::Loc(_) => propagated.push(stm),
+        }
+    }
+
+    propagated
+}
+
+fn propagate_exp(copies: &FnvHashMap<Temp, Temp>, exp: Exp) -> Exp {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::ESeq(_, _) => exp,
+    | Exp::Temp(temp) => Exp::Temp(*copies.get(&temp).unwrap_or(&temp)),
+    | Exp::Binop(lhs, op, rhs) => {
+        Exp::Binop(
+            Box::new(propagate_exp(copies, *lhs)),
+            op,
+            Box::new(propagate_exp(copies, *rhs)),
+        )
+    },
+    | Exp::Mem(addr) => Exp::Mem(Box::new(propagate_exp(copies, *addr))),
+    | Exp::Call(name, args) => {
+        Exp::Call(
+            name,
+            args.into_iter()
+                .map(|arg| propagate_exp(copies, arg))
+                .collect()
+        )
+    },
+    }
+}
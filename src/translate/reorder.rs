@@ -1,4 +1,4 @@
-use fnv::FnvHashSet;
+use fnv::{FnvHashMap, FnvHashSet};
 
 use analyze::flow::Flow;
 use ir::*;
@@ -7,12 +7,12 @@ use operand::Label;
 pub fn reorder(unit: Unit) -> Unit {
     unit.map(|function| {
             Function {
-                label: function.label,
                 body: Flow::new(function.label, function.body).linearize(),
-                escapes: function.escapes,
+                ..function
             }
         })
         .map(condense)
+        .map(merge)
         .map(clean)
 }
 
@@ -48,12 +48,53 @@ fn condense(function: Function) -> Function {
     }
 
     Function {
-        label: function.label,
         body: condensed,
-        escapes: function.escapes,
+        ..function
     }
 }
 
+fn merge(function: Function) -> Function {
+
+    // Map each label to the last label in its run of back-to-back labels
+    let mut aliases = FnvHashMap::default();
+    let mut run = Vec::new();
+
+    for stm in &function.body {
+        match stm {
+        | Stm::Label(label) => run.push(*label),
+        | _ => {
+            if let Some(last) = run.pop() {
+                for label in run.drain(..) { aliases.insert(label, last); }
+            }
+        },
+        }
+    }
+
+    if let Some(last) = run.pop() {
+        for label in run.drain(..) { aliases.insert(label, last); }
+    }
+
+    let alias = |label: &Label| *aliases.get(label).unwrap_or(label);
+
+    function.map(|body| {
+        body.into_iter()
+            .filter_map(|stm| match stm {
+                | Stm::Label(label) if aliases.contains_key(&label) => None,
+                | Stm::Jump(Exp::Name(label), targets) => {
+                    Some(Stm::Jump(
+                        Exp::Name(alias(&label)),
+                        targets.iter().map(&alias).collect(),
+                    ))
+                },
+                | Stm::CJump(lhs, op, rhs, t, f) => {
+                    Some(Stm::CJump(lhs, op, rhs, alias(&t), alias(&f)))
+                },
+                | stm => Some(stm),
+            })
+            .collect()
+    })
+}
+
 fn clean(function: Function) -> Function {
 
     let mut used = FnvHashSet::default();
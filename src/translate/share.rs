@@ -0,0 +1,232 @@
+use std::cmp::Reverse;
+
+use ir::*;
+use operand::{Reg, Temp};
+
+/// Common subexpression elimination within basic blocks. A side-effect-free
+/// subexpression evaluated more than once before anything could change its
+/// value is computed into a temp just before its first use, and every later
+/// occurrence reads the temp instead.
+pub fn share(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(share_body)
+    })
+}
+
+fn share_body(body: Vec<Stm>) -> Vec<Stm> {
+
+    // Expressions already held in temps within the current block
+    let mut available: Vec<(Exp, Temp)> = Vec::new();
+    let mut shared = Vec::with_capacity(body.len());
+
+    for (index, stm) in body.iter().enumerate() {
+        let mut stm = replace_stm(&available, stm.clone());
+        let rest = &body[index + 1..];
+
+        // Larger expressions first, so their parts are shared along with them
+        while let Some(exp) = candidates(&stm).into_iter()
+            .find(|exp| count_stm(exp, &stm) + count_rest(exp, &stm, rest, &available) > 1) {
+            let temp = Temp::from_str("SHARED");
+            shared.push(Stm::Move(exp.clone(), Exp::Temp(temp)));
+            available.push((exp, temp));
+            stm = replace_stm(&available, stm);
+        }
+
+        match stm {
+        | Stm::Label(_)
+        | Stm::Jump(_, _)
+        | Stm::CJump(_, _, _, _, _) => available.clear(),
+        | _ => available.retain(|(exp, _)| !kills(&stm, exp)),
+        }
+
+        shared.push(stm);
+    }
+
+    shared
+}
+
+/// Occurrences of `exp` in the statements following `stm` in its block,
+/// up to the first one that could change its value.
+fn count_rest(exp: &Exp, stm: &Stm, rest: &[Stm], available: &[(Exp, Temp)]) -> usize {
+    if kills(stm, exp) { return 0 }
+
+    let mut available = available.iter()
+        .filter(|(available, _)| !kills(stm, available))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut count = 0;
+
+    for stm in rest {
+        let stm = replace_stm(&available, stm.clone());
+        count += count_stm(exp, &stm);
+        match stm {
+        | Stm::Label(_)
+        | Stm::Jump(_, _)
+        | Stm::CJump(_, _, _, _, _) => break,
+        | _ if kills(&stm, exp) => break,
+        | _ => available.retain(|(available, _)| !kills(&stm, available)),
+        }
+    }
+
+    count
+}
+
+/// Whether executing `stm` could change the value of `exp`.
+fn kills(stm: &Stm, exp: &Exp) -> bool {
+    match stm {
+    | Stm::Move(Exp::Call(_, _), Exp::Temp(temp)) => reads_memory(exp) || uses(exp, *temp),
+    | Stm::Move(_, Exp::Temp(temp)) => uses(exp, *temp),
+    | Stm::Move(_, _)
+    | Stm::Exp(Exp::Call(_, _)) => reads_memory(exp),
+    | _ => false,
+    }
+}
+
+/// Whether `exp` is worth sharing: a computation or load without side effects,
+/// using no fixed registers other than the frame pointer. Frame slot addresses
+/// are left alone, since they fold into the addressing mode of their access.
+fn is_candidate(exp: &Exp) -> bool {
+    match exp {
+    | Exp::Binop(box Exp::Temp(Temp::Reg(Reg::RBP)), _, box Exp::Const(_)) => false,
+    | Exp::Binop(_, _, _)
+    | Exp::Mem(_) => !exp.has_side_effects() && is_fixed(exp),
+    | _ => false,
+    }
+}
+
+fn is_fixed(exp: &Exp) -> bool {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(Temp::Reg(Reg::RBP))
+    | Exp::Temp(Temp::Temp { .. }) => true,
+    | Exp::Temp(Temp::Reg(_)) => false,
+    | Exp::Binop(lhs, _, rhs) => is_fixed(lhs) && is_fixed(rhs),
+    | Exp::Mem(addr) => is_fixed(addr),
+    | Exp::Call(_, _)
+    | Exp::ESeq(_, _) => false,
+    }
+}
+
+fn reads_memory(exp: &Exp) -> bool {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_) => false,
+    | Exp::Binop(lhs, _, rhs) => reads_memory(lhs) || reads_memory(rhs),
+    | Exp::Mem(_)
+    | Exp::Call(_, _)
+    | Exp::ESeq(_, _) => true,
+    }
+}
+
+fn uses(exp: &Exp, temp: Temp) -> bool {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_) => false,
+    | Exp::Temp(used) => *used == temp,
+    | Exp::Binop(lhs, _, rhs) => uses(lhs, temp) || uses(rhs, temp),
+    | Exp::Mem(addr) => uses(addr, temp),
+    | Exp::Call(name, args) => uses(name, temp) || args.iter().any(|arg| uses(arg, temp)),
+    | Exp::ESeq(_, _) => true,
+    }
+}
+
+/// Expressions evaluated by `stm`, excluding the memory it writes to.
+fn evaluated(stm: &Stm) -> Vec<&Exp> {
+    match stm {
+    | Stm::Move(src, Exp::Mem(addr)) => vec![src, addr],
+    | Stm::Move(src, _)
+    | Stm::Exp(src)
+    | Stm::Jump(src, _) => vec![src],
+    | Stm::CJump(lhs, _, rhs, _, _) => vec![lhs, rhs],
+    | Stm::Seq(_)
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_) => vec![],
+    }
+}
+
+/// Candidate subexpressions of `stm`, largest first.
+fn candidates(stm: &Stm) -> Vec<Exp> {
+    let mut candidates = Vec::new();
+    for exp in evaluated(stm) { collect_exp(exp, &mut candidates) }
+    candidates.sort_by_key(|exp| Reverse(size(exp)));
+    candidates
+}
+
+fn collect_exp(exp: &Exp, candidates: &mut Vec<Exp>) {
+    if is_candidate(exp) && !candidates.contains(exp) {
+        candidates.push(exp.clone());
+    }
+    match exp {
+    | Exp::Binop(lhs, _, rhs) => {
+        collect_exp(lhs, candidates);
+        collect_exp(rhs, candidates);
+    },
+    | Exp::Mem(addr) => collect_exp(addr, candidates),
+    | Exp::Call(_, args) => for arg in args { collect_exp(arg, candidates) },
+    | _ => (),
+    }
+}
+
+fn size(exp: &Exp) -> usize {
+    match exp {
+    | Exp::Binop(lhs, _, rhs) => 1 + size(lhs) + size(rhs),
+    | Exp::Mem(addr) => 1 + size(addr),
+    | _ => 1,
+    }
+}
+
+fn count_stm(exp: &Exp, stm: &Stm) -> usize {
+    evaluated(stm).into_iter()
+        .map(|evaluated| count_exp(exp, evaluated))
+        .sum()
+}
+
+fn count_exp(exp: &Exp, within: &Exp) -> usize {
+    if exp == within { return 1 }
+    match within {
+    | Exp::Binop(lhs, _, rhs) => count_exp(exp, lhs) + count_exp(exp, rhs),
+    | Exp::Mem(addr) => count_exp(exp, addr),
+    | Exp::Call(_, args) => args.iter().map(|arg| count_exp(exp, arg)).sum(),
+    | _ => 0,
+    }
+}
+
+fn replace_stm(available: &[(Exp, Temp)], stm: Stm) -> Stm {
+    match stm {
+    | Stm::Move(src, Exp::Mem(addr)) => {
+        Stm::Move(replace_exp(available, src), Exp::Mem(Box::new(replace_exp(available, *addr))))
+    },
+    | Stm::Move(src, dst)        => Stm::Move(replace_exp(available, src), dst),
+    | Stm::Exp(exp)              => Stm::Exp(replace_exp(available, exp)),
+    | Stm::Jump(exp, labels)     => Stm::Jump(replace_exp(available, exp), labels),
+    | Stm::CJump(l, op, r, t, f) => Stm::CJump(replace_exp(available, l), op, replace_exp(available, r), t, f),
+    | Stm::Seq(_)
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                => stm,
+    }
+}
+
+fn replace_exp(available: &[(Exp, Temp)], exp: Exp) -> Exp {
+    if let Some((_, temp)) = available.iter().find(|(available, _)| *available == exp) {
+        return Exp::Temp(*temp)
+    }
+    match exp {
+    | Exp::Binop(lhs, op, rhs) => {
+        Exp::Binop(
+            Box::new(replace_exp(available, *lhs)),
+            op,
+            Box::new(replace_exp(available, *rhs)),
+        )
+    },
+    | Exp::Mem(addr)        => Exp::Mem(Box::new(replace_exp(available, *addr))),
+    | Exp::Call(name, args) => {
+        Exp::Call(name, args.into_iter().map(|arg| replace_exp(available, arg)).collect())
+    },
+    | _                     => exp,
+    }
+}
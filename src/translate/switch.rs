@@ -0,0 +1,108 @@
+use fnv::FnvHashMap;
+
+use config::WORD_SIZE;
+use ir::*;
+use operand::{Label, Temp};
+
+/// Fewest cases worth a jump table over a chain of comparisons.
+const MIN_CASES: usize = 4;
+
+/// Lowers chains of equality tests of one temp against dense constants,
+/// like `if x = 0 then ... else if x = 1 then ...`, to a bounds check and
+/// a single jump through a table of their branches. Runs on reordered IR,
+/// where each test in the chain falls through to the next.
+///
+/// The table's jump lists its targets in table order, one per entry.
+pub fn switch(unit: Unit) -> Unit {
+    unit.map(|function| function.map(switch_body))
+}
+
+/// Temp, constant, and branch of an equality test, if `stm` is one.
+fn case(stm: &Stm) -> Option<(Temp, i32, Label)> {
+    match stm {
+    | Stm::CJump(Exp::Temp(temp), Relop::Eq, Exp::Const(n), t, _)
+    | Stm::CJump(Exp::Const(n), Relop::Eq, Exp::Temp(temp), t, _) => Some((*temp, *n, *t)),
+    | _ => None,
+    }
+}
+
+fn switch_body(body: Vec<Stm>) -> Vec<Stm> {
+    let mut switched = Vec::with_capacity(body.len());
+    let mut index = 0;
+
+    while index < body.len() {
+        let temp = match case(&body[index]) {
+        | Some((temp, _, _)) => temp,
+        | None => {
+            switched.push(body[index].clone());
+            index += 1;
+            continue
+        },
+        };
+
+        let cases = body[index..].iter()
+            .map(case)
+            .take_while(|case| case.is_some_and(|(other, _, _)| other == temp))
+            .map(|case| case.unwrap())
+            .map(|(_, n, t)| (n, t))
+            .collect::<Vec<_>>();
+
+        match table(temp, &cases) {
+        | Some(stms) => switched.extend(stms),
+        | None       => switched.extend(body[index..index + cases.len()].iter().cloned()),
+        }
+
+        index += cases.len();
+    }
+
+    switched
+}
+
+/// Bounds check and jump through a table for `cases`, falling through to
+/// whatever followed the chain when no case matches. Returns `None` if
+/// there are too few cases, or they're too sparse to fill a table.
+fn table(temp: Temp, cases: &[(i32, Label)]) -> Option<Vec<Stm>> {
+    if cases.len() < MIN_CASES { return None }
+
+    let min = cases.iter().map(|(n, _)| *n).min()?;
+    let max = cases.iter().map(|(n, _)| *n).max()?;
+
+    // At least half of the table should be real cases
+    let size = max as i64 - min as i64 + 1;
+    if size > 2 * cases.len() as i64 { return None }
+
+    // Earlier tests win over later ones for the same constant
+    let mut branches = FnvHashMap::default();
+    for (n, t) in cases {
+        branches.entry(*n).or_insert(*t);
+    }
+
+    let default = Label::from_str("SWITCH_DEFAULT");
+    let table = Label::from_str("SWITCH_TABLE");
+    let low = Label::from_str("SWITCH_LOW");
+    let high = Label::from_str("SWITCH_HIGH");
+    let targets = (min..=max)
+        .map(|n| branches.get(&n).cloned().unwrap_or(default))
+        .collect::<Vec<_>>();
+
+    let offset = match min {
+    | 0 => Exp::Temp(temp),
+    | _ => Exp::Binop(Box::new(Exp::Temp(temp)), Binop::Sub, Box::new(Exp::Const(min))),
+    };
+
+    let entry = Exp::Mem(Box::new(Exp::Binop(
+        Box::new(Exp::Name(table)),
+        Binop::Add,
+        Box::new(Exp::Binop(Box::new(offset), Binop::Mul, Box::new(Exp::Const(WORD_SIZE)))),
+    )));
+
+    // False branches fall through to the next test, like the chain's own
+    Some(vec![
+        Stm::CJump(Exp::Temp(temp), Relop::Lt, Exp::Const(min), default, low),
+        Stm::Label(low),
+        Stm::CJump(Exp::Temp(temp), Relop::Gt, Exp::Const(max), default, high),
+        Stm::Label(high),
+        Stm::Jump(entry, targets),
+        Stm::Label(default),
+    ])
+}
@@ -1,5 +1,3 @@
-use itertools::Itertools;
-use itertools::FoldWhile::{Continue, Done};
 use simple_symbol::{store, Symbol};
 
 use ast::*;
@@ -8,42 +6,57 @@ use operand::*;
 use config::WORD_SIZE;
 use translate::Frame;
 use check::context::Binding;
+use span::Span;
 
 pub fn translate_fun_dec(frame: Frame, body_exp: ir::Tree) -> ir::Function {
     ir::Function::new(frame, body_exp)
 }
 
-pub fn translate_var_dec(frames: &mut [Frame], name: Symbol, escape: bool, init_exp: ir::Tree) -> ir::Tree {
+pub fn translate_var_dec(frames: &mut [Frame], span: Span, name: Symbol, escape: bool, heap: bool, init_exp: ir::Tree) -> ir::Tree {
 
-    let name_exp = frames.last_mut()
-        .expect("Internal error: missing frame")
-        .allocate(name, escape);
+    let frame = frames.last_mut()
+        .expect("Internal error: missing frame");
 
-    ir::Stm::Move(
-        init_exp.into(),
-        name_exp.into(),
-    ).into()
+    let name_exp = frame.allocate(name, escape);
+    if heap { frame.root(name) }
+
+    ir::Stm::Seq(vec![
+        ir::Stm::Loc(span),
+        ir::Stm::Move(
+            init_exp.into(),
+            name_exp.into(),
+        ),
+    ]).into()
 
 }
 
-pub fn translate_simple_var(frames: &[Frame], name: &Symbol) -> ir::Tree {
+/// Frame pointer of the frame `depth` static links out from the current one,
+/// with each enclosing frame's pointer cached in a temp of the current frame.
+fn translate_frame_pointer(frames: &mut [Frame], depth: usize) -> ir::Exp {
 
     // Start off at current frame's base pointer
-    let rbp = ir::Exp::Temp(Temp::Reg(Reg::RBP));
+    let mut base = ir::Exp::Temp(Temp::Reg(Reg::RBP));
     let link = store("STATIC_LINK");
+    let current = frames.len() - 1;
 
     // Follow static links
-    frames.iter()
+    for out in 0..depth {
+        let load = frames[current - out].get(link, base);
+        base = ir::Exp::Temp(frames[current].link(out, load));
+    }
+
+    base
+}
+
+pub fn translate_simple_var(frames: &mut [Frame], name: &Symbol) -> ir::Tree {
+
+    let depth = frames.iter()
         .rev()
-        .fold_while(rbp, |acc, frame| {
-            if frame.contains(*name) {
-                Done(frame.get(*name, acc))
-            } else {
-                Continue(frame.get(link, acc))
-            }
-        })
-        .into_inner()
-        .into()
+        .position(|frame| frame.contains(*name))
+        .expect("Internal error: unbound variable");
+
+    let base = translate_frame_pointer(frames, depth);
+    frames[frames.len() - 1 - depth].get(*name, base).into()
 }
 
 pub fn translate_field_var(rec_exp: ir::Tree, index: usize) -> ir::Tree {
@@ -102,7 +115,7 @@ pub fn translate_nil() -> ir::Tree {
     ir::Exp::Const(0).into()
 }
 
-pub fn translate_var(frames: &[Frame], name: &Symbol) -> ir::Tree {
+pub fn translate_var(frames: &mut [Frame], name: &Symbol) -> ir::Tree {
     translate_simple_var(frames, name)
 }
 
@@ -123,7 +136,7 @@ pub fn translate_str(data: &mut Vec<ir::Data>, string: Symbol) -> ir::Tree {
     ir::Exp::Name(label).into()
 }
 
-pub fn translate_call(binding: &Binding, arg_exps: Vec<ir::Tree>) -> ir::Tree {
+pub fn translate_call(frames: &mut [Frame], binding: &Binding, arg_exps: Vec<ir::Tree>) -> ir::Tree {
 
     let mut arg_exps = arg_exps.into_iter()
         .map(|arg_exp| arg_exp.into())
@@ -131,8 +144,14 @@ pub fn translate_call(binding: &Binding, arg_exps: Vec<ir::Tree>) -> ir::Tree {
 
     let label = match binding {
     | Binding::Ext(_, _, label) => label,
-    | Binding::Fun(_, _, label) => {
-        arg_exps.insert(0, ir::Exp::Temp(Temp::from_reg(Reg::RBP)).into());
+    | Binding::Fun(_, _, label, depth) => {
+
+        // Static link is the frame the callee was declared in,
+        // found by following links out from the caller's frame
+        let out = frames.len() - 1 - depth;
+        let parent = translate_frame_pointer(frames, out);
+
+        arg_exps.insert(0, parent);
         label
     },
     | _ => panic!("Internal error: call of non-function"),
@@ -227,10 +246,20 @@ pub fn translate_bin(lhs_exp: ir::Tree, op: Binop, rhs_exp: ir::Tree) -> ir::Tre
 
 }
 
+/// Size in bytes of a record with `fields` fields, unless it overflows an `i32`.
+/// Empty records still take a word, since `malloc(0)` may return a null pointer
+/// that would compare equal to `nil`, or the same pointer for distinct records.
+pub fn record_size(fields: usize) -> Option<i32> {
+    if fields > i32::MAX as usize { return None }
+    (fields.max(1) as i32).checked_mul(WORD_SIZE)
+}
+
 pub fn translate_rec(fields_exp: Vec<ir::Tree>) -> ir::Tree {
 
     // Calculate record size for malloc
-    let size = ir::Exp::Const(WORD_SIZE * fields_exp.len() as i32);
+    let size = record_size(fields_exp.len())
+        .map(ir::Exp::Const)
+        .expect("Internal error: record size overflow");
 
     // Retrieve malloc label
     // TODO: is it okay to hard-code this?
@@ -275,17 +304,34 @@ pub fn translate_rec(fields_exp: Vec<ir::Tree>) -> ir::Tree {
     ).into()
 }
 
-pub fn translate_seq(mut seq_exps: Vec<ir::Tree>) -> ir::Tree {
+pub fn translate_seq(mut seq_exps: Vec<(Span, ir::Tree)>) -> ir::Tree {
 
     // Unit is a no-op
     if seq_exps.is_empty() {
         return ir::Exp::Const(0).into()
     }
 
-    let last = seq_exps.pop().unwrap();
-    let rest = seq_exps.into_iter()
-        .map(|seq_exp| seq_exp.into())
-        .collect::<Vec<_>>();
+    let (last_span, last) = seq_exps.pop().unwrap();
+    let mut rest = Vec::new();
+
+    // Mark the start of each statement with its source location
+    for (span, seq_exp) in seq_exps {
+        rest.push(ir::Stm::Loc(span));
+        rest.push(seq_exp.into());
+    }
+
+    rest.push(ir::Stm::Loc(last_span));
+
+    // Keep a trailing comparison conditional, e.g. for a parenthesized guard
+    if let ir::Tree::Cx(last_cond) = last {
+        return ir::Tree::Cx(
+            Box::new(move |t, f| {
+                let mut stms = rest.clone();
+                stms.push(last_cond(t, f));
+                ir::Stm::Seq(stms)
+            })
+        )
+    }
 
     ir::Exp::ESeq(
         Box::new(ir::Stm::Seq(rest)),
@@ -367,10 +413,10 @@ pub fn translate_if(guard_exp: ir::Tree, then_exp: ir::Tree, opt_or_exp: Option<
     }
 }
 
-pub fn translate_while(s_label: Label, guard_exp: ir::Tree, body_exp: ir::Tree) -> ir::Tree {
+pub fn translate_while(e_label: Label, guard_exp: ir::Tree, body_exp: ir::Tree) -> ir::Tree {
 
+    let s_label = Label::from_str("START_WHILE");
     let t_label = Label::from_str("TRUE_BRANCH");
-    let e_label = Label::from_str("EXIT_WHILE");
     let guard_cond: ir::Cond = guard_exp.into();
 
     ir::Stm::Seq(vec![
@@ -409,7 +455,7 @@ pub fn translate_for_index(frames: &mut [Frame], name: Symbol, escape: bool) ->
 }
 
 pub fn translate_for(
-    s_label: Label,
+    e_label: Label,
     index_exp: ir::Tree,
     lo_exp: ir::Tree,
     hi_exp: ir::Tree,
@@ -417,8 +463,9 @@ pub fn translate_for(
 ) -> ir::Tree {
 
     let index_exp: ir::Exp = index_exp.into();
+    let hi_temp = ir::Exp::Temp(Temp::from_str("FOR_HI"));
+    let s_label = Label::from_str("START_FOR");
     let t_label = Label::from_str("TRUE_BRANCH");
-    let e_label = Label::from_str("EXIT_FOR");
 
     ir::Stm::Seq(vec![
 
@@ -428,25 +475,36 @@ pub fn translate_for(
             index_exp.clone(),
         ),
 
-        // Invariant: all labels must be proceeded by a jump
-        ir::Stm::Jump(
-            ir::Exp::Name(s_label),
-            vec![s_label],
+        // Evaluate upper bound once, before the first iteration
+        ir::Stm::Move(
+            hi_exp.into(),
+            hi_temp.clone(),
         ),
 
-        // Loop header
-        ir::Stm::Label(s_label),
+        // Skip the loop entirely if the range is empty
         ir::Stm::CJump(
             index_exp.clone(),
             ir::Relop::Gt,
-            hi_exp.into(),
+            hi_temp.clone(),
+            e_label,
+            s_label,
+        ),
+
+        // Loop body
+        ir::Stm::Label(s_label),
+        body_exp.into(),
+
+        // Exit before incrementing past the bound, which may be the largest integer
+        ir::Stm::CJump(
+            index_exp.clone(),
+            ir::Relop::Ge,
+            hi_temp,
             e_label,
             t_label,
         ),
 
-        // True branch: execute body and then increment index
+        // Increment index and repeat
         ir::Stm::Label(t_label),
-        body_exp.into(),
         ir::Stm::Move(
             ir::Exp::Binop(
                 Box::new(index_exp.clone()),
@@ -479,6 +537,8 @@ pub fn translate_let(dec_exps: Vec<ir::Tree>, body_exp: ir::Tree) -> ir::Tree {
 
 }
 
+/// The runtime's `init_array` takes the element count rather than a
+/// byte size, and scales by `WORD_SIZE` itself.
 pub fn translate_arr(size_exp: ir::Tree, init_exp: ir::Tree) -> ir::Tree {
 
     let init_array = Label::from_fixed("init_array");
@@ -517,7 +577,7 @@ fn translate_relop(op: &Binop) -> Option<ir::Relop> {
     }
 }
 
-pub fn translate_frame(label: &Label, args: &[FieldDec]) -> Frame {
+pub fn translate_frame(label: &Label, args: &[FieldDec], convention: Convention) -> Frame {
 
     // Set up static link as first argument
     let mut all_args = vec![
@@ -530,6 +590,6 @@ pub fn translate_frame(label: &Label, args: &[FieldDec]) -> Frame {
     );
 
     // Create new frame
-    Frame::new(*label, all_args)
+    Frame::new(*label, all_args, convention)
 
 }
@@ -0,0 +1,206 @@
+use fnv::{FnvHashMap, FnvHashSet};
+
+use ir::*;
+use operand::{Label, Temp};
+use error::{Error, IRError};
+
+pub fn validate(unit: &Unit) -> Result<(), Error> {
+    for function in &unit.functions {
+        validate_function(function)?;
+    }
+    Ok(())
+}
+
+fn error<T>(err: IRError) -> Result<T, Error> {
+    Err(Error::internal(err))
+}
+
+fn validate_function(function: &Function) -> Result<(), Error> {
+
+    // Collect all labels defined in this function
+    let defined = function.body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Label(label) => Some(*label),
+            | _                 => None,
+        })
+        .collect::<FnvHashSet<Label>>();
+
+    for (i, stm) in function.body.iter().enumerate() {
+        match stm {
+        | Stm::Seq(_) => return error(IRError::Seq(function.label)),
+        | Stm::Exp(_) => return error(IRError::Exp(function.label)),
+        | Stm::Label(_)
+        | Stm::Comment(_)
+        | Stm::Loc(_) => (),
+        | Stm::Move(src, dst) => {
+            validate_exp(function.label, src)?;
+            validate_exp(function.label, dst)?;
+        },
+        | Stm::Jump(Exp::Name(label), _) => {
+            if !defined.contains(label) {
+                return error(IRError::MissingLabel(function.label, *label))
+            }
+        },
+        | Stm::Jump(target @ Exp::Mem(_), labels) => {
+            validate_exp(function.label, target)?;
+            if let Some(label) = labels.iter().find(|label| !defined.contains(label)) {
+                return error(IRError::MissingLabel(function.label, *label))
+            }
+        },
+        | Stm::Jump(_, _) => return error(IRError::Jump(function.label)),
+        | Stm::CJump(lhs, _, rhs, t, f) => {
+
+            validate_exp(function.label, lhs)?;
+            validate_exp(function.label, rhs)?;
+
+            if !defined.contains(t) {
+                return error(IRError::MissingLabel(function.label, *t))
+            }
+
+            // False branch must fall through to its label. Reordering cleans
+            // away labels that only a single false branch refers to, since
+            // nothing jumps to them, so such a label may also be missing.
+            let falls_through = match function.body.get(i + 1) {
+            | Some(Stm::Label(next)) if next == f => true,
+            | _                                   => !defined.contains(f) && references(function, *f) == 1,
+            };
+
+            if !falls_through {
+                return error(IRError::CJump(function.label, *f))
+            }
+        },
+        }
+    }
+
+    validate_defs(function)
+}
+
+/// Number of jumps and conditional jumps in `function` that refer to `label`.
+fn references(function: &Function, label: Label) -> usize {
+    function.body.iter()
+        .map(|stm| match stm {
+            | Stm::Jump(_, targets)     => targets.iter().filter(|target| **target == label).count(),
+            | Stm::CJump(_, _, _, t, f) => (*t == label) as usize + (*f == label) as usize,
+            | _                         => 0,
+        })
+        .sum()
+}
+
+/// Make sure every temp is defined on all paths leading to each of its uses.
+/// Fixed registers are defined implicitly, e.g. by calls, and aren't checked.
+fn validate_defs(function: &Function) -> Result<(), Error> {
+
+    let body = &function.body;
+    let labels = body.iter()
+        .enumerate()
+        .filter_map(|(i, stm)| match stm {
+            | Stm::Label(label) => Some((*label, i)),
+            | _                 => None,
+        })
+        .collect::<FnvHashMap<_, _>>();
+
+    // Temps definitely defined before each statement, or None if not yet reached
+    let mut defined: Vec<Option<FnvHashSet<Temp>>> = vec![None; body.len() + 1];
+    defined[0] = Some(FnvHashSet::default());
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for (i, stm) in body.iter().enumerate() {
+
+            let mut out = match &defined[i] {
+            | Some(defined) => defined.clone(),
+            | None          => continue,
+            };
+
+            if let Stm::Move(_, Exp::Temp(temp)) = stm { out.insert(*temp); }
+
+            let successors = match stm {
+            | Stm::Jump(_, targets) => targets.iter().filter_map(|label| labels.get(label).cloned()).collect(),
+            | Stm::CJump(_, _, _, t, _) => vec![labels[t], i + 1],
+            | _ => vec![i + 1],
+            };
+
+            // Meet over all predecessors is the intersection of their definitions
+            for successor in successors {
+                let meet = match &defined[successor] {
+                | None          => out.clone(),
+                | Some(defined) => defined.intersection(&out).cloned().collect(),
+                };
+
+                if defined[successor].as_ref() != Some(&meet) {
+                    defined[successor] = Some(meet);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    for (stm, defined) in body.iter().zip(&defined) {
+        let defined = match defined {
+        | Some(defined) => defined,
+        | None          => continue,
+        };
+
+        let mut used = Vec::new();
+        match stm {
+        | Stm::Move(src, Exp::Temp(_)) => uses(src, &mut used),
+        | Stm::Move(src, dst) => {
+            uses(src, &mut used);
+            uses(dst, &mut used);
+        },
+        | Stm::CJump(lhs, _, rhs, _, _) => {
+            uses(lhs, &mut used);
+            uses(rhs, &mut used);
+        },
+        | Stm::Jump(target, _) => uses(target, &mut used),
+        | _ => (),
+        }
+
+        if let Some(temp) = used.into_iter().find(|temp| !defined.contains(temp)) {
+            return error(IRError::UseBeforeDef(function.label, temp, stm.to_string()))
+        }
+    }
+
+    Ok(())
+}
+
+fn uses(exp: &Exp, used: &mut Vec<Temp>) {
+    match exp {
+    | Exp::Temp(temp @ Temp::Temp { .. }) => used.push(*temp),
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)
+    | Exp::ESeq(_, _) => (),
+    | Exp::Mem(addr) => uses(addr, used),
+    | Exp::Binop(lhs, _, rhs) => {
+        uses(lhs, used);
+        uses(rhs, used);
+    },
+    | Exp::Call(_, args) => {
+        for arg in args { uses(arg, used) }
+    },
+    }
+}
+
+fn validate_exp(function: Label, exp: &Exp) -> Result<(), Error> {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_) => Ok(()),
+    | Exp::ESeq(_, _) => error(IRError::ESeq(function)),
+    | Exp::Mem(addr) => validate_exp(function, addr),
+    | Exp::Binop(lhs, _, rhs) => {
+        validate_exp(function, lhs)?;
+        validate_exp(function, rhs)
+    },
+    | Exp::Call(name, args) => {
+        if let Exp::Name(_) = **name {} else {
+            return error(IRError::Call(function))
+        }
+        for arg in args { validate_exp(function, arg)?; }
+        Ok(())
+    },
+    }
+}
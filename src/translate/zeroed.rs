@@ -0,0 +1,80 @@
+use ir::*;
+use operand::{Label, Temp};
+
+/// Allocates records with the runtime's zeroing `calloc` instead of `malloc`,
+/// for runtimes like garbage collectors that expect every word initialized.
+/// Fields explicitly initialized to zero are then redundant and dropped.
+pub fn zeroed(unit: Unit) -> Unit {
+    unit.map(|function| {
+        function.map(|body| {
+            body.into_iter()
+                .map(zeroed_stm)
+                .collect()
+        })
+    })
+}
+
+fn zeroed_stm(stm: Stm) -> Stm {
+    match stm {
+    | Stm::Exp(exp)                => Stm::Exp(zeroed_exp(exp)),
+    | Stm::Move(src, dst)          => Stm::Move(zeroed_exp(src), zeroed_exp(dst)),
+    | Stm::Jump(exp, labels)       => Stm::Jump(zeroed_exp(exp), labels),
+    | Stm::CJump(l, op, r, t, f)   => Stm::CJump(zeroed_exp(l), op, zeroed_exp(r), t, f),
+    | Stm::Seq(stms)               => Stm::Seq(stms.into_iter().map(zeroed_stm).collect()),
+    | Stm::Label(_)
+    | Stm::Comment(_)
+    | Stm::Loc(_)                  => stm,
+    }
+}
+
+fn zeroed_exp(exp: Exp) -> Exp {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)                 => exp,
+    | Exp::Binop(lhs, op, rhs)     => Exp::Binop(Box::new(zeroed_exp(*lhs)), op, Box::new(zeroed_exp(*rhs))),
+    | Exp::Mem(addr)               => Exp::Mem(Box::new(zeroed_exp(*addr))),
+    | Exp::Call(name, args)        => Exp::Call(name, args.into_iter().map(zeroed_exp).collect()),
+    | Exp::ESeq(stm, exp)          => {
+        let stm = zeroed_stm(*stm);
+        let exp = zeroed_exp(*exp);
+        match (stm, exp) {
+        | (Stm::Seq(seq), Exp::Temp(pointer)) => zeroed_rec(seq, pointer),
+        | (stm, exp)                          => Exp::ESeq(Box::new(stm), Box::new(exp)),
+        }
+    },
+    }
+}
+
+/// Rewrites the record allocation produced by `translate_rec`, if `seq` is one.
+fn zeroed_rec(mut seq: Vec<Stm>, pointer: Temp) -> Exp {
+
+    let malloc = Label::from_fixed("malloc");
+    let calloc = Label::from_fixed("calloc");
+
+    let allocates = match seq.first() {
+    | Some(Stm::Move(Exp::Call(box Exp::Name(label), args), Exp::Temp(temp))) => {
+        *label == malloc && *temp == pointer && args.len() == 1
+    },
+    | _ => false,
+    };
+
+    if !allocates {
+        return Exp::ESeq(Box::new(Stm::Seq(seq)), Box::new(Exp::Temp(pointer)))
+    }
+
+    // Allocate a single zeroed element of the record's size
+    if let Stm::Move(Exp::Call(name, args), _) = &mut seq[0] {
+        **name = Exp::Name(calloc);
+        args.insert(0, Exp::Const(1));
+    }
+
+    seq.retain(|stm| match stm {
+    | Stm::Move(Exp::Const(0), Exp::Mem(box Exp::Binop(box Exp::Temp(temp), Binop::Add, box Exp::Const(_)))) => {
+        *temp != pointer
+    },
+    | _ => true,
+    });
+
+    Exp::ESeq(Box::new(Stm::Seq(seq)), Box::new(Exp::Temp(pointer)))
+}
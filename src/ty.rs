@@ -10,6 +10,9 @@ pub enum Ty {
     Int,
     Str,
     Unit,
+
+    /// Type of expressions that never complete, which subtypes everything
+    Never,
     Arr(Box<Ty>, usize),
     Rec(Vec<(Symbol, Ty)>, usize),
     Name(Symbol, Option<Box<Ty>>),
@@ -19,6 +22,7 @@ impl Ty {
     pub fn subtypes(&self, rhs: &Self) -> bool {
         match (self, rhs) {
         | (Ty::Nil, Ty::Rec(_, _)) => true,
+        | (Ty::Never, _)           => true,
         | _                        => self == rhs,
         }
     }
@@ -30,6 +34,16 @@ impl Ty {
     pub fn is_unit(&self) -> bool {
         *self == Ty::Unit
     }
+
+    /// Whether values of this type point into the heap, i.e. records and arrays.
+    pub fn is_heap(&self) -> bool {
+        match self {
+        | Ty::Arr(_, _)
+        | Ty::Rec(_, _)         => true,
+        | Ty::Name(_, Some(ty)) => ty.is_heap(),
+        | _                     => false,
+        }
+    }
 }
 
 impl PartialEq for Ty {
@@ -38,7 +52,8 @@ impl PartialEq for Ty {
         | (Ty::Int, Ty::Int)
         | (Ty::Str, Ty::Str)
         | (Ty::Nil, Ty::Nil)
-        | (Ty::Unit, Ty::Unit) => true,
+        | (Ty::Unit, Ty::Unit)
+        | (Ty::Never, Ty::Never) => true,
         | (Ty::Arr(_, lid), Ty::Arr(_, rid))
         | (Ty::Rec(_, lid), Ty::Rec(_, rid)) => lid == rid,
         | (Ty::Name(_, _), _)
@@ -55,6 +70,7 @@ impl fmt::Display for Ty {
         | Ty::Int            => write!(fmt, "int"),
         | Ty::Str            => write!(fmt, "string"),
         | Ty::Unit           => write!(fmt, "unit"),
+        | Ty::Never          => write!(fmt, "never"),
         | Ty::Name(name, _)  => write!(fmt, "{}", name),
         | Ty::Arr(ty, _)     => write!(fmt, "array of {}", ty),
         | Ty::Rec(fields, _) => {
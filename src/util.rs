@@ -21,3 +21,20 @@ macro_rules! hashmap {
         }
     }
 }
+
+/// Escape a string for a double-quoted literal, in a form the assembler also
+/// accepts: control characters other than newline and tab become octal codes.
+pub fn escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+        | '"'                       => escaped.push_str("\\\""),
+        | '\\'                      => escaped.push_str("\\\\"),
+        | '\n'                      => escaped.push_str("\\n"),
+        | '\t'                      => escaped.push_str("\\t"),
+        | c if c.is_ascii_control() => escaped.push_str(&format!("\\{:03o}", c as u8)),
+        | c                         => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,10 +1,1084 @@
 #[macro_use]
 mod util;
 
+extern crate simple_symbol;
+extern crate tigerc;
+
 use std::fs::remove_file;
 
+use tigerc::asm::{Asm, Binary, Binop, Direct, Function, Relop, Unit, Unary, Unop, Value};
+use tigerc::assemble::spills;
+use tigerc::config::{CONVENTION, MAX_SPILLS, STRINGS, WORD_SIZE};
+use tigerc::error::{BackendError, Kind};
+use tigerc::ir;
+use tigerc::operand::{Convention, Imm, Label, Mem, Reg, Strings, Temp};
+use tigerc::translate::Layout;
+use tigerc::phase::*;
+
 use util::*;
 
 generate!(good, "type", "-t", "typedsol", "typed", compare_content);
 
 generate!(bad, "type", "-t", "typedsol", "typed", compare_location);
+
+fn assemble(path: &str, stub: bool) -> Unit<Reg> {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false))
+        .with_phase(Canonize::new(false))
+        .with_phase(Fold::new(false))
+        .with_phase(Reorder::new(false))
+        .with_phase(Tile::new(false))
+        .with_phase(Trivial::new(false))
+        .with_phase(CoalesceAssembly::new(false))
+        .with_phase(Schedule::new(false))
+        .with_phase(Stub::maybe(false, !stub));
+
+    match compiler.run() {
+    | Ok(Item::Assembly(unit)) => unit,
+    | _ => panic!("Expected assembly"),
+    }
+}
+
+#[test]
+pub fn test_stub_01() {
+    let unit = assemble("tests/assemble/stub_01.tig", true);
+
+    let instructions = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let called = instructions.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Call(label) => Some(*label),
+            | _                => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert!(!called.is_empty());
+
+    // Every callee must be defined, and runtime functions only weakly
+    for label in called {
+        let defined = instructions.iter()
+            .filter(|asm| **asm == Asm::Label(label))
+            .count();
+
+        let weak = instructions.iter()
+            .any(|asm| *asm == Asm::Direct(Direct::Weak(label)));
+
+        let external = !label.to_string().starts_with("show");
+
+        assert_eq!(defined, 1);
+        assert_eq!(weak, external);
+    }
+
+    let output = unit.to_string();
+    for runtime in &["malloc", "init_array", "__printi__", "__prints__"] {
+        assert!(output.contains(&format!(".weak {}", runtime)));
+    }
+}
+
+#[test]
+pub fn test_stub_02() {
+    let unit = assemble("tests/assemble/stub_01.tig", false);
+    assert!(!unit.to_string().contains(".weak"));
+}
+
+#[test]
+pub fn test_line_map_01() {
+    let mut compiler = Compiler::with_path("tests/assemble/line_map_01.tig")
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false))
+        .with_phase(Canonize::new(false))
+        .with_phase(Fold::new(false))
+        .with_phase(Reorder::new(false))
+        .with_phase(Tile::new(false))
+        .with_phase(Trivial::new(false));
+
+    let unit = match compiler.run() {
+    | Ok(Item::Assembly(unit)) => unit,
+    | _ => panic!("Expected assembly"),
+    };
+
+    let main = unit.functions.last().unwrap();
+    let map = main.line_map();
+    let code = compiler.code();
+    let statements = map.iter()
+        .map(|(_, span)| code.find_file(span.start()).unwrap().src_slice(*span).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(statements, vec![
+        "var a := 1",
+        "var b := 2",
+        "printi(a)",
+        "printi(b)",
+        "printi(a + b)",
+    ]);
+
+    let instructions = main.body.len() - map.len();
+    assert!(map.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(map.iter().all(|(index, _)| *index < instructions));
+}
+
+#[test]
+pub fn test_globl_main() {
+    let unit = assemble("tests/assemble/line_map_01.tig", false);
+    let output = unit.to_string();
+    assert!(output.contains(".globl main\n"));
+    assert!(output.contains("    main:\n"));
+
+    // Every function is declared global and defined at its prologue
+    for function in &unit.functions {
+        match &function.body[..3] {
+        | [Asm::Direct(Direct::Global(global)), Asm::Direct(Direct::Align(_)), Asm::Label(label)] => {
+            assert_eq!(global, label)
+        },
+        | _ => panic!("Expected function prologue"),
+        }
+    }
+}
+
+fn frame(body: Vec<Asm<Temp>>, escapes: usize) -> Function<Reg> {
+    let label = Label::from_fixed("frame");
+    let sub_rsp = simple_symbol::store("SUB");
+    let add_rsp = simple_symbol::store("ADD");
+
+    let body = vec![
+            Asm::Label(label),
+            Asm::Push(Unary::R(Temp::Reg(Reg::RBP))),
+            Asm::Comment(sub_rsp),
+        ].into_iter()
+        .chain(body.into_iter())
+        .chain(vec![
+            Asm::Comment(add_rsp),
+            Asm::Pop(Unary::R(Temp::Reg(Reg::RBP))),
+            Asm::Ret,
+        ].into_iter())
+        .collect();
+
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![Function { body, stack_info: (escapes, sub_rsp, add_rsp) }],
+    };
+
+    tigerc::assemble::allocate::<tigerc::assemble::Trivial>(unit).functions.pop().unwrap()
+}
+
+fn adjustments(function: &Function<Reg>) -> Vec<(Binop, Imm)> {
+    function.body.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Bin(op, Binary::IR(imm, Reg::RSP)) => Some((*op, *imm)),
+            | _                                      => None,
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_frame_empty() {
+    let leaf = frame(vec![
+        Asm::Mov(Binary::IR(Imm::Int(1), Temp::Reg(Reg::RAX))),
+    ], 0);
+
+    assert!(adjustments(&leaf).is_empty());
+    assert!(leaf.body.iter().all(|asm| match asm { Asm::Comment(_) => false, _ => true }));
+}
+
+#[test]
+pub fn test_frame_spill() {
+    let spill = Temp::from_str("SPILL");
+    let spilled = frame(vec![
+        Asm::Mov(Binary::IR(Imm::Int(1), spill)),
+        Asm::Mov(Binary::RR(spill, Temp::Reg(Reg::RAX))),
+    ], 1);
+
+    match adjustments(&spilled).as_slice() {
+    | [(Binop::Sub, Imm::Int(sub)), (Binop::Add, Imm::Int(add))] => {
+        assert_eq!(sub, add);
+        assert_eq!(*sub % 16, 0);
+        assert!(*sub > 0);
+    },
+    | _ => panic!("Expected matching stack adjustments"),
+    }
+}
+
+fn tile(body: Vec<ir::Stm>) -> Vec<Asm<Temp>> {
+    let unit = ir::Unit {
+        data: Vec::new(),
+        functions: vec![
+            ir::Function {
+                label: Label::from_fixed("tile"),
+                body,
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    tigerc::assemble::tile(unit).functions.pop().unwrap().body
+}
+
+#[test]
+pub fn test_tile_negative_offset() {
+    let arr = Temp::from_str("ARR");
+    let len = Temp::from_str("LEN");
+
+    // Header word stored just below the array pointer
+    let body = tile(vec![
+        ir::Stm::Move(
+            ir::Exp::Mem(Box::new(ir::Exp::Binop(
+                Box::new(ir::Exp::Temp(arr)),
+                ir::Binop::Sub,
+                Box::new(ir::Exp::Const(8)),
+            ))),
+            ir::Exp::Temp(len),
+        ),
+    ]);
+
+    assert!(body.contains(&Asm::Mov(Binary::MR(Mem::RO(arr, -8), len))));
+}
+
+fn tile_mul(n: i32) -> Vec<Asm<Temp>> {
+    tile(vec![
+        ir::Stm::Move(
+            ir::Exp::Binop(
+                Box::new(ir::Exp::Temp(Temp::from_str("X"))),
+                ir::Binop::Mul,
+                Box::new(ir::Exp::Const(n)),
+            ),
+            ir::Exp::Temp(Temp::from_str("Y")),
+        ),
+    ])
+}
+
+fn is_mul(asm: &Asm<Temp>) -> bool {
+    match asm { Asm::Mul(_) => true, _ => false }
+}
+
+#[test]
+pub fn test_tile_mul_shift() {
+    let body = tile_mul(8);
+    assert!(!body.iter().any(is_mul));
+    assert!(body.iter().any(|asm| match asm {
+        | Asm::Bin(Binop::Shl, Binary::IR(Imm::Int(3), _)) => true,
+        | _                                                => false,
+    }));
+}
+
+#[test]
+pub fn test_tile_mul_imul() {
+    let body = tile_mul(6);
+    assert!(body.iter().any(is_mul));
+    assert!(!body.iter().any(|asm| match asm { Asm::Bin(Binop::Shl, _) => true, _ => false }));
+}
+
+#[test]
+pub fn test_tile_index() {
+    let arr = Temp::from_str("ARR");
+    let index = Temp::from_str("INDEX");
+
+    // Shape produced by translate_index_var
+    let body = tile(vec![
+        ir::Stm::Move(
+            ir::Exp::Mem(Box::new(ir::Exp::Binop(
+                Box::new(ir::Exp::Temp(arr)),
+                ir::Binop::Add,
+                Box::new(ir::Exp::Binop(
+                    Box::new(ir::Exp::Temp(index)),
+                    ir::Binop::Mul,
+                    Box::new(ir::Exp::Const(8)),
+                )),
+            ))),
+            ir::Exp::Temp(Temp::from_str("ELEMENT")),
+        ),
+    ]);
+
+    assert!(!body.iter().any(is_mul));
+    assert!(body.iter().any(|asm| match asm {
+        | Asm::Bin(Binop::Shl, Binary::IR(Imm::Int(3), _)) => true,
+        | _                                                => false,
+    }));
+}
+
+fn schedule(body: Vec<Asm<Reg>>) -> Vec<Asm<Reg>> {
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![Function { body, stack_info: (0, simple_symbol::store("SUB"), simple_symbol::store("ADD")) }],
+    };
+
+    tigerc::optimize::schedule(unit).functions.pop().unwrap().body
+}
+
+#[test]
+pub fn test_schedule_independent() {
+    let independent = Asm::Mov(Binary::IR(Imm::Int(1), Reg::RAX));
+    let load = Asm::Mov(Binary::IR(Imm::Int(2), Reg::RBX));
+    let add = Asm::Bin(Binop::Add, Binary::RR(Reg::RBX, Reg::RCX));
+
+    // Longer dependency chain is started first
+    assert_eq!(schedule(vec![independent, load, add]), vec![load, independent, add]);
+}
+
+#[test]
+pub fn test_schedule_dependent() {
+    let load = Asm::Mov(Binary::IR(Imm::Int(2), Reg::RBX));
+    let add = Asm::Bin(Binop::Add, Binary::RR(Reg::RBX, Reg::RCX));
+    let store = Asm::Mov(Binary::RM(Reg::RCX, Mem::RO(Reg::RBP, -8)));
+    let reload = Asm::Mov(Binary::MR(Mem::RO(Reg::RBP, -8), Reg::RBX));
+
+    assert_eq!(schedule(vec![load, add]), vec![load, add]);
+    assert_eq!(schedule(vec![store, reload]), vec![store, reload]);
+}
+
+fn zero(body: Vec<Asm<Reg>>) -> Vec<Asm<Reg>> {
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![Function { body, stack_info: (0, simple_symbol::store("SUB"), simple_symbol::store("ADD")) }],
+    };
+
+    tigerc::optimize::zero(unit).functions.pop().unwrap().body
+}
+
+#[test]
+pub fn test_zero_xor() {
+    let clear = Asm::Mov(Binary::IR(Imm::Int(0), Reg::RAX));
+    let xor = Asm::Bin(Binop::XOr, Binary::RR(Reg::RAX, Reg::RAX));
+    let one = Asm::Mov(Binary::IR(Imm::Int(1), Reg::RAX));
+    let store = Asm::Mov(Binary::IM(Imm::Int(0), Mem::RO(Reg::RBP, -8)));
+
+    assert_eq!(zero(vec![clear, Asm::Ret]), vec![xor, Asm::Ret]);
+
+    // Only register destinations holding zero are rewritten
+    assert_eq!(zero(vec![one, store]), vec![one, store]);
+}
+
+#[test]
+pub fn test_zero_flags() {
+    let label = Label::from_str("TRUE");
+    let cmp = Asm::Cmp(Binary::RR(Reg::RBX, Reg::RCX));
+    let clear = Asm::Mov(Binary::IR(Imm::Int(0), Reg::RAX));
+    let xor = Asm::Bin(Binop::XOr, Binary::RR(Reg::RAX, Reg::RAX));
+    let jcc = Asm::Jcc(Relop::E, label);
+
+    // Flags set by cmp must survive until the jump reads them
+    assert_eq!(zero(vec![cmp, clear, jcc]), vec![cmp, clear, jcc]);
+    assert_eq!(zero(vec![clear, cmp, jcc]), vec![xor, cmp, jcc]);
+    assert_eq!(zero(vec![cmp, jcc, clear]), vec![cmp, jcc, xor]);
+
+    // Flags pass through not to the jump
+    let not = Asm::Un(Unop::Not, Unary::R(Reg::RDX));
+    assert_eq!(zero(vec![cmp, clear, not, jcc]), vec![cmp, clear, not, jcc]);
+    assert_eq!(zero(vec![clear, not, cmp, jcc]), vec![xor, not, cmp, jcc]);
+}
+
+#[test]
+pub fn test_flush_order() {
+    let unit = assemble("tests/assemble/flush_01.tig", false);
+    let called = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter_map(|asm| match asm {
+            | Asm::Call(label) => Some(label.to_string()),
+            | _                => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(called, vec!["__prints__", "__flush__", "__prints__"]);
+}
+
+#[test]
+pub fn test_annotate_temps() {
+    let output = assemble("tests/assemble/if_01.tig", false).to_string();
+    let annotation = output.lines()
+        .find(|line| line.trim().starts_with("# IF_ELSE_RESULT_"))
+        .expect("Missing annotation for if-else result");
+
+    assert!(annotation.ends_with("(%rbp)"));
+}
+
+fn tile_call(convention: Convention) -> Vec<Asm<Temp>> {
+    let args = (1..6).map(ir::Exp::Const).collect();
+    let unit = ir::Unit {
+        data: Vec::new(),
+        functions: vec![
+            ir::Function {
+                label: Label::from_fixed("caller"),
+                body: vec![
+                    ir::Stm::Move(
+                        ir::Exp::Call(Box::new(ir::Exp::Name(Label::from_fixed("callee"))), args),
+                        ir::Exp::Temp(Temp::from_str("RESULT")),
+                    ),
+                ],
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    tigerc::assemble::tile_with(unit, convention, STRINGS).functions.pop().unwrap().body
+}
+
+/// Registers and stack offsets written before the call, in order.
+fn call_destinations(body: &[Asm<Temp>]) -> (Vec<Reg>, Vec<i32>) {
+    let call = body.iter()
+        .position(|asm| match asm { Asm::Call(_) => true, _ => false })
+        .expect("Missing call");
+
+    let mut regs = Vec::new();
+    let mut stack = Vec::new();
+    for asm in &body[..call] {
+        match asm {
+        | Asm::Mov(Binary::RR(_, Temp::Reg(Reg::RBP))) => (),
+        | Asm::Mov(Binary::RR(_, Temp::Reg(reg)))      => regs.push(*reg),
+        | Asm::Mov(Binary::RM(_, Mem::RO(Temp::Reg(Reg::RSP), offset))) => stack.push(*offset),
+        | _ => (),
+        }
+    }
+
+    (regs, stack)
+}
+
+#[test]
+pub fn test_convention_arguments() {
+    let system_v = call_destinations(&tile_call(Convention::SystemV));
+    let microsoft = call_destinations(&tile_call(Convention::Microsoft));
+
+    assert_eq!(system_v, (vec![Reg::RDI, Reg::RSI, Reg::RDX, Reg::RCX, Reg::R8], vec![]));
+
+    // Fifth argument goes on the stack, past the shadow space
+    assert_eq!(microsoft, (vec![Reg::RCX, Reg::RDX, Reg::R8, Reg::R9], vec![32]));
+}
+
+#[test]
+pub fn test_convention_saved() {
+    let saves = |convention| tile_call(convention).iter()
+        .filter(|asm| match asm {
+            | Asm::Mov(Binary::RR(Temp::Reg(Reg::RDI), _))
+            | Asm::Mov(Binary::RR(Temp::Reg(Reg::RSI), _)) => true,
+            | _                                            => false,
+        })
+        .count();
+
+    // RDI and RSI are only callee-saved under the Microsoft convention
+    assert_eq!(saves(Convention::SystemV), 0);
+    assert_eq!(saves(Convention::Microsoft), 2);
+}
+
+#[test]
+pub fn test_static_link_spilled() {
+    let unit = assemble("tests/assemble/link_01.tig", false);
+
+    // Every nested function keeps its static link in memory for its children
+    let stored = unit.functions.iter()
+        .filter(|function| function.body.contains(&Asm::Mov(Binary::RM(Reg::RDI, Mem::RO(Reg::RBP, -8)))))
+        .count();
+
+    assert_eq!(stored, 3);
+}
+
+fn guard(path: &str, checked: bool) -> Unit<Temp> {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false))
+        .with_phase(Canonize::new(false))
+        .with_phase(Reorder::new(false))
+        .with_phase(Tile::new(false))
+        .with_phase(Canary::maybe(false, !checked));
+
+    match compiler.run() {
+    | Ok(Item::Abstract(unit)) => unit,
+    | _ => panic!("Expected abstract assembly"),
+    }
+}
+
+#[test]
+pub fn test_canary_01() {
+    let canary = Imm::Label(Label::from_fixed("__canary__"));
+    let abort = Label::from_fixed("__abort__");
+
+    for function in guard("tests/assemble/link_01.tig", true).functions {
+        let (_, sub_rsp, add_rsp) = function.stack_info;
+        let body = function.body;
+
+        // Canary is loaded right after the frame is allocated
+        let sub = body.iter().position(|asm| *asm == Asm::Comment(sub_rsp)).unwrap();
+        match &body[sub + 1..sub + 3] {
+        | [Asm::Mov(Binary::IR(imm, address)), Asm::Mov(Binary::MR(Mem::R(load), _))] => {
+            assert_eq!(*imm, canary);
+            assert_eq!(address, load);
+        },
+        | _ => panic!("Expected canary store in prologue"),
+        }
+
+        // And compared right before the frame is released
+        let add = body.iter().position(|asm| *asm == Asm::Comment(add_rsp)).unwrap();
+        match &body[add - 4..add] {
+        | [Asm::Cmp(Binary::RR(_, _)), Asm::Jcc(Relop::E, ok), Asm::Call(call), Asm::Label(label)] => {
+            assert_eq!(ok, label);
+            assert_eq!(*call, abort);
+        },
+        | _ => panic!("Expected canary comparison in epilogue"),
+        }
+    }
+
+    let output = guard("tests/assemble/link_01.tig", false).to_string();
+    assert!(!output.contains("__canary__"));
+    assert!(!output.contains("__abort__"));
+}
+
+/// Comparison emitted for `lhs < rhs`.
+fn tile_cmp(lhs: ir::Exp, rhs: ir::Exp) -> Binary<Temp> {
+    let t = Label::from_str("TRUE");
+    let f = Label::from_str("FALSE");
+    let body = tile(vec![ir::Stm::CJump(lhs, ir::Relop::Lt, rhs, t, f)]);
+
+    let cmp = body.iter()
+        .position(|asm| match asm { Asm::Cmp(_) => true, _ => false })
+        .expect("Expected comparison");
+
+    match &body[cmp..cmp + 2] {
+    | [Asm::Cmp(binary), Asm::Jcc(Relop::L, label)] if *label == t => *binary,
+    | _ => panic!("Expected comparison followed by conditional jump"),
+    }
+}
+
+fn field(rec: Temp) -> ir::Exp {
+    ir::Exp::Mem(Box::new(ir::Exp::Binop(
+        Box::new(ir::Exp::Temp(rec)),
+        ir::Binop::Add,
+        Box::new(ir::Exp::Const(8)),
+    )))
+}
+
+#[test]
+pub fn test_tile_cmp_mem() {
+    let rec = Temp::from_str("REC");
+    let x = Temp::from_str("X");
+
+    // AT&T cmp sets flags from destination minus source, so lhs is the destination
+    assert_eq!(tile_cmp(field(rec), ir::Exp::Const(1)), Binary::IM(Imm::Int(1), Mem::RO(rec, 8)));
+    assert_eq!(tile_cmp(field(rec), ir::Exp::Temp(x)), Binary::RM(x, Mem::RO(rec, 8)));
+    assert_eq!(tile_cmp(ir::Exp::Temp(x), field(rec)), Binary::MR(Mem::RO(rec, 8), x));
+
+    // An immediate can't be the destination, so it's loaded into a temp first
+    match tile_cmp(ir::Exp::Const(1), field(rec)) {
+    | Binary::RR(_, _) => (),
+    | binary           => panic!("Expected register comparison, found {:?}", binary),
+    }
+
+    // At most one operand can be in memory
+    match tile_cmp(field(rec), field(x)) {
+    | Binary::RM(_, mem) => assert_eq!(mem, Mem::RO(rec, 8)),
+    | binary             => panic!("Expected one memory operand, found {:?}", binary),
+    }
+}
+
+#[test]
+pub fn test_tile_mov_mem_mem() {
+    let rec = Temp::from_str("REC");
+    let arr = Temp::from_str("ARR");
+    let i = Temp::from_str("I");
+
+    // arr[i] := rec.field
+    let element = ir::Exp::Mem(Box::new(ir::Exp::Binop(
+        Box::new(ir::Exp::Temp(arr)),
+        ir::Binop::Add,
+        Box::new(ir::Exp::Binop(
+            Box::new(ir::Exp::Temp(i)),
+            ir::Binop::Mul,
+            Box::new(ir::Exp::Const(8)),
+        )),
+    )));
+
+    let body = tile(vec![ir::Stm::Move(field(rec), element)]);
+    let moves = body.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Mov(binary) => Some(*binary),
+            | _                => None,
+        })
+        .collect::<Vec<_>>();
+
+    // The field is staged through a register before being stored
+    let staged = moves.iter()
+        .find_map(|binary| match binary {
+            | Binary::MR(mem, temp) if *mem == Mem::RO(rec, 8) => Some(*temp),
+            | _                                              => None,
+        })
+        .expect("Expected field to be loaded into a register");
+
+    assert!(moves.iter().any(|binary| match binary {
+        | Binary::RM(temp, _) => *temp == staged,
+        | _                   => false,
+    }));
+}
+
+#[test]
+pub fn test_stats_01() {
+    let unit = assemble("tests/assemble/link_01.tig", false);
+    let stats = unit.stats();
+
+    for (function, stats) in unit.functions.iter().zip(&stats) {
+        let emitted = function.body.iter()
+            .filter(|asm| match asm {
+                | Asm::Label(_) | Asm::Comment(_) | Asm::Loc(_) | Asm::Direct(_) => false,
+                | _                                                              => true,
+            })
+            .count();
+
+        assert_eq!(stats.instructions, emitted);
+        assert_eq!(stats.frame_size % 16, 0);
+        assert!(stats.frame_size as usize >= (stats.spills + function.stack_info.0) * 8);
+    }
+
+    // main calls outer and printi, and each nested function calls one other
+    assert_eq!(stats.iter().map(|stats| stats.calls).sum::<usize>(), 5);
+    assert!(stats.iter().all(|stats| stats.spills > 0));
+}
+
+#[test]
+pub fn test_spills_01() {
+    let unit = assemble("tests/assemble/spills_01.tig", false);
+    let most = unit.stats().iter()
+        .map(|stats| stats.spills)
+        .max()
+        .unwrap();
+
+    assert!(most > 16);
+    assert!(spills(&unit, most).is_ok());
+    assert!(spills(&unit, MAX_SPILLS).is_ok());
+
+    match spills(&unit, 16).map_err(|err| err.kind) {
+    | Err(Kind::Backend(BackendError::TooManySpills(label, found, limit))) => {
+        assert!(label.to_string().starts_with("f_"));
+        assert_eq!((found, limit), (most, 16));
+    },
+    | _ => panic!("Expected too many spills"),
+    }
+}
+
+#[test]
+pub fn test_data_align() {
+    let unit = assemble("tests/assemble/data_01.tig", false);
+
+    let labels = unit.data.iter()
+        .enumerate()
+        .filter(|(_, asm)| if let Asm::Label(_) = asm { true } else { false })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    assert_eq!(labels.len(), 3);
+
+    // Each string's length prefix starts on a word boundary, so the string does too
+    for index in labels {
+        match unit.data[index - 1] {
+        | Asm::Direct(Direct::Quad(_)) => (),
+        | _                            => panic!("Expected length prefix"),
+        }
+        assert_eq!(unit.data[index - 2], Asm::Direct(Direct::Align(8)));
+    }
+}
+
+#[test]
+pub fn test_tile_nested_call() {
+    let call = |name: &'static str, args| ir::Exp::Call(Box::new(ir::Exp::Name(Label::from_fixed(name))), args);
+    let y = Temp::from_str("Y");
+
+    // f(g(), h(y)) before canonization, so both inner calls are still arguments
+    let body = tile(vec![
+        ir::Stm::Move(
+            call("f", vec![call("g", vec![]), call("h", vec![ir::Exp::Temp(y)])]),
+            ir::Exp::Temp(Temp::from_str("X")),
+        ),
+    ]);
+
+    // Argument registers written before each call, which must all belong to it
+    let mut segments = vec![(Vec::new(), None)];
+    for asm in &body {
+        match asm {
+        | Asm::Call(label) => {
+            segments.last_mut().unwrap().1 = Some(label.to_string());
+            segments.push((Vec::new(), None));
+        },
+        | Asm::Mov(binary) => match binary.dest() {
+            | Value::Reg(Temp::Reg(reg)) if CONVENTION.arguments().contains(&reg) => {
+                segments.last_mut().unwrap().0.push(reg);
+            },
+            | _ => (),
+        },
+        | _ => (),
+        }
+    }
+
+    assert_eq!(segments, vec![
+        (vec![], Some("g".to_string())),
+        (vec![Reg::RDI], Some("h".to_string())),
+        (vec![Reg::RDI, Reg::RSI], Some("f".to_string())),
+        (vec![], None),
+    ]);
+}
+
+#[test]
+pub fn test_tile_nested_call_constants() {
+    let call = |name: &'static str, args| ir::Exp::Call(Box::new(ir::Exp::Name(Label::from_fixed(name))), args);
+
+    // f(1, g(2), 3)
+    let body = tile(vec![
+        ir::Stm::Move(
+            call("f", vec![ir::Exp::Const(1), call("g", vec![ir::Exp::Const(2)]), ir::Exp::Const(3)]),
+            ir::Exp::Temp(Temp::from_str("X")),
+        ),
+    ]);
+
+    let g = body.iter().position(|asm| *asm == Asm::Call(Label::from_fixed("g"))).unwrap();
+    let f = body.iter().position(|asm| *asm == Asm::Call(Label::from_fixed("f"))).unwrap();
+
+    let loaded = |range: &[Asm<Temp>]| range.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Mov(Binary::IR(Imm::Int(n), _)) => Some(*n),
+            | _                                    => None,
+        })
+        .collect::<Vec<_>>();
+
+    let written = |range: &[Asm<Temp>]| range.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Mov(binary) => match binary.dest() {
+                | Value::Reg(Temp::Reg(reg)) if CONVENTION.arguments().contains(&reg) => Some(reg),
+                | _ => None,
+            },
+            | _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Outer constants are only materialized once the inner call has returned,
+    // so the call can't clobber them in their argument registers
+    assert_eq!(loaded(&body[..g]), vec![2]);
+    assert_eq!(written(&body[..g]), vec![Reg::RDI]);
+    assert_eq!(loaded(&body[g + 1..f]), vec![1, 3]);
+    assert_eq!(written(&body[g + 1..f]), vec![Reg::RDI, Reg::RSI, Reg::RDX]);
+}
+
+#[test]
+pub fn test_tile_nested_call_fixed() {
+    let call = |name: &'static str, args| ir::Exp::Call(Box::new(ir::Exp::Name(Label::from_fixed(name))), args);
+    let rcx = Temp::Reg(Reg::RCX);
+
+    // f(rcx, g()), where the call to g is free to clobber RCX
+    let body = tile(vec![
+        ir::Stm::Move(
+            call("f", vec![ir::Exp::Temp(rcx), call("g", vec![])]),
+            ir::Exp::Temp(Temp::from_str("X")),
+        ),
+    ]);
+
+    let g = body.iter().position(|asm| *asm == Asm::Call(Label::from_fixed("g"))).unwrap();
+
+    let saved = body[..g].iter()
+        .filter_map(|asm| match asm {
+            | Asm::Mov(Binary::RR(src, temp @ Temp::Temp { .. })) if *src == rcx => Some(*temp),
+            | _                                                              => None,
+        })
+        .next()
+        .expect("Expected RCX to be copied before the inner call");
+
+    assert!(body[g + 1..].contains(&Asm::Mov(Binary::RR(saved, Temp::Reg(Reg::RDI)))));
+}
+
+#[test]
+pub fn test_trap_01() {
+    let unit = assemble("tests/assemble/trap_01.tig", false);
+    let exit = Label::from_fixed("__exit__");
+
+    let body = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter(|asm| match asm { Asm::Loc(_) | Asm::Comment(_) => false, _ => true })
+        .collect::<Vec<_>>();
+
+    let call = body.iter()
+        .position(|asm| **asm == Asm::Call(exit))
+        .expect("Missing call to exit");
+
+    // Code after exit is still emitted, but a return from exit traps first
+    assert_eq!(*body[call + 1], Asm::Trap);
+    assert!(body[call + 1..].iter().any(|asm| **asm == Asm::Call(Label::from_fixed("__printi__"))));
+}
+
+fn tile_size(strings: Strings) -> Unit<Temp> {
+    let unit = ir::Unit {
+        data: vec![ir::Data::new(simple_symbol::store("hello"))],
+        functions: vec![
+            ir::Function {
+                label: Label::from_fixed("tile"),
+                body: vec![
+                    ir::Stm::Move(
+                        ir::Exp::Call(
+                            Box::new(ir::Exp::Name(Label::from_fixed("__size__"))),
+                            vec![ir::Exp::Temp(Temp::from_str("S"))],
+                        ),
+                        ir::Exp::Temp(Temp::from_str("N")),
+                    ),
+                ],
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    tigerc::assemble::tile_with(unit, CONVENTION, strings)
+}
+
+#[test]
+pub fn test_size_prefixed() {
+    let unit = tile_size(Strings::Prefixed);
+    let size = Label::from_fixed("__size__");
+
+    // Length is read from the word before the string, without calling the runtime
+    assert!(!unit.functions[0].body.contains(&Asm::Call(size)));
+    assert!(unit.functions[0].body.iter().any(|asm| match asm {
+        | Asm::Mov(Binary::MR(Mem::RO(_, -8), _)) => true,
+        | _                                       => false,
+    }));
+
+    assert!(unit.data.contains(&Asm::Direct(Direct::Quad(5))));
+}
+
+#[test]
+pub fn test_size_terminated() {
+    let unit = tile_size(Strings::Terminated);
+    let size = Label::from_fixed("__size__");
+
+    assert!(unit.functions[0].body.contains(&Asm::Call(size)));
+    assert!(unit.data.iter().all(|asm| match asm {
+        | Asm::Direct(Direct::Quad(_)) => false,
+        | _                            => true,
+    }));
+}
+
+#[test]
+pub fn test_section_comments() {
+    let unit = assemble("tests/assemble/if_01.tig", false);
+    let sections = ["prologue", "body", "epilogue"].iter()
+        .map(|section| Asm::Comment(simple_symbol::store(section)))
+        .collect::<Vec<_>>();
+
+    for function in &unit.functions {
+        let found = function.body.iter()
+            .filter(|asm| sections.contains(asm))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, sections);
+    }
+}
+
+fn uses_rbp(asm: &Asm<Reg>) -> bool {
+    let mem = |mem: &Mem<Reg>| match mem {
+        | Mem::R(reg) | Mem::RO(reg, _) => *reg == Reg::RBP,
+    };
+    match asm {
+    | Asm::Mov(Binary::MR(m, r))
+    | Asm::Mov(Binary::RM(r, m)) => *r == Reg::RBP || mem(m),
+    | Asm::Mov(Binary::RR(a, b)) => *a == Reg::RBP || *b == Reg::RBP,
+    | Asm::Push(Unary::R(r))
+    | Asm::Pop(Unary::R(r))      => *r == Reg::RBP,
+    | _                          => false,
+    }
+}
+
+#[test]
+pub fn test_omit_leaf() {
+    let unit = tigerc::optimize::omit(assemble("tests/assemble/leaf_01.tig", false));
+    let (callers, leaves): (Vec<_>, Vec<_>) = unit.functions.iter()
+        .partition(|function| function.body.iter().any(|asm| if let Asm::Call(_) = asm { true } else { false }));
+
+    assert_eq!(leaves.len(), 1);
+    assert!(!leaves[0].body.iter().any(uses_rbp));
+    assert!(!leaves[0].body.iter().any(|asm| match asm {
+        | Asm::Comment(text) => text.to_string().contains("%rbp"),
+        | _                  => false,
+    }));
+    assert!(leaves[0].body.iter().any(|asm| match asm {
+        | Asm::Mov(Binary::MR(Mem::RO(Reg::RSP, _), _))
+        | Asm::Mov(Binary::RM(_, Mem::RO(Reg::RSP, _))) => true,
+        | _                                             => false,
+    }));
+
+    // Functions that call out keep their frame pointer
+    for function in callers {
+        assert!(function.body.contains(&Asm::Push(Unary::R(Reg::RBP))));
+    }
+}
+
+/// Jumps whose last instruction repeats the one falling into their target.
+fn duplicate_tails(function: &Function<Reg>) -> usize {
+    let body = &function.body;
+    body.iter()
+        .enumerate()
+        .filter_map(|(index, asm)| match asm {
+            | Asm::Jmp(label) => Some((index, *label)),
+            | _               => None,
+        })
+        .filter(|(jump, label)| {
+            let target = body.iter().position(|asm| *asm == Asm::Label(*label)).unwrap();
+            match (&body[jump - 1], &body[target - 1]) {
+            | (Asm::Mov(a), Asm::Mov(b)) => a == b,
+            | _                          => false,
+            }
+        })
+        .count()
+}
+
+#[test]
+pub fn test_merge_tail() {
+    let unit = assemble("tests/assemble/tail_01.tig", false);
+    let before = unit.functions.iter().map(duplicate_tails).sum::<usize>();
+    let length = unit.functions.iter().map(|function| function.body.len()).sum::<usize>();
+    assert_eq!(before, 1);
+
+    // Both branches end by storing into y, which is now emitted once
+    let merged = tigerc::optimize::merge(unit);
+    let after = merged.functions.iter().map(duplicate_tails).sum::<usize>();
+    assert_eq!(after, 0);
+    assert!(merged.functions.iter().map(|function| function.body.len()).sum::<usize>() < length);
+}
+
+#[test]
+pub fn test_merge_flags() {
+    let (other, end) = (Label::from_str("OTHER"), Label::from_str("END"));
+    let set = Asm::Set(Relop::L, Reg::RAX);
+    let store = Asm::Mov(Binary::RR(Reg::RAX, Reg::RDI));
+    let body = vec![
+        Asm::Cmp(Binary::RR(Reg::RBX, Reg::RCX)),
+        Asm::Jcc(Relop::E, other),
+        set, store, Asm::Jmp(end),
+        Asm::Label(other),
+        set, store,
+        Asm::Label(end),
+        Asm::Ret,
+    ];
+
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![Function { body, stack_info: (0, simple_symbol::store("SUB"), simple_symbol::store("ADD")) }],
+    };
+
+    // The stores merge, but each setl keeps reading the flags in its own block
+    let merged = tigerc::optimize::merge(unit).functions.pop().unwrap().body;
+    assert_eq!(merged.iter().filter(|asm| **asm == set).count(), 2);
+    assert_eq!(merged.iter().filter(|asm| **asm == store).count(), 1);
+}
+
+#[test]
+pub fn test_tile_setcc() {
+    let unit = assemble("tests/assemble/set_01.tig", false);
+    let less = unit.functions.iter()
+        .find(|function| function.body.iter().any(|asm| match asm {
+            | Asm::Label(label) => label.to_string().starts_with("less"),
+            | _                 => false,
+        }))
+        .expect("Expected less");
+
+    // Comparison is materialized without branching around the false case
+    let set = less.body.iter()
+        .position(|asm| match asm { Asm::Set(Relop::L, _) => true, _ => false })
+        .expect("Expected setl");
+
+    assert!(less.body[set..].iter().any(|asm| match asm { Asm::Movzx(_, _) => true, _ => false }));
+    assert!(!less.body.iter().any(|asm| match asm {
+        | Asm::Jmp(_) | Asm::Jcc(_, _) => true,
+        | _                            => false,
+    }));
+}
+
+#[test]
+pub fn test_tile_setcc_self() {
+    let a = Temp::from_str("A");
+    let t = Label::from_str("T");
+    let f = Label::from_str("F");
+
+    // a := (a < 5), where the comparison sees a after it's been set to 1
+    let body = tile(vec![
+        ir::Stm::Move(ir::Exp::Const(1), ir::Exp::Temp(a)),
+        ir::Stm::CJump(ir::Exp::Temp(a), ir::Relop::Lt, ir::Exp::Const(5), t, f),
+        ir::Stm::Move(ir::Exp::Const(0), ir::Exp::Temp(a)),
+        ir::Stm::Label(t),
+    ]);
+
+    assert!(!body.iter().any(|asm| match asm { Asm::Set(_, _) => true, _ => false }));
+    assert!(body.contains(&Asm::Mov(Binary::IR(Imm::Int(1), a))));
+}
+
+#[test]
+pub fn test_stack_args_reserved() {
+    let unit = assemble("tests/assemble/args_02.tig", false);
+    let caller = unit.functions.iter()
+        .find(|function| function.body.iter().any(|asm| match asm {
+            | Asm::Label(label) => label.to_string().starts_with("caller"),
+            | _                 => false,
+        }))
+        .expect("Expected caller");
+
+    // Three outgoing arguments
+    assert!(caller.stack_info.0 >= 3);
+
+    let stores = caller.body.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Mov(Binary::RM(_, Mem::RO(Reg::RSP, offset))) => Some(*offset),
+            | Asm::Mov(Binary::IM(_, Mem::RO(Reg::RSP, offset))) => Some(*offset),
+            | _                                                  => None,
+        })
+        .collect::<Vec<_>>();
+
+    let reserved = caller.body.iter()
+        .filter_map(|asm| match asm {
+            | Asm::Bin(Binop::Sub, Binary::IR(Imm::Int(size), Reg::RSP)) => Some(*size),
+            | _                                                          => None,
+        })
+        .next()
+        .expect("Expected frame to be reserved");
+
+    // Every outgoing argument lands inside the frame, below the locals
+    assert_eq!(stores.len(), 3);
+    assert!(stores.iter().all(|offset| offset + WORD_SIZE <= reserved));
+}
+
+/// Callee-saved registers stored to and loaded from the stack.
+fn saves(function: &Function<Reg>) -> (Vec<Reg>, Vec<Reg>) {
+    let saved = |reg: &Reg| reg.is_callee_saved() && *reg != Reg::RSP && *reg != Reg::RBP;
+    let stores = function.body.iter()
+        .filter_map(|asm| match asm { Asm::Mov(Binary::RM(reg, _)) => Some(*reg), _ => None })
+        .filter(saved)
+        .collect();
+    let loads = function.body.iter()
+        .filter_map(|asm| match asm { Asm::Mov(Binary::MR(_, reg)) => Some(*reg), _ => None })
+        .filter(saved)
+        .collect();
+    (stores, loads)
+}
+
+#[test]
+pub fn test_prune_saves() {
+    let unit = ir::Unit {
+        data: Vec::new(),
+        functions: vec![
+            ir::Function {
+                label: Label::from_fixed("prune"),
+                body: vec![
+                    ir::Stm::Move(ir::Exp::Const(1), ir::Exp::Temp(Temp::Reg(Reg::RBX))),
+                ],
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    let unit = tigerc::assemble::allocate::<tigerc::assemble::Trivial>(tigerc::assemble::tile(unit));
+    let unit = tigerc::optimize::coalesce(unit);
+    let all = vec![Reg::RBX, Reg::R12, Reg::R13, Reg::R14, Reg::R15];
+    assert_eq!(saves(&unit.functions[0]), (all.clone(), all));
+
+    // Only RBX is written to, so R12-R15 are left as they were
+    let pruned = tigerc::optimize::prune(unit);
+    assert_eq!(saves(&pruned.functions[0]), (vec![Reg::RBX], vec![Reg::RBX]));
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "driver")]
+
+extern crate tigerc;
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tigerc::driver::build;
+use tigerc::phase::*;
+
+fn compile(path: &str, checked: bool) -> PathBuf {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false))
+        .with_phase(Bounds::maybe(false, !checked))
+        .with_phase(Exit::new(false))
+        .with_phase(Canonize::new(false))
+        .with_phase(Fold::new(false))
+        .with_phase(Reorder::new(false))
+        .with_phase(Tile::new(false))
+        .with_phase(Trivial::new(false))
+        .with_phase(CoalesceAssembly::new(false))
+        .with_phase(Stub::new(false));
+
+    match compiler.run() {
+    | Ok(Item::Assembly(unit)) => build(&unit, Path::new("runtime/libtiger.c"))
+        .expect("Failed to assemble and link"),
+    | _ => panic!("Expected assembly"),
+    }
+}
+
+#[test]
+pub fn test_exit_01() {
+    let status = Command::new(compile("tests/driver/exit_01.tig", false))
+        .status()
+        .expect("Failed to run executable");
+
+    assert_eq!(status.code(), Some(3));
+}
+
+#[test]
+pub fn test_bounds_01() {
+    let output = Command::new(compile("tests/driver/bounds_01.tig", true))
+        .output()
+        .expect("Failed to run executable");
+
+    // Every in-range index is written before the one past the end fails
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "0123456789");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Index out of bounds\n");
+}
+
+/// Compiles `path` through every phase enabled by default, including the optimizations.
+fn optimize(path: &str) -> PathBuf {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false))
+        .with_phase(Clear::new(false))
+        .with_phase(Exit::new(false))
+        .with_phase(Canonize::new(false))
+        .with_phase(Fold::new(false))
+        .with_phase(Propagate::new(false))
+        .with_phase(Share::new(false))
+        .with_phase(Eliminate::new(false))
+        .with_phase(Reorder::new(false))
+        .with_phase(Switch::new(false))
+        .with_phase(Tile::new(false))
+        .with_phase(CoalesceAbstract::new(false))
+        .with_phase(Trivial::new(false))
+        .with_phase(CoalesceAssembly::new(false))
+        .with_phase(Prune::new(false))
+        .with_phase(Schedule::new(false))
+        .with_phase(Merge::new(false))
+        .with_phase(Zero::new(false))
+        .with_phase(Omit::new(false))
+        .with_phase(Stub::new(false));
+
+    match compiler.run() {
+    | Ok(Item::Assembly(unit)) => build(&unit, Path::new("runtime/libtiger.c"))
+        .expect("Failed to assemble and link"),
+    | _ => panic!("Expected assembly"),
+    }
+}
+
+fn output(binary: PathBuf) -> String {
+    let output = Command::new(binary)
+        .output()
+        .expect("Failed to run executable");
+
+    String::from_utf8(output.stdout).expect("Expected UTF-8 output")
+}
+
+#[test]
+pub fn test_share_link() {
+    // x is shared across iterations through a temp that's read more than once
+    assert_eq!(output(optimize("tests/translate/link_02.tig")), "201");
+}
+
+#[test]
+pub fn test_share_queens() {
+    let output = output(optimize("tests/type/queens.tig"));
+    let boards = output.split("\n\n")
+        .filter(|board| !board.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    assert_eq!(boards.len(), 92);
+    assert_eq!(boards[0].lines().next(), Some(" O . . . . . . ."));
+}
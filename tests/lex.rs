@@ -1,8 +1,14 @@
 #[macro_use]
 mod util;
 
+extern crate simple_symbol;
+extern crate tigerc;
+
 use std::fs::remove_file;
 
+use tigerc::phase::*;
+use tigerc::token::Token;
+
 use util::*;
 
 generate!(good, "lex", "-l", "lexedsol", "lexed", compare_content);
@@ -60,3 +66,56 @@ good!(test_symbol_06, "symbol_06");
 good!(test_symbol_07, "symbol_07");
 good!(test_symbol_08, "symbol_08");
 good!(test_symbol_09, "symbol_09");
+
+good!(test_string_01, "string_01");
+good!(test_string_02, "string_02");
+
+bad!(test_bad_string_01, "bad_string_01");
+bad!(test_bad_string_02, "bad_string_02");
+
+#[test]
+pub fn test_string_span() {
+    let mut compiler = Compiler::with_path("tests/lex/string_01.tig")
+        .with_phase(Lex::new(false));
+
+    let tokens = match compiler.run() {
+    | Ok(Item::Tokens(tokens)) => tokens.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+    | _                        => panic!("Expected tokens"),
+    };
+
+    // Span covers the quoted source, while the value holds the decoded tab
+    match tokens.as_slice() {
+    | [(start, Token::Str(string), end)] => {
+        assert_eq!((*end - *start).to_usize(), 6);
+        assert_eq!(string, "a\tb");
+        assert_eq!(string.len(), 3);
+    },
+    | _ => panic!("Expected a single string token"),
+    }
+}
+
+#[test]
+pub fn test_describe() {
+    let described = vec![
+        Token::Then,
+        Token::Assign,
+        Token::LBrack,
+        Token::Int(42),
+        Token::Str("then".to_string()),
+        Token::Ident(simple_symbol::store("x")),
+    ];
+
+    // Literals are described by kind rather than by value
+    let described = described.iter()
+        .map(|token| token.describe().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(described, vec![
+        "keyword 'then'",
+        "operator ':='",
+        "'{'",
+        "integer literal",
+        "string literal",
+        "identifier",
+    ]);
+}
@@ -1,10 +1,27 @@
 #[macro_use]
 mod util;
 
+extern crate tigerc;
+
 use std::fs::remove_file;
+use std::path::PathBuf;
+
+use tigerc::ast::{Dec, Exp};
+use tigerc::phase::*;
 
 use util::*;
 
+fn parse(path: &str) -> Exp {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false));
+
+    match compiler.run() {
+    | Ok(Item::Syntax(ast)) => ast,
+    | _                     => panic!("Failed to parse {}", path),
+    }
+}
+
 generate!(good, "parse", "-p", "parsedsol", "parsed", compare_content);
 
 generate!(bad, "parse", "-p", "parsedsol", "parsed", compare_location);
@@ -69,3 +86,97 @@ good!(test_comment_04, "comment_04");
 good!(test_comment_05, "comment_05");
 
 bad!(test_bad_var_type_01, "bad_var_type_01");
+
+#[test]
+pub fn test_pure_01() {
+    let seq = match parse("tests/parse/pure_01.tig") {
+    | Exp::Seq(exps, _) => exps,
+    | _                 => panic!("Expected sequence"),
+    };
+
+    let pure = seq.iter()
+        .map(|exp| exp.is_pure())
+        .collect::<Vec<_>>();
+
+    assert_eq!(pure, vec![true, true, false, false]);
+    assert!(!Exp::Seq(seq, Default::default()).is_pure());
+}
+
+#[test]
+pub fn test_pure_02() {
+    assert!(parse("tests/parse/pure_02.tig").is_pure());
+}
+
+#[test]
+pub fn test_diverges_01() {
+    let decs = match parse("tests/parse/never_01.tig") {
+    | Exp::Let { decs, .. } => decs,
+    | _                     => panic!("Expected let expression"),
+    };
+
+    let diverges = match decs.as_slice() {
+    | [Dec::Fun(funs, _)] => funs.iter().map(|fun| fun.body.diverges(true)).collect::<Vec<_>>(),
+    | _                   => panic!("Expected function declarations"),
+    };
+
+    assert_eq!(diverges, vec![true, true, true, false, false]);
+}
+
+good!(test_call_01, "call_01");
+good!(test_call_02, "call_02");
+
+fn message(path: &str) -> String {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false));
+
+    match compiler.run() {
+    | Err(err) => (&err.kind).into(),
+    | _        => panic!("Expected parse error in {}", path),
+    }
+}
+
+#[test]
+pub fn test_expected_01() {
+    let message = message("tests/parse/expected_01.tig");
+    assert!(message.starts_with("Unexpected integer literal encountered, expected one of "));
+    assert!(message.contains("keyword 'then'"));
+    assert!(message.contains("operator '+'"));
+}
+
+#[test]
+pub fn test_recover_01() {
+    let mut compiler = Compiler::with_path("tests/parse/recover_01.tig")
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false));
+
+    let first = match compiler.run() {
+    | Err(err) => err,
+    | _        => panic!("Expected parse error"),
+    };
+
+    // Parsing resumes after the first malformed statement to find the second
+    let errors = compiler.errors();
+    assert_eq!(errors.len(), 1);
+    assert!(first.to_debug(compiler.code()).starts_with("5:12 syntactic error"));
+    assert!(errors[0].to_debug(compiler.code()).starts_with("7:8 syntactic error"));
+}
+
+#[test]
+pub fn test_recover_02() {
+    let mut compiler = Compiler::with_path("tests/parse/recover_01.tig")
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(true));
+
+    assert!(compiler.run().is_err());
+
+    // Recovered errors follow the first one in the diagnostic file
+    let path = PathBuf::from("tests/parse/recover_01.parsed");
+    let parsed = read_to_string(&path);
+    remove_file(&path).unwrap();
+
+    let lines = parsed.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("5:12 syntactic error"));
+    assert!(lines[1].starts_with("7:8 syntactic error"));
+}
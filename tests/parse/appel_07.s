@@ -0,0 +1,114 @@
+.local STRING_2
+.align 8
+    .quad 3
+STRING_2:
+    .string "str"
+.local STRING_3
+.align 8
+    .quad 1
+STRING_3:
+    .string " "
+.local STRING_4
+.align 8
+    .quad 4
+STRING_4:
+    .string "str2"
+
+
+    .globl do_nothing1_0
+    .align 4
+    do_nothing1_0:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_10 in -16(%rbp)
+    # STORE_R12_11 in -24(%rbp)
+    # STORE_R13_12 in -32(%rbp)
+    # STORE_R14_13 in -40(%rbp)
+    # STORE_R15_14 in -48(%rbp)
+    # ARG_0 in -56(%rbp)
+    # LINK_2 in -64(%rbp)
+    # TILE_UNARY_RESULT_9 in -72(%rbp)
+    # CANONIZE_CALL_5 in -80(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -72(%rbp)
+    incq %r10
+    movq %r10, -72(%rbp)
+    movq -64(%rbp), %rdi
+    movq -72(%rbp), %rsi
+    call do_nothing2_1
+    movq %rax, -80(%rbp)
+    xorq %rax, %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl do_nothing2_1
+    .align 4
+    do_nothing2_1:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_17 in -16(%rbp)
+    # STORE_R12_18 in -24(%rbp)
+    # STORE_R13_19 in -32(%rbp)
+    # STORE_R14_20 in -40(%rbp)
+    # STORE_R15_21 in -48(%rbp)
+    # ARG_3 in -56(%rbp)
+    # LINK_4 in -64(%rbp)
+    # CANONIZE_CALL_6 in -72(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -64(%rbp), %rdi
+    movq -56(%rbp), %rsi
+    movq $STRING_2, %rdx
+    call do_nothing1_0
+    movq %rax, -72(%rbp)
+    movq $STRING_3, %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_25 in -8(%rbp)
+    # STORE_R12_26 in -16(%rbp)
+    # STORE_R13_27 in -24(%rbp)
+    # STORE_R14_28 in -32(%rbp)
+    # STORE_R15_29 in -40(%rbp)
+    # CANONIZE_CALL_7 in -48(%rbp)
+    # body
+    movq %rbp, %rdi
+    xorq %rsi, %rsi
+    movq $STRING_4, %rdx
+    call do_nothing1_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
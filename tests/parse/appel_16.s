@@ -0,0 +1,25 @@
+.local STRING_0
+.align 8
+    .quad 0
+STRING_0:
+    .string ""
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_0 in -8(%rbp)
+    # STORE_R12_1 in -16(%rbp)
+    # STORE_R13_2 in -24(%rbp)
+    # STORE_R14_3 in -32(%rbp)
+    # STORE_R15_4 in -40(%rbp)
+    # body
+    movq $STRING_0, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+
+
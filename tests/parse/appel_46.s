@@ -0,0 +1,34 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $72, %rsp
+    # STORE_RBX_2 in -8(%rbp)
+    # STORE_R12_3 in -16(%rbp)
+    # STORE_R13_4 in -24(%rbp)
+    # STORE_R14_5 in -32(%rbp)
+    # STORE_R15_6 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # COND_EXP_1 in -56(%rbp)
+    # body
+    movq $0, 16(%rsp)
+    movq $1, 8(%rsp)
+    movq 16(%rsp), %r10
+    cmpq $0, %r10
+    je TRUE_BRANCH_0
+    TRUE_BRANCH_0:
+    movq 16(%rsp), %r10
+    cmpq $0, %r10
+    jne TRUE_BRANCH_2
+    xorq %r10, %r10
+    movq %r10, 8(%rsp)
+    TRUE_BRANCH_2:
+    movq 8(%rsp), %rax
+    # epilogue
+    addq $72, %rsp
+    ret
+
+
+
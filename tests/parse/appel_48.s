@@ -0,0 +1,54 @@
+
+
+    .globl g_0
+    .align 4
+    g_0:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_2 in -16(%rbp)
+    # STORE_R12_3 in -24(%rbp)
+    # STORE_R13_4 in -32(%rbp)
+    # STORE_R14_5 in -40(%rbp)
+    # STORE_R15_6 in -48(%rbp)
+    # body
+    movq %rdi, 40(%rsp)
+    movq %rsi, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+    .globl g_1
+    .align 4
+    g_1:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_7 in -16(%rbp)
+    # STORE_R12_8 in -24(%rbp)
+    # STORE_R13_9 in -32(%rbp)
+    # STORE_R14_10 in -40(%rbp)
+    # STORE_R15_11 in -48(%rbp)
+    # body
+    movq %rdi, 40(%rsp)
+    movq %rsi, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_12 in -8(%rbp)
+    # STORE_R12_13 in -16(%rbp)
+    # STORE_R13_14 in -24(%rbp)
+    # STORE_R14_15 in -32(%rbp)
+    # STORE_R15_16 in -40(%rbp)
+    # body
+    xorq %rax, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+
+
@@ -0,0 +1,44 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $88, %rsp
+    # STORE_RBX_4 in -8(%rbp)
+    # STORE_R12_5 in -16(%rbp)
+    # STORE_R13_6 in -24(%rbp)
+    # STORE_R14_7 in -32(%rbp)
+    # STORE_R15_8 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # TILE_BINOP_RESULT_3 in -56(%rbp)
+    # TILE_BINOP_RESULT_2 in -64(%rbp)
+    # TILE_BINOP_RESULT_1 in -72(%rbp)
+    # body
+    movq $5, 32(%rsp)
+    movq 32(%rsp), %r11
+    movq %r11, 24(%rsp)
+    movq 32(%rsp), %r10
+    movq 24(%rsp), %r11
+    addq %r10, %r11
+    movq %r11, 24(%rsp)
+    movq %r11, %r10
+    movq %r10, 16(%rsp)
+    movq 32(%rsp), %r10
+    movq 16(%rsp), %r11
+    addq %r10, %r11
+    movq %r11, 16(%rsp)
+    movq %r11, %r10
+    movq %r10, 8(%rsp)
+    movq 32(%rsp), %r10
+    movq 8(%rsp), %r11
+    addq %r10, %r11
+    movq %r11, %r10
+    movq %r11, 8(%rsp)
+    movq %r10, %rax
+    # epilogue
+    addq $88, %rsp
+    ret
+
+
+
@@ -0,0 +1,27 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_2 in -8(%rbp)
+    # STORE_R12_3 in -16(%rbp)
+    # STORE_R13_4 in -24(%rbp)
+    # STORE_R14_5 in -32(%rbp)
+    # STORE_R15_6 in -40(%rbp)
+    # CANONIZE_CALL_0 in -48(%rbp)
+    # body
+    call __flush__
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,1078 @@
+#![feature(box_patterns)]
+
+extern crate regex;
+extern crate simple_symbol;
+extern crate tigerc;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use regex::{Captures, Regex};
+use simple_symbol::store;
+
+use tigerc::ir::*;
+use tigerc::operand::{Convention, Label, Reg, Temp};
+use tigerc::assemble::tile;
+use tigerc::check;
+use tigerc::translate::{canonize, layout, propagate, record_size, Layout, Root};
+use tigerc::phase::*;
+
+/// Intermediate code for `path` after type checking and then `phases`.
+fn lower(path: &str, phases: Vec<Box<dyn Phase>>) -> Unit {
+    lower_with(Compiler::with_path(path), phases)
+}
+
+/// Same as `lower`, but starts from an already configured `compiler`.
+fn lower_with(compiler: Compiler, phases: Vec<Box<dyn Phase>>) -> Unit {
+    let compiler = compiler
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false));
+
+    match phases.into_iter().fold(compiler, Compiler::with_phase).run() {
+    | Ok(Item::Intermediate(unit)) => unit,
+    | _ => panic!("Expected intermediate code"),
+    }
+}
+
+/// Abstract syntax of the program in `path`.
+fn parse(path: &str) -> tigerc::ast::Exp {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false));
+
+    match compiler.run() {
+    | Ok(Item::Syntax(ast)) => ast,
+    | _ => panic!("Expected abstract syntax tree"),
+    }
+}
+
+fn translate(path: &str) -> Unit {
+    lower(path, vec![Canonize::new(false)])
+}
+
+fn optimize(path: &str) -> Unit {
+    lower(path, vec![
+        Canonize::new(false),
+        Fold::new(false),
+        Propagate::new(false),
+        Eliminate::new(false),
+        Reorder::new(false),
+    ])
+}
+
+/// Canonized IR for `source`, with every label and temp renumbered in order
+/// of first appearance, so the output doesn't depend on what ran before.
+fn snapshot(source: &str) -> String {
+    static SNAPSHOTS: AtomicUsize = AtomicUsize::new(0);
+
+    let id = SNAPSHOTS.fetch_add(1, Ordering::SeqCst);
+    let path = std::env::temp_dir().join(format!("tigerc_snapshot_{}_{}.tig", std::process::id(), id));
+    std::fs::write(&path, source).unwrap();
+    let unit = translate(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+
+    let mut ids: HashMap<(String, String), usize> = HashMap::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let generated = Regex::new(r"\b([A-Za-z][A-Za-z0-9_]*?)_(\d+)\b").unwrap();
+
+    generated.replace_all(unit.to_string().trim_end(), |caps: &Captures| {
+        let name = caps[1].to_string();
+        let next = counts.get(&name).cloned().unwrap_or(0);
+        let id = *ids.entry((name.clone(), caps[2].to_string())).or_insert_with(|| {
+            counts.insert(name.clone(), next + 1);
+            next
+        });
+        format!("{}_{}", name, id)
+    }).into_owned()
+}
+
+fn collect_calls(exp: &Exp, calls: &mut Vec<(Exp, Vec<Exp>)>) {
+    match exp {
+    | Exp::Const(_)
+    | Exp::Name(_)
+    | Exp::Temp(_)
+    | Exp::ESeq(_, _) => (),
+    | Exp::Mem(addr) => collect_calls(addr, calls),
+    | Exp::Binop(lhs, _, rhs) => {
+        collect_calls(lhs, calls);
+        collect_calls(rhs, calls);
+    },
+    | Exp::Call(name, args) => {
+        calls.push((*name.clone(), args.clone()));
+        for arg in args { collect_calls(arg, calls); }
+    },
+    }
+}
+
+fn calls(unit: &Unit) -> Vec<(Exp, Vec<Exp>)> {
+    let mut calls = Vec::new();
+    for function in &unit.functions {
+        for stm in &function.body {
+            match stm {
+            | Stm::Move(src, dst) => {
+                collect_calls(src, &mut calls);
+                collect_calls(dst, &mut calls);
+            },
+            | Stm::Exp(exp) => collect_calls(exp, &mut calls),
+            | Stm::CJump(lhs, _, rhs, _, _) => {
+                collect_calls(lhs, &mut calls);
+                collect_calls(rhs, &mut calls);
+            },
+            | _ => (),
+            }
+        }
+    }
+    calls
+}
+
+/// Arguments of each call to the fixed label `name`.
+fn calls_to(unit: &Unit, name: &'static str) -> Vec<Vec<Exp>> {
+    let label = Label::from_fixed(name);
+    calls(unit).into_iter()
+        .filter_map(|(callee, args)| match callee {
+            | Exp::Name(callee) if callee == label => Some(args),
+            | _                                    => None,
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_size_01() {
+    let unit = translate("tests/translate/size_01.tig");
+
+    // Translation always calls size, leaving the tiler to read prefixed lengths
+    let args = calls_to(&unit, "__size__");
+
+    assert_eq!(args.len(), 2);
+
+    for (arg, string) in args.iter().zip(&["", "tiger"]) {
+        let datum = unit.data.iter()
+            .find(|datum| datum.data.to_string() == *string)
+            .expect("Missing string literal");
+
+        match arg.as_slice() {
+        | [Exp::Name(label)] => assert_eq!(*label, datum.label),
+        | _                  => panic!("Expected string literal argument"),
+        }
+    }
+}
+
+#[test]
+pub fn test_propagate_chain() {
+    let a = Temp::from_str("A");
+    let b = Temp::from_str("B");
+    let c = Temp::from_str("C");
+    let d = Temp::from_str("D");
+
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![
+            Function {
+                label: Label::from_fixed("main"),
+                body: vec![
+                    Stm::Move(Exp::Temp(a), Exp::Temp(b)),
+                    Stm::Move(Exp::Temp(b), Exp::Temp(c)),
+                    Stm::Move(
+                        Exp::Binop(Box::new(Exp::Temp(c)), Binop::Add, Box::new(Exp::Const(1))),
+                        Exp::Temp(d),
+                    ),
+                ],
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    let body = &propagate(unit).functions[0].body;
+
+    match &body[2] {
+    | Stm::Move(Exp::Binop(box Exp::Temp(used), Binop::Add, _), Exp::Temp(def)) => {
+        assert_eq!(*used, a);
+        assert_eq!(*def, d);
+    },
+    | _ => panic!("Expected binop move"),
+    }
+}
+
+#[test]
+pub fn test_propagate_redefined() {
+    let a = Temp::from_str("A");
+    let b = Temp::from_str("B");
+    let c = Temp::from_str("C");
+
+    let unit = Unit {
+        data: Vec::new(),
+        functions: vec![
+            Function {
+                label: Label::from_fixed("main"),
+                body: vec![
+                    Stm::Move(Exp::Temp(a), Exp::Temp(b)),
+                    Stm::Move(Exp::Const(1), Exp::Temp(a)),
+                    Stm::Move(Exp::Temp(b), Exp::Temp(c)),
+                ],
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    };
+
+    let body = &propagate(unit).functions[0].body;
+
+    // Original was overwritten, so the copy must be used
+    match &body[2] {
+    | Stm::Move(Exp::Temp(used), _) => assert_eq!(*used, b),
+    | _ => panic!("Expected temp move"),
+    }
+}
+
+#[test]
+pub fn test_reorder_while_01() {
+    let unit = lower("tests/translate/while_01.tig", vec![Canonize::new(false), Reorder::new(false)]);
+
+    let body = unit.functions[0].body.iter()
+        .filter(|stm| match stm { Stm::Loc(_) => false, _ => true })
+        .collect::<Vec<_>>();
+
+    let print = body.iter()
+        .position(|stm| match stm { Stm::Move(Exp::Call(_, _), _) => true, _ => false })
+        .expect("Missing call to printi");
+
+    // Code after the loop must not be reachable by falling out of the guard
+    match body[print - 1] {
+    | Stm::CJump(_, _, _, _, _) => panic!("Loop exit falls through into loop body"),
+    | _ => (),
+    }
+}
+
+#[test]
+pub fn test_break_01() {
+    let unit = translate("tests/translate/break_01.tig");
+    let is_exit = |label: &Label| label.to_string().starts_with("EXIT_WHILE");
+
+    // Inner loop exits first, since it's nested in the outer body
+    let exits = unit.functions[0].body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Label(label) if is_exit(label) => Some(*label),
+            | _                                   => None,
+        })
+        .collect::<Vec<_>>();
+
+    let breaks = unit.functions[0].body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Jump(_, targets) if targets.iter().any(is_exit) => Some(targets.clone()),
+            | _                                                    => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(exits.len(), 2);
+    assert_eq!(breaks, vec![vec![exits[0]], vec![exits[1]]]);
+
+    // Code left unreachable by a break is dropped instead of breaking reordering
+    optimize("tests/translate/break_01.tig");
+}
+
+#[test]
+pub fn test_for_hi_01() {
+    let unit = translate("tests/translate/for_hi_01.tig");
+    let main = unit.functions.iter()
+        .find(|function| function.label == Label::from_fixed("main"))
+        .expect("Missing main function");
+
+    let start = main.body.iter()
+        .position(|stm| match stm {
+            | Stm::Label(label) => label.to_string().starts_with("START_FOR"),
+            | _                 => false,
+        })
+        .expect("Missing loop header");
+
+    let bounds = main.body.iter()
+        .enumerate()
+        .filter(|(_, stm)| match stm {
+            | Stm::Move(Exp::Call(box Exp::Name(label), _), _) => label.to_string().starts_with("bound"),
+            | _                                                => false,
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    // Upper bound is called once, before entering the loop
+    assert_eq!(bounds.len(), 1);
+    assert!(bounds[0] < start);
+}
+
+#[test]
+pub fn test_for_max_01() {
+    let unit = translate("tests/translate/for_max_01.tig");
+    let body = &unit.functions[0].body;
+
+    let increment = body.iter()
+        .position(|stm| match stm {
+            | Stm::Move(Exp::Binop(_, Binop::Add, box Exp::Const(1)), _) => true,
+            | _                                                          => false,
+        })
+        .expect("Missing index increment");
+
+    let checks = body.iter()
+        .enumerate()
+        .filter_map(|(index, stm)| match stm {
+            | Stm::CJump(_, op, _, _, _) => Some((index, *op)),
+            | _                          => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Index is compared with the bound before incrementing, so it never
+    // has to exceed the largest integer for the loop to exit
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks[1].1, Relop::Ge);
+    assert!(checks.iter().all(|(index, _)| *index < increment));
+}
+
+#[test]
+pub fn test_guard_01() {
+    let unit = translate("tests/translate/guard_01.tig");
+
+    let jumps = unit.functions[0].body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::CJump(lhs, op, rhs, _, _) => Some((lhs, *op, rhs)),
+            | _                              => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Comparisons jump directly instead of materializing a boolean to test
+    // against zero, even when parenthesized
+    match jumps.as_slice() {
+    | [(Exp::Temp(_), Relop::Lt, Exp::Temp(_)), (Exp::Temp(_), Relop::Lt, Exp::Temp(_))] => (),
+    | _ => panic!("Expected two comparisons of a and b, found {:?}", jumps),
+    }
+}
+
+fn callees(unit: &Unit) -> Vec<(String, usize)> {
+    calls(unit).into_iter()
+        .map(|(name, args)| match name {
+            | Exp::Name(label) => (label.to_string(), args.len()),
+            | _                => panic!("Expected named call"),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_flush_01() {
+    let expected = vec![
+        ("__prints__".to_string(), 1),
+        ("__flush__".to_string(), 0),
+        ("__prints__".to_string(), 1),
+    ];
+
+    // Unused unit result must not drop or reorder the call
+    assert_eq!(callees(&translate("tests/translate/flush_01.tig")), expected);
+    assert_eq!(callees(&optimize("tests/translate/flush_01.tig")), expected);
+}
+
+#[test]
+pub fn test_dce_01() {
+    let unit = optimize("tests/translate/dce_01.tig");
+    let body = &unit.functions[0].body;
+
+    assert_eq!(callees(&unit), vec![("__prints__".to_string(), 1)]);
+
+    // Dead initialization of x is removed, leaving the call and its result
+    let moves = body.iter()
+        .filter(|stm| match stm { Stm::Move(_, _) => true, _ => false })
+        .count();
+
+    assert_eq!(moves, 2);
+}
+
+#[test]
+pub fn test_record_size() {
+    assert_eq!(record_size(0), Some(8));
+    assert_eq!(record_size(3), Some(24));
+
+    // Largest record whose byte size still fits in an i32
+    let max = i32::MAX as usize / 8;
+    assert!(record_size(max).is_some());
+    assert!(record_size(max + 1).is_none());
+    assert!(record_size(usize::MAX).is_none());
+}
+
+#[test]
+pub fn test_record_large() {
+    let fields = 4096;
+    let decs = (0..fields).map(|i| format!("f{} : int", i)).collect::<Vec<_>>();
+    let inits = (0..fields).map(|i| format!("f{} = {}", i, i)).collect::<Vec<_>>();
+    let source = format!(
+        "let type big = {{ {} }} var b := big {{ {} }} in b.f{} end",
+        decs.join(", "),
+        inits.join(", "),
+        fields - 1,
+    );
+
+    let path = std::env::temp_dir().join("tigerc_record_large.tig");
+    std::fs::write(&path, source).unwrap();
+    let unit = translate(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+
+    match calls_to(&unit, "malloc").as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Const(size)] => assert_eq!(*size, fields * 8),
+        | _                  => panic!("Expected constant record size"),
+    },
+    | _ => panic!("Expected a single allocation"),
+    }
+}
+
+#[test]
+pub fn test_record_empty() {
+    let sizes = calls_to(&translate("tests/translate/empty_rec_01.tig"), "malloc");
+
+    // Each empty record still gets its own word
+    assert_eq!(sizes.len(), 2);
+    for args in sizes {
+        match args.as_slice() {
+        | [Exp::Const(size)] => assert_eq!(*size, 8),
+        | _                  => panic!("Expected constant record size"),
+        }
+    }
+}
+
+#[test]
+pub fn test_init_array_01() {
+    let args = calls_to(&translate("tests/translate/arr_01.tig"), "init_array");
+
+    // Size is passed as an element count, not scaled to bytes
+    match args.as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Const(10), Exp::Const(7)] => (),
+        | _                               => panic!("Expected element count and initial value"),
+    },
+    | _ => panic!("Expected a single array allocation"),
+    }
+}
+
+#[test]
+pub fn test_fold_div_zero() {
+    let args = calls_to(&optimize("tests/translate/div_zero_01.tig"), "__printi__");
+
+    match args.as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Binop(box Exp::Const(5), Binop::Div, box Exp::Const(0))] => (),
+        | _ => panic!("Expected division by zero to be left unfolded"),
+    },
+    | _ => panic!("Expected a single call to printi"),
+    }
+}
+
+#[test]
+pub fn test_merge_labels_01() {
+    let unit = optimize("tests/translate/labels_01.tig");
+    let body = &unit.functions[0].body;
+
+    let defined = body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Label(label) => Some(*label),
+            | _                 => None,
+        })
+        .collect::<Vec<_>>();
+
+    for pair in body.windows(2) {
+        match pair {
+        | [Stm::Label(_), Stm::Label(_)] => panic!("Expected back-to-back labels to merge"),
+        | _ => (),
+        }
+    }
+
+    for stm in body {
+        match stm {
+        | Stm::Jump(Exp::Name(label), _) => assert!(defined.contains(label)),
+        | Stm::CJump(_, _, _, t, _)      => assert!(defined.contains(t)),
+        | _ => (),
+        }
+    }
+}
+
+#[test]
+pub fn test_reorder_end_01() {
+    let unit = optimize("tests/translate/end_01.tig");
+    let last = unit.functions[0].body.iter()
+        .filter(|stm| match stm { Stm::Loc(_) => false, _ => true })
+        .last();
+
+    // Exit block falls through to the epilogue
+    match last {
+    | Some(Stm::Move(_, Exp::Temp(Temp::Reg(_)))) => (),
+    | _ => panic!("Expected function to end with its result"),
+    }
+}
+
+#[test]
+pub fn test_escape_01() {
+    let unit = translate("tests/translate/escape_01.tig");
+    let main = unit.functions.iter()
+        .find(|function| function.label == Label::from_fixed("main"))
+        .expect("Missing main");
+
+    // Initializer must be stored in the frame for f to read it
+    let init = main.body.iter()
+        .find(|stm| match stm { Stm::Move(Exp::Const(3), _) => true, _ => false });
+
+    match init {
+    | Some(Stm::Move(_, Exp::Mem(_))) => (),
+    | _ => panic!("Expected escaping variable to live in memory"),
+    }
+}
+
+#[test]
+pub fn test_exit_01() {
+    let exit = Label::from_fixed("__exit__");
+    let unit = lower("tests/translate/exit_01.tig", vec![Exit::new(false), Canonize::new(false)]);
+
+    let last = unit.functions[0].body.iter()
+        .filter(|stm| match stm { Stm::Loc(_) => false, _ => true })
+        .last();
+
+    match last {
+    | Some(Stm::Move(Exp::Call(box Exp::Name(label), args), _)) => {
+        assert_eq!(*label, exit);
+        match args.as_slice() {
+        | [Exp::Const(0)] => (),
+        | _               => panic!("Expected exit status 0"),
+        }
+    },
+    | _ => panic!("Expected main to end with a call to exit"),
+    }
+
+    // Hosted programs return from main instead
+    assert!(callees(&translate("tests/translate/exit_01.tig")).iter().all(|(name, _)| name != "__exit__"));
+}
+
+/// Number of static links followed from the frame pointer.
+fn link_depth(body: &[Stm], exp: &Exp) -> usize {
+    match exp {
+    | Exp::Temp(Temp::Reg(_)) => 0,
+    | Exp::Temp(temp) => body.iter()
+        .find_map(|stm| match stm {
+            | Stm::Move(src, Exp::Temp(dst)) if dst == temp => Some(link_depth(body, src)),
+            | _                                             => None,
+        })
+        .expect("Expected cached static link"),
+    | Exp::Mem(box Exp::Binop(box base, Binop::Sub, box Exp::Const(8))) => 1 + link_depth(body, base),
+    | _ => panic!("Expected static link chain"),
+    }
+}
+
+/// Temps caching an enclosing frame's pointer.
+fn links(body: &[Stm]) -> usize {
+    body.iter()
+        .filter(|stm| match stm {
+            | Stm::Move(_, Exp::Temp(Temp::Temp { name, .. })) => name.to_string() == "LINK",
+            | _                                                 => false,
+        })
+        .count()
+}
+
+#[test]
+pub fn test_static_link_01() {
+    let unit = translate("tests/translate/link_01.tig");
+    let function = |name: &str| unit.functions.iter()
+        .find(|function| function.label.to_string().starts_with(name))
+        .expect("Missing function");
+
+    // Each nested function stores its incoming link in its frame
+    for name in &["outer", "middle", "inner"] {
+        let body = &function(name).body;
+        match &body[0] {
+        | Stm::Move(Exp::Temp(Temp::Reg(_)), dst) => assert_eq!(link_depth(body, dst), 1),
+        | _ => panic!("Expected static link to be stored in the frame"),
+        }
+    }
+
+    let inner = &function("inner").body;
+
+    // x lives in main's frame: three links out, then its own slot
+    let x = inner.iter()
+        .find_map(|stm| match stm {
+            | Stm::CJump(x, _, _, _, _) => Some(x),
+            | _                         => None,
+        })
+        .expect("Expected comparison against x");
+
+    assert_eq!(link_depth(inner, x), 4);
+
+    // Calling outer passes main's frame, three links out
+    let links = inner.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Move(Exp::Call(_, args), _) => Some(link_depth(inner, &args[0])),
+            | _                                => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(links, vec![3]);
+}
+
+#[test]
+pub fn test_static_link_02() {
+    let unit = translate("tests/translate/link_02.tig");
+    let inner = &unit.functions.iter()
+        .find(|function| function.label.to_string().starts_with("inner"))
+        .expect("Missing function")
+        .body;
+
+    // Every read of x in the loop shares one load of each enclosing frame
+    assert_eq!(links(inner), 2);
+}
+
+#[test]
+pub fn test_matrix_01() {
+    let args = calls_to(&translate("tests/translate/matrix_01.tig"), "__printi__");
+
+    // m[i][j] loads the row, then indexes into it, scaling both by the word size
+    match args.as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Mem(box Exp::Binop(
+            box Exp::Mem(box Exp::Binop(_, Binop::Add, box Exp::Binop(_, Binop::Mul, box Exp::Const(8)))),
+            Binop::Add,
+            box Exp::Binop(_, Binop::Mul, box Exp::Const(8)),
+        ))] => (),
+        | _ => panic!("Expected two nested index computations"),
+    },
+    | _ => panic!("Expected a single call to printi"),
+    }
+}
+
+#[test]
+pub fn test_rec_arr_01() {
+    let args = calls_to(&translate("tests/translate/rec_arr_01.tig"), "__printi__");
+
+    // r.data[i] loads the second field, then scales the index into it
+    match args.as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Mem(box Exp::Binop(
+            box Exp::Mem(box Exp::Binop(_, Binop::Add, box Exp::Const(8))),
+            Binop::Add,
+            box Exp::Binop(_, Binop::Mul, box Exp::Const(8)),
+        ))] => (),
+        | _ => panic!("Expected a field load feeding an index computation"),
+    },
+    | _ => panic!("Expected a single call to printi"),
+    }
+}
+
+#[test]
+pub fn test_entry_01() {
+    let entry = Label::from_fixed("tiger_main");
+    let (unit, _) = check::check_with_entry(parse("tests/translate/exit_01.tig"), entry)
+        .expect("Expected valid program");
+
+    let labels = unit.functions.iter()
+        .map(|function| function.label)
+        .collect::<Vec<_>>();
+
+    assert_eq!(labels, vec![entry]);
+
+    let asm = tile(canonize(unit)).to_string();
+    assert!(asm.contains(".globl tiger_main"));
+    assert!(!asm.contains(".globl main"));
+}
+
+/// Registers and RBP offsets that `sum` reads its incoming arguments from.
+fn incoming(convention: Convention) -> (Vec<Reg>, Vec<i32>) {
+    let ast = parse("tests/translate/args_01.tig");
+    let (unit, _) = check::check_with(ast, Label::from_fixed("main"), convention)
+        .expect("Expected valid program");
+
+    let sum = canonize(unit).functions.into_iter()
+        .find(|function| function.label.to_string().starts_with("sum"))
+        .expect("Expected function sum");
+
+    let mut regs = Vec::new();
+    let mut stack = Vec::new();
+    for stm in &sum.body {
+        match stm {
+        | Stm::Move(Exp::Temp(Temp::Reg(reg)), _) if convention.arguments().contains(reg) => regs.push(*reg),
+        | Stm::Move(Exp::Mem(box Exp::Binop(box Exp::Temp(Temp::Reg(Reg::RBP)), Binop::Add, box Exp::Const(offset))), _) => {
+            stack.push(*offset)
+        },
+        | _ => (),
+        }
+    }
+
+    (regs, stack)
+}
+
+#[test]
+pub fn test_convention_incoming() {
+    let system_v = incoming(Convention::SystemV);
+    let microsoft = incoming(Convention::Microsoft);
+
+    // Seventh argument sits past the saved RBP and return address
+    assert_eq!(system_v, (vec![Reg::RDI, Reg::RSI, Reg::RDX, Reg::RCX, Reg::R8, Reg::R9], vec![16]));
+
+    // Fifth through seventh also skip the caller's shadow space
+    assert_eq!(microsoft, (vec![Reg::RCX, Reg::RDX, Reg::R8, Reg::R9], vec![48, 56, 64]));
+}
+
+#[test]
+pub fn test_entry_02() {
+    let entry = Label::from_fixed("tiger_main");
+    let compiler = Compiler::with_path("tests/translate/exit_01.tig").with_entry("tiger_main");
+    let unit = lower_with(compiler, vec![Exit::new(false), Canonize::new(false)]);
+
+    assert_eq!(unit.functions[0].label, entry);
+
+    // Exit call lands in the renamed entry point
+    assert_eq!(callees(&unit).last(), Some(&("__exit__".to_string(), 1)));
+}
+
+fn bounds(path: &str) -> Unit {
+    lower(path, vec![Bounds::new(false), Canonize::new(false)])
+}
+
+/// Comparisons of an index against an array's length, loaded from the word before it.
+fn length_checks(unit: &Unit) -> usize {
+    unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter(|stm| match stm {
+            | Stm::CJump(_, Relop::Ge, Exp::Mem(box Exp::Binop(_, Binop::Sub, box Exp::Const(8))), _, _) => true,
+            | _                                                                                         => false,
+        })
+        .count()
+}
+
+#[test]
+pub fn test_bounds_01() {
+    let unit = bounds("tests/translate/bounds_01.tig");
+    assert_eq!(length_checks(&unit), 0);
+    assert!(callees(&unit).iter().all(|(name, _)| name != "__bounds__"));
+}
+
+#[test]
+pub fn test_bounds_02() {
+    let unit = bounds("tests/translate/bounds_02.tig");
+
+    // Failure is unconditional, without comparing against the length
+    assert_eq!(length_checks(&unit), 0);
+    assert!(callees(&unit).contains(&("__bounds__".to_string(), 0)));
+    assert!(unit.functions.iter().all(|function| function.body.iter().all(|stm| match stm {
+        | Stm::CJump(_, _, _, _, _) => false,
+        | _                         => true,
+    })));
+}
+
+#[test]
+pub fn test_bounds_03() {
+    let unit = bounds("tests/translate/bounds_03.tig");
+    assert_eq!(length_checks(&unit), 1);
+    assert!(callees(&unit).contains(&("__bounds__".to_string(), 0)));
+
+    // Outside checked mode, arrays are indexed directly
+    assert!(callees(&translate("tests/translate/bounds_03.tig")).iter().all(|(name, _)| name != "__bounds__"));
+}
+
+#[test]
+pub fn test_concat_01() {
+    let unit = translate("tests/translate/concat_01.tig");
+
+    let data = unit.data.iter()
+        .map(|datum| datum.data.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(data, vec!["foobar", "baz"]);
+
+    // Only the concatenation with a variable is left for the runtime
+    let concats = callees(&unit).into_iter()
+        .filter(|(name, _)| name == "__concat__")
+        .count();
+
+    assert_eq!(concats, 1);
+}
+
+#[test]
+pub fn test_layout_01() {
+    let unit = translate("tests/translate/layout_01.tig");
+    let f = unit.functions.iter()
+        .find(|function| function.label.to_string().starts_with("f_"))
+        .expect("Expected function f");
+
+    let a = f.layout.offset(store("a")).expect("Expected a to escape");
+    let b = f.layout.offset(store("b")).expect("Expected b to escape");
+
+    // Static link is the first argument, and always escapes
+    assert_eq!(f.layout.offset(store("STATIC_LINK")), Some(-8));
+    assert_eq!(f.layout.offset(store("n")), None);
+    assert!(a != b && a < -8 && b < -8);
+    assert_eq!(f.layout.size, 24);
+
+    let dump = layout(&unit);
+    assert!(dump.contains(&format!("{}:\n", f.label)));
+    assert!(dump.contains(&format!("a           {}(%rbp)", a)));
+    assert!(dump.contains("n           temp"));
+}
+
+/// Arguments to every call to `printi`, in order.
+fn printed(unit: &Unit) -> Vec<Exp> {
+    calls_to(unit, "__printi__").into_iter()
+        .filter_map(|mut args| args.pop())
+        .collect()
+}
+
+#[test]
+pub fn test_fold_div_01() {
+    let printed = printed(&optimize("tests/translate/fold_div_01.tig"));
+
+    // f is declared, and so translated, before the body of main
+    match printed.as_slice() {
+    | [Exp::Binop(box Exp::Temp(_), Binop::Div, box Exp::Const(3)), Exp::Const(3), Exp::Const(1), Exp::Const(-3)] => (),
+    | _ => panic!("Expected only constant division to fold, found {:?}", printed),
+    }
+}
+
+#[test]
+pub fn test_fold_div_02() {
+    let printed = printed(&optimize("tests/translate/fold_div_02.tig"));
+
+    match printed.as_slice() {
+    | [Exp::Binop(box Exp::Const(::std::i32::MIN), Binop::Div, box Exp::Const(-1))] => (),
+    | _ => panic!("Expected overflowing division to be left unfolded, found {:?}", printed),
+    }
+}
+
+#[test]
+pub fn test_fold_overflow_01() {
+    let printed = printed(&optimize("tests/translate/fold_overflow_01.tig"));
+
+    match printed.as_slice() {
+    | [Exp::Binop(box Exp::Const(::std::i32::MAX), Binop::Add, box Exp::Const(1))] => (),
+    | _ => panic!("Expected overflowing addition to be left unfolded, found {:?}", printed),
+    }
+}
+
+#[test]
+pub fn test_zeroed_01() {
+    let unit = lower("tests/translate/zeroed_01.tig", vec![Zeroed::new(false), Canonize::new(false)]);
+    assert!(calls_to(&unit, "malloc").is_empty());
+
+    match calls_to(&unit, "calloc").as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Const(1), Exp::Const(24)] => (),
+        | _                               => panic!("Expected a single zeroed record"),
+    },
+    | _ => panic!("Expected a single allocation"),
+    }
+
+    // Only the string field still needs an explicit store
+    let stores = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter(|stm| match stm {
+            | Stm::Move(_, Exp::Mem(box Exp::Binop(box Exp::Temp(_), Binop::Add, box Exp::Const(_)))) => true,
+            | _ => false,
+        })
+        .count();
+
+    assert_eq!(stores, 1);
+}
+
+#[test]
+pub fn test_gc_01() {
+    let unit = lower("tests/translate/gc_01.tig", vec![Collect::new(false), Canonize::new(false)]);
+
+    // Only the record is a root, and it's kept on the stack
+    let main = &unit.functions[0];
+    let offset = match main.layout.roots.as_slice() {
+    | [Root::Frame(offset)] => *offset,
+    | _                     => panic!("Expected a single root on the stack"),
+    };
+
+    assert!(calls_to(&unit, "malloc").is_empty());
+    assert_eq!(calls_to(&unit, "__alloc__").len(), 1);
+
+    match calls_to(&unit, "__gc_push__").as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Binop(box Exp::Temp(Temp::Reg(Reg::RBP)), Binop::Sub, box Exp::Const(n))] => assert_eq!(-n, offset),
+        | _ => panic!("Expected root address"),
+    },
+    | _ => panic!("Expected a single registered root"),
+    }
+
+    match calls_to(&unit, "__gc_pop__").as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Const(1)] => (),
+        | _               => panic!("Expected a single unregistered root"),
+    },
+    | _ => panic!("Expected roots unregistered once"),
+    }
+}
+
+#[test]
+pub fn test_snapshot_fact() {
+    let source = "let function fact(n: int): int = if n = 0 then 1 else n * fact(n - 1) in fact(5) end";
+    let expected = r"fact_0
+    (MOVE (TEMP TEMP_RDI) (MEM (BINOP (TEMP TEMP_RBP) SUB (CONST 8))))
+    (MOVE (TEMP TEMP_RSI) (TEMP TEMP_ARG_0))
+    (MOVE (MEM (BINOP (TEMP TEMP_RBP) SUB (CONST 8))) (TEMP TEMP_LINK_0))
+    (CJUMP (TEMP TEMP_ARG_0) EQ (CONST 0) TRUE_BRANCH_0 FALSE_BRANCH_0)
+    (LABEL TRUE_BRANCH_0)
+    (MOVE (CONST 1) (TEMP TEMP_IF_ELSE_RESULT_0))
+    (JUMP (NAME EXIT_IF_ELSE_0))
+    (LABEL FALSE_BRANCH_0)
+    (MOVE (CALL (NAME fact_0) (TEMP TEMP_LINK_0) (BINOP (TEMP TEMP_ARG_0) SUB (CONST 1))) (TEMP TEMP_CANONIZE_CALL_0))
+    (MOVE (BINOP (TEMP TEMP_ARG_0) MUL (TEMP TEMP_CANONIZE_CALL_0)) (TEMP TEMP_IF_ELSE_RESULT_0))
+    (JUMP (NAME EXIT_IF_ELSE_0))
+    (LABEL EXIT_IF_ELSE_0)
+    (MOVE (TEMP TEMP_IF_ELSE_RESULT_0) (TEMP TEMP_RAX))
+
+main
+    (LOC 74..81)
+    (MOVE (CALL (NAME fact_0) (TEMP TEMP_RBP) (CONST 5)) (TEMP TEMP_CANONIZE_CALL_1))
+    (MOVE (TEMP TEMP_CANONIZE_CALL_1) (TEMP TEMP_RAX))";
+
+    assert_eq!(snapshot(source), expected);
+}
+
+/// Offset from the record pointer of a field read.
+fn field_offset(exp: &Exp) -> Option<i32> {
+    match exp {
+    | Exp::Mem(box Exp::Binop(box Exp::Temp(_), Binop::Add, box Exp::Const(offset))) => Some(*offset),
+    | _ => None,
+    }
+}
+
+#[test]
+pub fn test_rec_layout_01() {
+    let offsets = printed(&translate("tests/translate/rec_layout_01.tig"))
+        .iter()
+        .map(field_offset)
+        .collect::<Vec<_>>();
+
+    // Offsets follow each record's declaration order, not its identity
+    assert_eq!(offsets, vec![Some(8), Some(8), Some(0)]);
+}
+
+#[test]
+pub fn test_clear_01() {
+    let unit = lower("tests/translate/clear_01.tig", vec![Clear::new(false), Canonize::new(false)]);
+    let args = calls_to(&unit, "memset");
+
+    // The five nil fields after the key are cleared together
+    match args.as_slice() {
+    | [args] => match args.as_slice() {
+        | [Exp::Binop(box Exp::Temp(_), Binop::Add, box Exp::Const(8)), Exp::Const(0), Exp::Const(40)] => (),
+        | _ => panic!("Expected memset of five fields, found {:?}", args),
+    },
+    | _ => panic!("Expected a single memset"),
+    }
+
+    let stores = unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter(|stm| match stm {
+            | Stm::Move(_, Exp::Mem(_)) => true,
+            | _                         => false,
+        })
+        .count();
+
+    assert_eq!(stores, 1);
+}
+
+#[test]
+pub fn test_switch_01() {
+    let unit = lower("tests/translate/switch_01.tig", vec![
+        Canonize::new(false),
+        Reorder::new(false),
+        Switch::new(false),
+        Validate::new(false),
+    ]);
+
+    let body = &unit.functions[0].body;
+
+    // One jump through a table with a branch for each of 0 through 3
+    let tables = body.iter()
+        .filter_map(|stm| match stm {
+            | Stm::Jump(Exp::Mem(_), targets) => Some(targets.len()),
+            | _                               => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(tables, vec![4]);
+    assert!(!body.iter().any(|stm| match stm {
+        | Stm::CJump(_, Relop::Eq, _, _, _) => true,
+        | _                                 => false,
+    }));
+
+    // Both bounds checks fall through to their own false labels
+    let bounds = body.windows(2)
+        .filter(|pair| match pair {
+            | [Stm::CJump(_, Relop::Lt, _, _, f), Stm::Label(label)]
+            | [Stm::CJump(_, Relop::Gt, _, _, f), Stm::Label(label)] => f == label,
+            | _                                                     => false,
+        })
+        .count();
+
+    assert_eq!(bounds, 2);
+}
+
+#[test]
+pub fn test_share_01() {
+    let unit = lower("tests/translate/share_01.tig", vec![
+        Canonize::new(false),
+        Propagate::new(false),
+        Share::new(false),
+        Validate::new(false),
+    ]);
+
+    let body = &unit.functions[0].body;
+
+    // The address of a.y is computed once, then read and written through
+    let addresses = body.iter()
+        .filter(|stm| match stm {
+            | Stm::Move(Exp::Binop(_, Binop::Add, box Exp::Const(8)), Exp::Temp(_)) => true,
+            | _                                                                      => false,
+        })
+        .count();
+
+    let offsets = body.iter()
+        .map(|stm| stm.to_string().matches("(CONST 8)").count())
+        .sum::<usize>();
+
+    assert_eq!(addresses, 1);
+    assert_eq!(offsets, 1);
+}
+
+/// Allocation results compared against null, in order.
+fn null_checks(unit: &Unit) -> Vec<Temp> {
+    unit.functions.iter()
+        .flat_map(|function| function.body.iter())
+        .filter_map(|stm| match stm {
+            | Stm::CJump(Exp::Temp(temp), Relop::Ne, Exp::Const(0), _, _) => Some(*temp),
+            | _                                                           => None,
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_nonnull_01() {
+    let unit = lower("tests/translate/alloc_01.tig", vec![NonNull::new(false), Canonize::new(false)]);
+    let aborts = calls_to(&unit, "__oom__").len();
+
+    // The record and the array are each checked once, right after allocation
+    assert_eq!(null_checks(&unit).len(), 2);
+    assert_eq!(aborts, 2);
+
+    let body = &unit.functions[0].body;
+    for temp in null_checks(&unit) {
+        let check = body.iter()
+            .position(|stm| match stm {
+                | Stm::CJump(Exp::Temp(checked), _, _, _, _) => *checked == temp,
+                | _                                          => false,
+            })
+            .unwrap();
+
+        match &body[check - 1] {
+        | Stm::Move(Exp::Temp(_), Exp::Temp(dst)) => assert_eq!(*dst, temp),
+        | _ => panic!("Expected check right after allocation"),
+        }
+    }
+
+    // Nothing extra outside checked mode
+    let unit = translate("tests/translate/alloc_01.tig");
+    assert!(null_checks(&unit).is_empty());
+    assert!(calls_to(&unit, "__oom__").is_empty());
+}
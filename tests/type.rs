@@ -1,14 +1,79 @@
 #[macro_use]
 mod util;
 
+extern crate simple_symbol;
+extern crate tigerc;
+
 use std::fs::remove_file;
+use std::iter;
+
+use simple_symbol::store;
+
+use tigerc::check::Checker;
+use tigerc::check::context::TypeContext;
+use tigerc::config::CONVENTION;
+use tigerc::operand::Label;
+use tigerc::phase::*;
+use tigerc::span::Span;
+use tigerc::ty::Ty;
 
 use util::*;
 
+fn check(path: &str) -> (Compiler, Result<Item, tigerc::error::Error>) {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Type::new(false));
+
+    let result = compiler.run();
+    (compiler, result)
+}
+
+/// Source text under `span`.
+fn text(compiler: &Compiler, span: Span) -> String {
+    compiler.code().find_file(span.start()).unwrap().src_slice(span).unwrap().to_string()
+}
+
+/// Primary span of the type error in `path`, then its secondary spans, with their text.
+fn spans(path: &str) -> Vec<(Span, String)> {
+    let (compiler, result) = check(path);
+    let err = match result {
+    | Err(err) => err,
+    | _        => panic!("Expected type error in {}", path),
+    };
+
+    iter::once(err.span)
+        .chain(err.secondary)
+        .map(|span| (span, text(&compiler, span)))
+        .collect()
+}
+
+/// Text and message of each warning in `path`, which must type check.
+fn warnings(path: &str) -> Vec<(String, String)> {
+    let (compiler, result) = check(path);
+    assert!(result.is_ok());
+
+    compiler.warnings()
+        .into_iter()
+        .map(|warning| (text(&compiler, warning.span), (&warning.kind).into()))
+        .collect()
+}
+
+fn strict(path: &str) -> Result<Item, tigerc::error::Error> {
+    Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false))
+        .with_phase(Overflow::new(false))
+        .with_phase(Type::new(false))
+        .run()
+}
+
 generate!(good, "type", "-t", "typedsol", "typed", compare_content);
 
 generate!(bad, "type", "-t", "typedsol", "typed", compare_location);
 
+generate!(message, "type", "-t", "typedsol", "typed", compare_content);
+
 good!(test_queens, "queens");
 good!(test_merge, "merge");
 
@@ -62,3 +127,229 @@ bad!(test_appel_39, "appel_39");
 bad!(test_appel_40, "appel_40");
 bad!(test_appel_43, "appel_43");
 bad!(test_appel_45, "appel_45");
+
+message!(test_unbound_type_01, "unbound_type_01");
+message!(test_unbound_type_02, "unbound_type_02");
+message!(test_size_01, "size_01");
+good!(test_size_02, "size_02");
+good!(test_alias_index_01, "alias_index_01");
+good!(test_matrix_01, "matrix_01");
+good!(test_rec_arr_01, "rec_arr_01");
+good!(test_alias_rec_01, "alias_rec_01");
+message!(test_alias_rec_02, "alias_rec_02");
+
+good!(test_mutual_rec_01, "mutual_rec_01");
+message!(test_mutual_rec_02, "mutual_rec_02");
+
+#[test]
+pub fn test_unresolved_type() {
+    let mut tc = TypeContext::default();
+    let name = store("pending");
+
+    // Declared, but its body hasn't been filled in yet
+    tc.insert(name, Ty::Name(name, None));
+    assert!(tc.get_partial(&Span::default(), &name).is_ok());
+
+    match tc.get_full(&Span::default(), &name) {
+    | Err(err) => {
+        let message: String = (&err.kind).into();
+        assert_eq!(message, "Type 'pending' is used before its definition is known.");
+    },
+    | Ok(ty) => panic!("Expected unresolved type, found {}", ty),
+    }
+
+    tc.insert(name, Ty::Name(name, Some(Box::new(Ty::Int))));
+    assert_eq!(tc.get_full(&Span::default(), &name).unwrap(), Ty::Int);
+}
+
+good!(test_alias_arr_01, "alias_arr_01");
+
+message!(test_self_init_01, "self_init_01");
+message!(test_self_init_02, "self_init_02");
+message!(test_self_init_03, "self_init_03");
+good!(test_self_init_04, "self_init_04");
+
+message!(test_dup_fun_01, "dup_fun_01");
+
+#[test]
+pub fn test_dup_fun_secondary() {
+    let spans = spans("tests/type/dup_fun_01.tig");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[0].1, "f");
+    assert_eq!(spans[1].1, "f");
+    assert!(spans[1].0.start() < spans[0].0.start());
+}
+
+message!(test_dup_type_01, "dup_type_01");
+message!(test_dup_field_01, "dup_field_01");
+
+message!(test_ret_01, "ret_01");
+message!(test_ret_02, "ret_02");
+
+#[test]
+pub fn test_ret_span() {
+    let ret = spans("tests/type/ret_01.tig");
+    assert_eq!(ret.len(), 2);
+    assert_eq!(ret[0].1, "concat(\"a\", \"b\")");
+    assert_eq!(ret[1].1, "int");
+
+    // Procedures have no annotation to point at
+    assert_eq!(spans("tests/type/ret_02.tig").len(), 1);
+}
+
+message!(test_div_zero_01, "div_zero_01");
+good!(test_div_zero_02, "div_zero_02");
+message!(test_div_zero_03, "div_zero_03");
+message!(test_div_zero_04, "div_zero_04");
+
+good!(test_namespace_01, "namespace_01");
+good!(test_namespace_02, "namespace_02");
+message!(test_namespace_03, "namespace_03");
+
+message!(test_call_arity_01, "call_arity_01");
+message!(test_call_type_01, "call_type_01");
+
+message!(test_not_rec_01, "not_rec_01");
+message!(test_not_rec_02, "not_rec_02");
+message!(test_not_rec_03, "not_rec_03");
+message!(test_not_rec_04, "not_rec_04");
+
+good!(test_rec_eq_01, "rec_eq_01");
+message!(test_rec_eq_02, "rec_eq_02");
+good!(test_rec_eq_03, "rec_eq_03");
+
+message!(test_nil_cmp_01, "nil_cmp_01");
+message!(test_nil_cmp_02, "nil_cmp_02");
+
+good!(test_never_01, "never_01");
+good!(test_never_02, "never_02");
+message!(test_never_03, "never_03");
+message!(test_never_04, "never_04");
+
+good!(test_seq_01, "seq_01");
+message!(test_seq_02, "seq_02");
+
+#[test]
+pub fn test_discard_01() {
+    assert_eq!(warnings("tests/type/discard_01.tig"), vec![
+        ("1 + 2".to_string(), "Value of expression is discarded.".to_string()),
+    ]);
+}
+
+good!(test_constant_cmp_01, "constant_cmp_01");
+
+#[test]
+pub fn test_constant_cmp() {
+    // Calls may have effects, so f() = f() isn't flagged
+    assert_eq!(warnings("tests/type/constant_cmp_01.tig"), vec![
+        ("5 < 3".to_string(), "Comparison is always false.".to_string()),
+        ("x = x".to_string(), "Comparison is always true.".to_string()),
+        ("a[x - 1] <> a[x - 1]".to_string(), "Comparison is always false.".to_string()),
+    ]);
+}
+
+good!(test_unused_01, "unused_01");
+
+#[test]
+pub fn test_unused_fun() {
+    // Calls from unreachable functions, including themselves, don't count
+    assert_eq!(warnings("tests/type/unused_01.tig"), vec![
+        ("unused".to_string(), "Function 'unused' is never called.".to_string()),
+        ("recursive".to_string(), "Function 'recursive' is never called.".to_string()),
+    ]);
+}
+
+#[test]
+pub fn test_unread_var() {
+    // Reading sum to update it still counts as a read
+    assert_eq!(warnings("tests/type/unread_01.tig"), vec![
+        ("last".to_string(), "Variable 'last' is assigned but never read.".to_string()),
+    ]);
+
+    assert_eq!(warnings("tests/type/unread_02.tig"), vec![
+        ("i".to_string(), "Variable 'i' is assigned but never read.".to_string()),
+    ]);
+}
+
+message!(test_dec_01, "dec_01");
+
+#[test]
+pub fn test_dec_span() {
+    let dec = spans("tests/type/dec_01.tig");
+    assert_eq!(dec.len(), 2);
+    assert_eq!(dec[0].1, "\"hello\"");
+    assert_eq!(dec[1].1, "int");
+}
+
+message!(test_bin_mismatch_01, "bin_mismatch_01");
+message!(test_bin_mismatch_02, "bin_mismatch_02");
+
+good!(test_if_unit_01, "if_unit_01");
+good!(test_if_nil_01, "if_nil_01");
+
+message!(test_arr_size_01, "arr_size_01");
+good!(test_arr_size_02, "arr_size_02");
+
+message!(test_arr_size_03, "arr_size_03");
+
+#[test]
+pub fn test_overflow_01() {
+    match strict("tests/type/overflow_01.tig") {
+    | Err(err) => {
+        let message: String = (&err.kind).into();
+        assert_eq!(message, "Constant arithmetic overflows a 32-bit integer.");
+    },
+    | _ => panic!("Expected constant overflow"),
+    }
+
+    // Only rejected in strict mode
+    assert!(check("tests/type/overflow_01.tig").1.is_ok());
+}
+
+#[test]
+pub fn test_overflow_02() {
+    assert!(strict("tests/type/overflow_02.tig").is_ok());
+}
+
+#[test]
+pub fn test_overflow_not() {
+    assert!(strict("tests/type/overflow_03.tig").is_err());
+
+    // Shadowing not makes its result unknown
+    assert!(strict("tests/type/overflow_04.tig").is_ok());
+}
+
+good!(test_break_01, "break_01");
+
+message!(test_field_01, "field_01");
+message!(test_field_02, "field_02");
+
+message!(test_assign_guard_01, "assign_guard_01");
+message!(test_assign_guard_02, "assign_guard_02");
+
+message!(test_for_bound_01, "for_bound_01");
+message!(test_for_bound_02, "for_bound_02");
+
+message!(test_break_fun_01, "break_fun_01");
+message!(test_break_fun_02, "break_fun_02");
+good!(test_break_fun_03, "break_fun_03");
+
+good!(test_empty_rec_01, "empty_rec_01");
+
+fn eval(path: &str) -> Option<i32> {
+    let mut compiler = Compiler::with_path(path)
+        .with_phase(Lex::new(false))
+        .with_phase(Parse::new(false));
+
+    match compiler.run() {
+    | Ok(Item::Syntax(ast)) => Checker::new(Label::from_fixed("main"), CONVENTION).eval_const(&ast),
+    | _                     => panic!("Failed to parse {}", path),
+    }
+}
+
+#[test]
+pub fn test_eval_const() {
+    assert_eq!(eval("tests/type/eval_01.tig"), Some(14));
+    assert_eq!(eval("tests/type/eval_02.tig"), Some(1));
+    assert_eq!(eval("tests/type/eval_03.tig"), None);
+}
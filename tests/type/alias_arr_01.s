@@ -0,0 +1,55 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_10 in -8(%rbp)
+    # STORE_R12_11 in -16(%rbp)
+    # STORE_R13_12 in -24(%rbp)
+    # STORE_R14_13 in -32(%rbp)
+    # STORE_R15_14 in -40(%rbp)
+    # CANONIZE_CALL_3 in -48(%rbp)
+    # LOCAL_1 in -56(%rbp)
+    # TILE_UNARY_RESULT_7 in -64(%rbp)
+    # TILE_BINOP_RESULT_9 in -72(%rbp)
+    # CANONIZE_CALL_2 in -80(%rbp)
+    # body
+    movq $3, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq 8(%r10), %r11
+    movq %r11, -56(%rbp)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -64(%rbp)
+    incq %r10
+    movq %r10, -64(%rbp)
+    movq %r10, %r10
+    movq -48(%rbp), %r11
+    movq %r10, (%r11)
+    movq -48(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -72(%rbp)
+    movq -48(%rbp), %r10
+    movq -72(%rbp), %r11
+    addq 16(%r10), %r11
+    movq %r11, %r10
+    movq %r11, -72(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,42 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_8 in -8(%rbp)
+    # STORE_R12_9 in -16(%rbp)
+    # STORE_R13_10 in -24(%rbp)
+    # STORE_R14_11 in -32(%rbp)
+    # STORE_R15_12 in -40(%rbp)
+    # CANONIZE_CALL_1 in -48(%rbp)
+    # TILE_MEM_5 in -56(%rbp)
+    # TILE_MEM_6 in -64(%rbp)
+    # TILE_MEM_7 in -72(%rbp)
+    # body
+    movq $2, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq 8(%r10), %r11
+    movq %r11, -56(%rbp)
+    movq %r11, %r10
+    movq 8(%r10), %r11
+    movq %r11, -64(%rbp)
+    movq %r11, %r10
+    movq (%r10), %r11
+    movq %r11, -72(%rbp)
+    movq %r11, %r10
+    movq (%r10), %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,58 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_13 in -8(%rbp)
+    # STORE_R12_14 in -16(%rbp)
+    # STORE_R13_15 in -24(%rbp)
+    # STORE_R14_16 in -32(%rbp)
+    # STORE_R15_17 in -40(%rbp)
+    # CANONIZE_CALL_4 in -48(%rbp)
+    # CANONIZE_CALL_5 in -56(%rbp)
+    # TILE_BINOP_RESULT_11 in -64(%rbp)
+    # SHARED_6 in -72(%rbp)
+    # TILE_BINOP_RESULT_12 in -80(%rbp)
+    # body
+    movq $16, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq $1, (%r10)
+    movq -48(%rbp), %r10
+    movq $2, 8(%r10)
+    movq $16, %rdi
+    call malloc
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq $3, (%r10)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -64(%rbp)
+    addq $8, %r10
+    movq %r10, -64(%rbp)
+    movq %r10, %r10
+    movq %r10, -72(%rbp)
+    movq -72(%rbp), %r10
+    movq $4, (%r10)
+    movq -56(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -80(%rbp)
+    movq -72(%rbp), %r10
+    movq -80(%rbp), %r11
+    addq (%r10), %r11
+    movq %r11, %r10
+    movq %r11, -80(%rbp)
+    movq %r10, %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
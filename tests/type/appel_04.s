@@ -0,0 +1,86 @@
+
+
+    .globl nfactor_0
+    .align 4
+    nfactor_0:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $112, %rsp
+    # STORE_RBX_8 in -16(%rbp)
+    # STORE_R12_9 in -24(%rbp)
+    # STORE_R13_10 in -32(%rbp)
+    # STORE_R14_11 in -40(%rbp)
+    # STORE_R15_12 in -48(%rbp)
+    # ARG_0 in -56(%rbp)
+    # LINK_1 in -64(%rbp)
+    # TILE_UNARY_RESULT_6 in -72(%rbp)
+    # TILE_CALL_5 in -80(%rbp)
+    # CANONIZE_CALL_3 in -88(%rbp)
+    # TILE_DIV_MUL_RESULT_7 in -96(%rbp)
+    # IF_ELSE_RESULT_2 in -104(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    je TRUE_BRANCH_1
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -72(%rbp)
+    decq %r10
+    movq %r10, -72(%rbp)
+    movq -64(%rbp), %rdi
+    movq -72(%rbp), %rsi
+    call nfactor_0
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %r11
+    movq %r11, -88(%rbp)
+    movq -56(%rbp), %rax
+    movq -88(%rbp), %r10
+    imulq %r10
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r10
+    movq %r10, %r11
+    movq %r11, -104(%rbp)
+    jmp EXIT_IF_ELSE_2
+    TRUE_BRANCH_1:
+    movq $1, %r10
+    movq %r10, -104(%rbp)
+    EXIT_IF_ELSE_2:
+    movq -104(%rbp), %rax
+    # epilogue
+    addq $112, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_15 in -8(%rbp)
+    # STORE_R12_16 in -16(%rbp)
+    # STORE_R13_17 in -24(%rbp)
+    # STORE_R14_18 in -32(%rbp)
+    # STORE_R15_19 in -40(%rbp)
+    # CANONIZE_CALL_4 in -48(%rbp)
+    # body
+    movq %rbp, %rdi
+    movq $10, %rsi
+    call nfactor_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
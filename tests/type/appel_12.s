@@ -0,0 +1,54 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $88, %rsp
+    # STORE_RBX_5 in -8(%rbp)
+    # STORE_R12_6 in -16(%rbp)
+    # STORE_R13_7 in -24(%rbp)
+    # STORE_R14_8 in -32(%rbp)
+    # STORE_R15_9 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # LOCAL_1 in -56(%rbp)
+    # FOR_HI_2 in -64(%rbp)
+    # TILE_UNARY_RESULT_3 in -72(%rbp)
+    # TILE_UNARY_RESULT_4 in -80(%rbp)
+    # body
+    movq $0, 32(%rsp)
+    movq $0, 24(%rsp)
+    movq $100, 16(%rsp)
+    movq 16(%rsp), %r10
+    movq 24(%rsp), %r11
+    cmpq %r10, %r11
+    jg EXIT_FOR_0
+    START_FOR_1:
+    movq 32(%rsp), %r11
+    movq %r11, %r10
+    movq %r11, 8(%rsp)
+    incq %r10
+    movq %r10, 8(%rsp)
+    movq %r10, %r10
+    movq %r10, 32(%rsp)
+    movq 16(%rsp), %r10
+    movq 24(%rsp), %r11
+    cmpq %r10, %r11
+    jge EXIT_FOR_0
+    movq 24(%rsp), %r11
+    movq %r11, %r10
+    movq %r11, 0(%rsp)
+    incq %r10
+    movq %r10, 0(%rsp)
+    movq %r10, %r10
+    movq %r10, %r11
+    movq %r11, 24(%rsp)
+    jmp START_FOR_1
+    EXIT_FOR_0:
+    xorq %rax, %rax
+    # epilogue
+    addq $88, %rsp
+    ret
+
+
+
@@ -0,0 +1,46 @@
+
+
+    .globl g_0
+    .align 4
+    g_0:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_3 in -16(%rbp)
+    # STORE_R12_4 in -24(%rbp)
+    # STORE_R13_5 in -32(%rbp)
+    # STORE_R14_6 in -40(%rbp)
+    # STORE_R15_7 in -48(%rbp)
+    # body
+    movq %rdi, 40(%rsp)
+    movq %rsi, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_10 in -8(%rbp)
+    # STORE_R12_11 in -16(%rbp)
+    # STORE_R13_12 in -24(%rbp)
+    # STORE_R14_13 in -32(%rbp)
+    # STORE_R15_14 in -40(%rbp)
+    # CANONIZE_CALL_2 in -48(%rbp)
+    # body
+    movq %rbp, %rdi
+    movq $2, %rsi
+    call g_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
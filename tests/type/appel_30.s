@@ -0,0 +1,30 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_5 in -8(%rbp)
+    # STORE_R12_6 in -16(%rbp)
+    # STORE_R13_7 in -24(%rbp)
+    # STORE_R14_8 in -32(%rbp)
+    # STORE_R15_9 in -40(%rbp)
+    # CANONIZE_CALL_1 in -48(%rbp)
+    # body
+    movq $10, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq 16(%r10), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,163 @@
+.local STRING_0
+.align 8
+    .quad 5
+STRING_0:
+    .string "aname"
+.local STRING_1
+.align 8
+    .quad 9
+STRING_1:
+    .string "somewhere"
+.local STRING_2
+.align 8
+    .quad 0
+STRING_2:
+    .string ""
+.local STRING_3
+.align 8
+    .quad 7
+STRING_3:
+    .string "Kapoios"
+.local STRING_4
+.align 8
+    .quad 5
+STRING_4:
+    .string "Kapou"
+.local STRING_5
+.align 8
+    .quad 5
+STRING_5:
+    .string "Allos"
+.local STRING_6
+.align 8
+    .quad 4
+STRING_6:
+    .string "kati"
+.local STRING_7
+.align 8
+    .quad 3
+STRING_7:
+    .string "sfd"
+.local STRING_8
+.align 8
+    .quad 3
+STRING_8:
+    .string "sdf"
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $144, %rsp
+    # STORE_RBX_38 in -8(%rbp)
+    # STORE_R12_39 in -16(%rbp)
+    # STORE_R13_40 in -24(%rbp)
+    # STORE_R14_41 in -32(%rbp)
+    # STORE_R15_42 in -40(%rbp)
+    # CANONIZE_CALL_8 in -48(%rbp)
+    # CANONIZE_CALL_9 in -56(%rbp)
+    # CANONIZE_CALL_10 in -64(%rbp)
+    # CANONIZE_CALL_11 in -72(%rbp)
+    # CANONIZE_CALL_12 in -80(%rbp)
+    # CANONIZE_CALL_13 in -88(%rbp)
+    # CANONIZE_CALL_14 in -96(%rbp)
+    # TILE_BINOP_RESULT_33 in -104(%rbp)
+    # SHARED_15 in -112(%rbp)
+    # TILE_MEM_34 in -120(%rbp)
+    # TILE_MEM_35 in -128(%rbp)
+    # TILE_MEM_36 in -136(%rbp)
+    # TILE_MEM_37 in -144(%rbp)
+    # body
+    movq $10, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -48(%rbp)
+    movq $32, %rdi
+    call malloc
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq $STRING_0, (%r10)
+    movq -56(%rbp), %r10
+    movq $STRING_1, 8(%r10)
+    movq -56(%rbp), %r10
+    movq $0, 16(%r10)
+    movq -56(%rbp), %r10
+    movq $0, 24(%r10)
+    movq $5, %rdi
+    movq -56(%rbp), %rsi
+    call init_array
+    movq %rax, -64(%rbp)
+    movq $100, %rdi
+    movq $STRING_2, %rsi
+    call init_array
+    movq %rax, -72(%rbp)
+    movq $32, %rdi
+    call malloc
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %r10
+    movq $STRING_3, (%r10)
+    movq -80(%rbp), %r10
+    movq $STRING_4, 8(%r10)
+    movq -80(%rbp), %r10
+    movq $2432, 16(%r10)
+    movq -80(%rbp), %r10
+    movq $44, 24(%r10)
+    movq $16, %rdi
+    call malloc
+    movq %rax, -88(%rbp)
+    movq -88(%rbp), %r10
+    movq $STRING_5, (%r10)
+    movq $3, %rdi
+    movq $1900, %rsi
+    call init_array
+    movq %rax, -96(%rbp)
+    movq -88(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -104(%rbp)
+    addq $8, %r10
+    movq %r10, -104(%rbp)
+    movq %r10, %r10
+    movq %r10, -112(%rbp)
+    movq -96(%rbp), %r10
+    movq -112(%rbp), %r11
+    movq %r10, (%r11)
+    movq -48(%rbp), %r10
+    movq $1, (%r10)
+    movq -48(%rbp), %r10
+    movq $3, 72(%r10)
+    movq -64(%rbp), %r10
+    movq 24(%r10), %r11
+    movq %r11, -120(%rbp)
+    movq %r11, %r10
+    movq $STRING_6, (%r10)
+    movq -64(%rbp), %r10
+    movq 8(%r10), %r11
+    movq %r11, -128(%rbp)
+    movq %r11, %r10
+    movq $23, 24(%r10)
+    movq -72(%rbp), %r10
+    movq $STRING_7, 272(%r10)
+    movq -80(%rbp), %r10
+    movq $STRING_8, (%r10)
+    movq -112(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -136(%rbp)
+    movq %r11, %r10
+    movq $2323, (%r10)
+    movq -112(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -144(%rbp)
+    movq %r11, %r10
+    movq $2323, 16(%r10)
+    xorq %rax, %rax
+    # epilogue
+    addq $144, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
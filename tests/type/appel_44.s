@@ -0,0 +1,20 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_1 in -8(%rbp)
+    # STORE_R12_2 in -16(%rbp)
+    # STORE_R13_3 in -24(%rbp)
+    # STORE_R14_4 in -32(%rbp)
+    # STORE_R15_5 in -40(%rbp)
+    # body
+    xorq %rax, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+
+
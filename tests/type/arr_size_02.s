@@ -0,0 +1,32 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_5 in -8(%rbp)
+    # STORE_R12_6 in -16(%rbp)
+    # STORE_R13_7 in -24(%rbp)
+    # STORE_R14_8 in -32(%rbp)
+    # STORE_R15_9 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # CANONIZE_CALL_2 in -56(%rbp)
+    # body
+    movq $3, -48(%rbp)
+    movq -48(%rbp), %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq (%r10), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
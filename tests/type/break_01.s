@@ -0,0 +1,51 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_4 in -8(%rbp)
+    # STORE_R12_5 in -16(%rbp)
+    # STORE_R13_6 in -24(%rbp)
+    # STORE_R14_7 in -32(%rbp)
+    # STORE_R15_8 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # TILE_UNARY_RESULT_2 in -56(%rbp)
+    # CANONIZE_CALL_1 in -64(%rbp)
+    # body
+    xorq %r10, %r10
+    movq %r10, -48(%rbp)
+    START_WHILE_6:
+    movq -48(%rbp), %r10
+    cmpq $10, %r10
+    jge EXIT_WHILE_0
+    movq -48(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -56(%rbp)
+    incq %r10
+    movq %r10, -56(%rbp)
+    movq %r10, %r10
+    movq %r10, %r11
+    movq %r11, -48(%rbp)
+    movq -48(%rbp), %r10
+    cmpq $5, %r10
+    jle EXIT_IF_ELSE_5
+    jmp EXIT_WHILE_0
+    EXIT_IF_ELSE_5:
+    jmp START_WHILE_6
+    EXIT_WHILE_0:
+    movq -48(%rbp), %rdi
+    call __printi__
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,199 @@
+
+
+    .globl f_0
+    .align 4
+    f_0:
+    # prologue
+    subq $88, %rsp
+    # STORE_RBX_22 in -16(%rbp)
+    # STORE_R12_23 in -24(%rbp)
+    # STORE_R13_24 in -32(%rbp)
+    # STORE_R14_25 in -40(%rbp)
+    # STORE_R15_26 in -48(%rbp)
+    # LINK_1 in -56(%rbp)
+    # TILE_BINOP_RESULT_20 in -64(%rbp)
+    # SHARED_16 in -72(%rbp)
+    # TILE_UNARY_RESULT_21 in -80(%rbp)
+    # body
+    movq %rdi, 72(%rsp)
+    movq %rdi, 24(%rsp)
+    movq 24(%rsp), %r11
+    movq %r11, %r10
+    movq %r11, 16(%rsp)
+    subq $8, %r10
+    movq %r10, 16(%rsp)
+    movq %r10, %r10
+    movq %r10, 8(%rsp)
+    movq 8(%rsp), %r10
+    movq (%r10), %r11
+    movq %r11, %r10
+    movq %r11, 0(%rsp)
+    incq %r10
+    movq %r10, 0(%rsp)
+    movq %r10, %r10
+    movq 8(%rsp), %r11
+    movq %r10, (%r11)
+    movq 8(%rsp), %r10
+    movq (%r10), %rax
+    # epilogue
+    addq $88, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $288, %rsp
+    # STORE_RBX_41 in -16(%rbp)
+    # STORE_R12_42 in -24(%rbp)
+    # STORE_R13_43 in -32(%rbp)
+    # STORE_R14_44 in -40(%rbp)
+    # STORE_R15_45 in -48(%rbp)
+    # TILE_IMM_28 in -56(%rbp)
+    # TILE_IMM_29 in -64(%rbp)
+    # TILE_CALL_27 in -72(%rbp)
+    # CANONIZE_CALL_15 in -80(%rbp)
+    # LOCAL_0 in -88(%rbp)
+    # COND_EXP_2 in -96(%rbp)
+    # TILE_CALL_30 in -104(%rbp)
+    # CANONIZE_CALL_8 in -112(%rbp)
+    # COND_EXP_3 in -120(%rbp)
+    # SHARED_17 in -128(%rbp)
+    # TILE_CALL_31 in -136(%rbp)
+    # CANONIZE_CALL_9 in -144(%rbp)
+    # COND_EXP_4 in -152(%rbp)
+    # TILE_UNARY_RESULT_34 in -160(%rbp)
+    # TILE_SHIFT_RESULT_33 in -168(%rbp)
+    # TILE_BINOP_RESULT_32 in -176(%rbp)
+    # SHARED_18 in -184(%rbp)
+    # TILE_CALL_35 in -192(%rbp)
+    # CANONIZE_CALL_10 in -200(%rbp)
+    # COND_EXP_5 in -208(%rbp)
+    # TILE_CALL_36 in -216(%rbp)
+    # CANONIZE_CALL_11 in -224(%rbp)
+    # TILE_CALL_37 in -232(%rbp)
+    # CANONIZE_CALL_12 in -240(%rbp)
+    # TILE_CALL_38 in -248(%rbp)
+    # CANONIZE_CALL_14 in -256(%rbp)
+    # COND_EXP_6 in -264(%rbp)
+    # SHARED_19 in -272(%rbp)
+    # TILE_UNARY_RESULT_39 in -280(%rbp)
+    # CANONIZE_CALL_7 in -288(%rbp)
+    # body
+    movq $1, -8(%rbp)
+    movq $2, -56(%rbp)
+    movq -56(%rbp), %rdi
+    movq $0, -64(%rbp)
+    movq -64(%rbp), %rsi
+    call init_array
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq %r11, %r10
+    movq %r10, -88(%rbp)
+    movq $1, -96(%rbp)
+    movq $0, -96(%rbp)
+    movq -96(%rbp), %rdi
+    call __printi__
+    movq %rax, -104(%rbp)
+    movq -104(%rbp), %r11
+    movq %r11, -112(%rbp)
+    movq $1, -120(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -128(%rbp)
+    movq %r10, %r10
+    movq -128(%rbp), %r11
+    cmpq %r10, %r11
+    je TRUE_BRANCH_3
+    xorq %r10, %r10
+    movq %r10, -120(%rbp)
+    TRUE_BRANCH_3:
+    movq -120(%rbp), %rdi
+    call __printi__
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %r11
+    movq %r11, -144(%rbp)
+    movq $1, -152(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -160(%rbp)
+    movq %r10, %r10
+    decq %r10
+    movq %r10, -160(%rbp)
+    movq %r10, %r10
+    movq %r10, -168(%rbp)
+    movq -168(%rbp), %r10
+    shlq $3, %r10
+    movq %r10, -168(%rbp)
+    movq -88(%rbp), %r11
+    movq %r11, -176(%rbp)
+    movq -168(%rbp), %r10
+    movq -176(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -176(%rbp)
+    movq %r11, %r10
+    movq (%r10), %r11
+    movq %r11, -184(%rbp)
+    movq %r11, %r10
+    movq -184(%rbp), %r11
+    cmpq %r10, %r11
+    jne TRUE_BRANCH_5
+    xorq %r10, %r10
+    movq %r10, -152(%rbp)
+    TRUE_BRANCH_5:
+    movq -152(%rbp), %rdi
+    call __printi__
+    movq %rax, -192(%rbp)
+    movq -192(%rbp), %r11
+    movq %r11, -200(%rbp)
+    movq $1, -208(%rbp)
+    movq %rbp, %rdi
+    call f_0
+    movq %rax, -216(%rbp)
+    movq -216(%rbp), %r11
+    movq %r11, -224(%rbp)
+    movq %rbp, %rdi
+    call f_0
+    movq %rax, -232(%rbp)
+    movq -232(%rbp), %r11
+    movq %r11, -240(%rbp)
+    movq %r11, %r10
+    movq -224(%rbp), %r11
+    cmpq %r10, %r11
+    je TRUE_BRANCH_7
+    xorq %r10, %r10
+    movq %r10, -208(%rbp)
+    TRUE_BRANCH_7:
+    movq -208(%rbp), %rdi
+    call __printi__
+    movq %rax, -248(%rbp)
+    movq -248(%rbp), %r11
+    movq %r11, -256(%rbp)
+    movq $1, -264(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -272(%rbp)
+    movq %r10, %r10
+    movq %r10, -280(%rbp)
+    movq -280(%rbp), %r10
+    incq %r10
+    movq %r10, -280(%rbp)
+    movq %r10, %r10
+    movq -272(%rbp), %r11
+    cmpq %r10, %r11
+    jl TRUE_BRANCH_9
+    xorq %r10, %r10
+    movq %r10, -264(%rbp)
+    TRUE_BRANCH_9:
+    movq -264(%rbp), %rdi
+    call __printi__
+    movq %rax, -288(%rbp)
+    movq -288(%rbp), %rax
+    # epilogue
+    addq $288, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,47 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_13 in -8(%rbp)
+    # STORE_R12_14 in -16(%rbp)
+    # STORE_R13_15 in -24(%rbp)
+    # STORE_R14_16 in -32(%rbp)
+    # STORE_R15_17 in -40(%rbp)
+    # CANONIZE_CALL_6 in -48(%rbp)
+    # CANONIZE_CALL_7 in -56(%rbp)
+    # COND_EXP_4 in -64(%rbp)
+    # CANONIZE_CALL_5 in -72(%rbp)
+    # body
+    movq $8, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq $8, %rdi
+    call malloc
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq -48(%rbp), %r11
+    cmpq %r10, %r11
+    movq -64(%rbp), %r10
+    sete %r10b
+    movq %r10, -64(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -64(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,53 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $96, %rsp
+    # STORE_RBX_8 in -8(%rbp)
+    # STORE_R12_9 in -16(%rbp)
+    # STORE_R13_10 in -24(%rbp)
+    # STORE_R14_11 in -32(%rbp)
+    # STORE_R15_12 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # TILE_IMM_5 in -56(%rbp)
+    # TILE_IMM_7 in -64(%rbp)
+    # TILE_CALL_6 in -72(%rbp)
+    # CANONIZE_CALL_4 in -80(%rbp)
+    # IF_ELSE_RESULT_2 in -88(%rbp)
+    # body
+    movq $1, -48(%rbp)
+    movq $0, -56(%rbp)
+    movq -48(%rbp), %r10
+    movq -56(%rbp), %r11
+    cmpq %r10, %r11
+    jne TRUE_BRANCH_0
+    movq $8, -64(%rbp)
+    movq -64(%rbp), %rdi
+    call malloc
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq %r11, %r10
+    movq $1, (%r10)
+    movq -80(%rbp), %r10
+    movq %r10, %r11
+    movq %r11, -88(%rbp)
+    jmp EXIT_IF_ELSE_1
+    TRUE_BRANCH_0:
+    xorq %r10, %r10
+    movq %r10, -88(%rbp)
+    EXIT_IF_ELSE_1:
+    movq -88(%rbp), %r10
+    movq (%r10), %rax
+    # epilogue
+    addq $96, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,86 @@
+
+
+    .globl set_0
+    .align 4
+    set_0:
+    # prologue
+    subq $88, %rsp
+    # STORE_RBX_5 in -16(%rbp)
+    # STORE_R12_6 in -24(%rbp)
+    # STORE_R13_7 in -32(%rbp)
+    # STORE_R14_8 in -40(%rbp)
+    # STORE_R15_9 in -48(%rbp)
+    # ARG_0 in -56(%rbp)
+    # LINK_1 in -64(%rbp)
+    # TILE_IMM_4 in -72(%rbp)
+    # IF_ELSE_RESULT_2 in -80(%rbp)
+    # body
+    movq %rdi, 72(%rsp)
+    movq %rsi, 24(%rsp)
+    movq 72(%rsp), %r10
+    movq %r10, 16(%rsp)
+    movq $0, 8(%rsp)
+    movq 24(%rsp), %r10
+    movq 8(%rsp), %r11
+    cmpq %r10, %r11
+    jne TRUE_BRANCH_1
+    movq 16(%rsp), %r10
+    movq $2, -16(%r10)
+    jmp TAIL_6
+    TRUE_BRANCH_1:
+    movq 16(%rsp), %r10
+    movq $1, -8(%r10)
+    TAIL_6:
+    xorq %r10, %r10
+    movq %r10, 0(%rsp)
+    EXIT_IF_ELSE_2:
+    movq 0(%rsp), %rax
+    # epilogue
+    addq $88, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $96, %rsp
+    # STORE_RBX_14 in -24(%rbp)
+    # STORE_R12_15 in -32(%rbp)
+    # STORE_R13_16 in -40(%rbp)
+    # STORE_R14_17 in -48(%rbp)
+    # STORE_R15_18 in -56(%rbp)
+    # TILE_IMM_11 in -64(%rbp)
+    # TILE_CALL_10 in -72(%rbp)
+    # CANONIZE_CALL_3 in -80(%rbp)
+    # TILE_MEM_12 in -88(%rbp)
+    # TILE_IMM_13 in -96(%rbp)
+    # body
+    movq $0, -8(%rbp)
+    movq $0, -16(%rbp)
+    movq $1, -64(%rbp)
+    movq %rbp, %rdi
+    movq -64(%rbp), %rsi
+    call set_0
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -88(%rbp)
+    movq $0, -96(%rbp)
+    movq -88(%rbp), %r10
+    movq -96(%rbp), %r11
+    cmpq %r10, %r11
+    je EXIT_IF_ELSE_5
+    movq $3, -16(%rbp)
+    EXIT_IF_ELSE_5:
+    xorq %rax, %rax
+    # epilogue
+    addq $96, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,86 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $144, %rsp
+    # STORE_RBX_19 in -8(%rbp)
+    # STORE_R12_20 in -16(%rbp)
+    # STORE_R13_21 in -24(%rbp)
+    # STORE_R14_22 in -32(%rbp)
+    # STORE_R15_23 in -40(%rbp)
+    # CANONIZE_CALL_4 in -48(%rbp)
+    # CANONIZE_CALL_5 in -56(%rbp)
+    # LOCAL_1 in -64(%rbp)
+    # LOCAL_2 in -72(%rbp)
+    # TILE_SHIFT_RESULT_14 in -80(%rbp)
+    # TILE_BINOP_RESULT_13 in -88(%rbp)
+    # SHARED_6 in -96(%rbp)
+    # TILE_SHIFT_RESULT_15 in -104(%rbp)
+    # SHARED_7 in -112(%rbp)
+    # TILE_BINOP_RESULT_16 in -120(%rbp)
+    # TILE_BINOP_RESULT_18 in -128(%rbp)
+    # CANONIZE_CALL_3 in -136(%rbp)
+    # body
+    movq $3, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -48(%rbp)
+    movq $2, %rdi
+    movq -48(%rbp), %rsi
+    call init_array
+    movq %rax, -56(%rbp)
+    movq $1, -64(%rbp)
+    movq $2, -72(%rbp)
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -80(%rbp)
+    shlq $3, %r10
+    movq %r10, -80(%rbp)
+    movq -56(%rbp), %r11
+    movq %r11, -88(%rbp)
+    movq -80(%rbp), %r10
+    movq -88(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -88(%rbp)
+    movq %r11, %r10
+    movq %r10, -96(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -104(%rbp)
+    shlq $3, %r10
+    movq %r10, -104(%rbp)
+    movq %r10, %r10
+    movq %r10, -112(%rbp)
+    movq -96(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -120(%rbp)
+    movq -112(%rbp), %r10
+    movq -120(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -120(%rbp)
+    movq %r11, %r10
+    movq $5, (%r10)
+    movq -96(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -128(%rbp)
+    movq -112(%rbp), %r10
+    movq -128(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -128(%rbp)
+    movq %r11, %r10
+    movq (%r10), %rdi
+    call __printi__
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %rax
+    # epilogue
+    addq $144, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,792 @@
+.local STRING_3
+.align 8
+    .quad 1
+STRING_3:
+    .string "0"
+.local STRING_4
+.align 8
+    .quad 1
+STRING_4:
+    .string "9"
+.local STRING_8
+.align 8
+    .quad 1
+STRING_8:
+    .string " "
+.local STRING_9
+.align 8
+    .quad 1
+STRING_9:
+    .string "\n"
+.local STRING_36
+.align 8
+    .quad 1
+STRING_36:
+    .string "-"
+
+
+    .globl isdigit_1
+    .align 4
+    isdigit_1:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $160, %rsp
+    # STORE_RBX_80 in -16(%rbp)
+    # STORE_R12_81 in -24(%rbp)
+    # STORE_R13_82 in -32(%rbp)
+    # STORE_R14_83 in -40(%rbp)
+    # STORE_R15_84 in -48(%rbp)
+    # LINK_3 in -56(%rbp)
+    # LINK_4 in -64(%rbp)
+    # COND_EXP_5 in -72(%rbp)
+    # TILE_CALL_74 in -80(%rbp)
+    # CANONIZE_CALL_33 in -88(%rbp)
+    # TILE_IMM_76 in -96(%rbp)
+    # TILE_CALL_75 in -104(%rbp)
+    # CANONIZE_CALL_34 in -112(%rbp)
+    # TILE_CALL_77 in -120(%rbp)
+    # CANONIZE_CALL_36 in -128(%rbp)
+    # TILE_IMM_79 in -136(%rbp)
+    # TILE_CALL_78 in -144(%rbp)
+    # CANONIZE_CALL_37 in -152(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rdi, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq -8(%r10), %r11
+    movq %r11, -64(%rbp)
+    movq $1, -72(%rbp)
+    movq -64(%rbp), %r10
+    movq -8(%r10), %rdi
+    call __ord__
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %r11
+    movq %r11, -88(%rbp)
+    movq $STRING_3, -96(%rbp)
+    movq -96(%rbp), %rdi
+    call __ord__
+    movq %rax, -104(%rbp)
+    movq -104(%rbp), %r11
+    movq %r11, -112(%rbp)
+    movq %r11, %r10
+    movq -88(%rbp), %r11
+    cmpq %r10, %r11
+    jl FALSE_BRANCH_7
+    movq -64(%rbp), %r10
+    movq -8(%r10), %rdi
+    call __ord__
+    movq %rax, -120(%rbp)
+    movq -120(%rbp), %r11
+    movq %r11, -128(%rbp)
+    movq $STRING_4, -136(%rbp)
+    movq -136(%rbp), %rdi
+    call __ord__
+    movq %rax, -144(%rbp)
+    movq -144(%rbp), %r11
+    movq %r11, -152(%rbp)
+    movq %r11, %r10
+    movq -128(%rbp), %r11
+    cmpq %r10, %r11
+    jle TRUE_BRANCH_6
+    FALSE_BRANCH_7:
+    xorq %r10, %r10
+    movq %r10, -72(%rbp)
+    TRUE_BRANCH_6:
+    movq -72(%rbp), %rax
+    # epilogue
+    addq $160, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl skipto_2
+    .align 4
+    skipto_2:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $80, %rsp
+    # STORE_RBX_86 in -16(%rbp)
+    # STORE_R12_87 in -24(%rbp)
+    # STORE_R13_88 in -32(%rbp)
+    # STORE_R14_89 in -40(%rbp)
+    # STORE_R15_90 in -48(%rbp)
+    # LINK_6 in -56(%rbp)
+    # LINK_7 in -64(%rbp)
+    # TILE_CALL_85 in -72(%rbp)
+    # CANONIZE_CALL_39 in -80(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rdi, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq -8(%r10), %r11
+    movq %r11, -64(%rbp)
+    START_WHILE_12:
+    movq -64(%rbp), %r10
+    cmpq $STRING_8, -8(%r10)
+    je TRUE_BRANCH_13
+    movq -64(%rbp), %r10
+    cmpq $STRING_9, -8(%r10)
+    jne EXIT_WHILE_11
+    TRUE_BRANCH_13:
+    call __getchar__
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq %r11, %r10
+    movq -64(%rbp), %r11
+    movq %r10, -8(%r11)
+    jmp START_WHILE_12
+    EXIT_WHILE_11:
+    xorq %rax, %rax
+    # epilogue
+    addq $80, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl readint_0
+    .align 4
+    readint_0:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $240, %rsp
+    # STORE_RBX_104 in -16(%rbp)
+    # STORE_R12_105 in -24(%rbp)
+    # STORE_R13_106 in -32(%rbp)
+    # STORE_R14_107 in -40(%rbp)
+    # STORE_R15_108 in -48(%rbp)
+    # ARG_0 in -56(%rbp)
+    # LINK_8 in -64(%rbp)
+    # LOCAL_1 in -72(%rbp)
+    # TILE_CALL_91 in -80(%rbp)
+    # CANONIZE_CALL_40 in -88(%rbp)
+    # TILE_CALL_92 in -96(%rbp)
+    # CANONIZE_CALL_41 in -104(%rbp)
+    # TILE_CALL_93 in -112(%rbp)
+    # CANONIZE_CALL_42 in -120(%rbp)
+    # TILE_IMM_94 in -128(%rbp)
+    # TILE_BINOP_RESULT_95 in -136(%rbp)
+    # SHARED_72 in -144(%rbp)
+    # TILE_CALL_96 in -152(%rbp)
+    # CANONIZE_CALL_44 in -160(%rbp)
+    # TILE_IMM_98 in -168(%rbp)
+    # TILE_CALL_97 in -176(%rbp)
+    # CANONIZE_CALL_45 in -184(%rbp)
+    # TILE_IMM_102 in -192(%rbp)
+    # TILE_DIV_MUL_RESULT_101 in -200(%rbp)
+    # TILE_BINOP_RESULT_100 in -208(%rbp)
+    # TILE_BINOP_RESULT_99 in -216(%rbp)
+    # TILE_CALL_103 in -224(%rbp)
+    # CANONIZE_CALL_43 in -232(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq $0, -72(%rbp)
+    movq %rbp, %rdi
+    call skipto_2
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %r11
+    movq %r11, -88(%rbp)
+    movq -64(%rbp), %r10
+    movq %rbp, %rdi
+    movq -8(%r10), %rsi
+    call isdigit_1
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    movq %r11, %r10
+    movq -56(%rbp), %r11
+    movq %r10, (%r11)
+    START_WHILE_15:
+    movq -64(%rbp), %r10
+    movq %rbp, %rdi
+    movq -8(%r10), %rsi
+    call isdigit_1
+    movq %rax, -112(%rbp)
+    movq -112(%rbp), %r11
+    movq %r11, -120(%rbp)
+    movq $0, -128(%rbp)
+    movq -120(%rbp), %r10
+    movq -128(%rbp), %r11
+    cmpq %r10, %r11
+    je EXIT_WHILE_14
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -136(%rbp)
+    subq $8, %r10
+    movq %r10, -136(%rbp)
+    movq %r10, %r10
+    movq %r10, -144(%rbp)
+    movq -144(%rbp), %r10
+    movq (%r10), %rdi
+    call __ord__
+    movq %rax, -152(%rbp)
+    movq -152(%rbp), %r11
+    movq %r11, -160(%rbp)
+    movq $STRING_3, -168(%rbp)
+    movq -168(%rbp), %rdi
+    call __ord__
+    movq %rax, -176(%rbp)
+    movq -176(%rbp), %r11
+    movq %r11, -184(%rbp)
+    movq $10, -192(%rbp)
+    movq -72(%rbp), %rax
+    movq -192(%rbp), %r10
+    imulq %r10
+    movq %rax, -200(%rbp)
+    movq -200(%rbp), %r11
+    movq %r11, -208(%rbp)
+    movq -160(%rbp), %r10
+    movq -208(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -208(%rbp)
+    movq %r11, %r10
+    movq %r10, -216(%rbp)
+    movq -184(%rbp), %r10
+    movq -216(%rbp), %r11
+    subq %r10, %r11
+    movq %r11, -216(%rbp)
+    movq %r11, %r10
+    movq %r10, -72(%rbp)
+    call __getchar__
+    movq %rax, -224(%rbp)
+    movq -224(%rbp), %r11
+    movq %r11, -232(%rbp)
+    movq %r11, %r10
+    movq -144(%rbp), %r11
+    movq %r10, (%r11)
+    jmp START_WHILE_15
+    EXIT_WHILE_14:
+    movq -72(%rbp), %rax
+    # epilogue
+    addq $240, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl readlist_17
+    .align 4
+    readlist_17:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $176, %rsp
+    # STORE_RBX_117 in -16(%rbp)
+    # STORE_R12_118 in -24(%rbp)
+    # STORE_R13_119 in -32(%rbp)
+    # STORE_R14_120 in -40(%rbp)
+    # STORE_R15_121 in -48(%rbp)
+    # LINK_11 in -56(%rbp)
+    # TILE_IMM_110 in -64(%rbp)
+    # TILE_CALL_109 in -72(%rbp)
+    # CANONIZE_CALL_48 in -80(%rbp)
+    # TILE_CALL_111 in -88(%rbp)
+    # CANONIZE_CALL_49 in -96(%rbp)
+    # LOCAL_12 in -104(%rbp)
+    # TILE_MEM_112 in -112(%rbp)
+    # TILE_IMM_113 in -120(%rbp)
+    # TILE_IMM_115 in -128(%rbp)
+    # TILE_CALL_114 in -136(%rbp)
+    # CANONIZE_CALL_46 in -144(%rbp)
+    # TILE_CALL_116 in -152(%rbp)
+    # CANONIZE_CALL_47 in -160(%rbp)
+    # IF_ELSE_RESULT_14 in -168(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rdi, -56(%rbp)
+    movq $8, -64(%rbp)
+    movq -64(%rbp), %rdi
+    call malloc
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq %r11, %r10
+    movq $0, (%r10)
+    movq -56(%rbp), %rdi
+    movq -80(%rbp), %rsi
+    call readint_0
+    movq %rax, -88(%rbp)
+    movq -88(%rbp), %r11
+    movq %r11, -96(%rbp)
+    movq %r11, %r10
+    movq %r10, -104(%rbp)
+    movq -80(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -112(%rbp)
+    movq $0, -120(%rbp)
+    movq -112(%rbp), %r10
+    movq -120(%rbp), %r11
+    cmpq %r10, %r11
+    je FALSE_BRANCH_23
+    movq $16, -128(%rbp)
+    movq -128(%rbp), %rdi
+    call malloc
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %r11
+    movq %r11, -144(%rbp)
+    movq -104(%rbp), %r10
+    movq -144(%rbp), %r11
+    movq %r10, (%r11)
+    movq -56(%rbp), %rdi
+    call readlist_17
+    movq %rax, -152(%rbp)
+    movq -152(%rbp), %r11
+    movq %r11, -160(%rbp)
+    movq %r11, %r10
+    movq -144(%rbp), %r11
+    movq %r10, 8(%r11)
+    movq -144(%rbp), %r10
+    movq %r10, %r11
+    movq %r11, -168(%rbp)
+    jmp EXIT_IF_ELSE_22
+    FALSE_BRANCH_23:
+    xorq %r10, %r10
+    movq %r10, -168(%rbp)
+    EXIT_IF_ELSE_22:
+    movq -168(%rbp), %rax
+    # epilogue
+    addq $176, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl merge_18
+    .align 4
+    merge_18:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $208, %rsp
+    # STORE_RBX_131 in -16(%rbp)
+    # STORE_R12_132 in -24(%rbp)
+    # STORE_R13_133 in -32(%rbp)
+    # STORE_R14_134 in -40(%rbp)
+    # STORE_R15_135 in -48(%rbp)
+    # ARG_15 in -56(%rbp)
+    # ARG_16 in -64(%rbp)
+    # LINK_17 in -72(%rbp)
+    # TILE_MEM_122 in -80(%rbp)
+    # TILE_IMM_124 in -88(%rbp)
+    # TILE_CALL_123 in -96(%rbp)
+    # CANONIZE_CALL_52 in -104(%rbp)
+    # TILE_MEM_125 in -112(%rbp)
+    # TILE_CALL_126 in -120(%rbp)
+    # CANONIZE_CALL_53 in -128(%rbp)
+    # IF_ELSE_RESULT_20 in -136(%rbp)
+    # TILE_IMM_128 in -144(%rbp)
+    # TILE_CALL_127 in -152(%rbp)
+    # CANONIZE_CALL_50 in -160(%rbp)
+    # TILE_MEM_129 in -168(%rbp)
+    # TILE_CALL_130 in -176(%rbp)
+    # CANONIZE_CALL_51 in -184(%rbp)
+    # IF_ELSE_RESULT_21 in -192(%rbp)
+    # IF_ELSE_RESULT_22 in -200(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq %rdx, -64(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -72(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    je TRUE_BRANCH_30
+    movq -64(%rbp), %r10
+    cmpq $0, %r10
+    je TRUE_BRANCH_27
+    movq -64(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -80(%rbp)
+    movq %r11, %r10
+    movq -56(%rbp), %r11
+    cmpq %r10, (%r11)
+    jl TRUE_BRANCH_24
+    movq $16, -88(%rbp)
+    movq -88(%rbp), %rdi
+    call malloc
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    movq -64(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -112(%rbp)
+    movq %r11, %r10
+    movq -104(%rbp), %r11
+    movq %r10, (%r11)
+    movq -64(%rbp), %r10
+    movq -72(%rbp), %rdi
+    movq -56(%rbp), %rsi
+    movq 8(%r10), %rdx
+    call merge_18
+    movq %rax, -120(%rbp)
+    movq -120(%rbp), %r11
+    movq %r11, -128(%rbp)
+    movq %r11, %r10
+    movq -104(%rbp), %r11
+    movq %r10, 8(%r11)
+    movq -104(%rbp), %r10
+    TAIL_46:
+    movq %r10, %r11
+    movq %r11, -136(%rbp)
+    jmp EXIT_IF_ELSE_25
+    TRUE_BRANCH_24:
+    movq $16, -144(%rbp)
+    movq -144(%rbp), %rdi
+    call malloc
+    movq %rax, -152(%rbp)
+    movq -152(%rbp), %r11
+    movq %r11, -160(%rbp)
+    movq -56(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -168(%rbp)
+    movq %r11, %r10
+    movq -160(%rbp), %r11
+    movq %r10, (%r11)
+    movq -56(%rbp), %r10
+    movq -72(%rbp), %rdi
+    movq 8(%r10), %rsi
+    movq -64(%rbp), %rdx
+    call merge_18
+    movq %rax, -176(%rbp)
+    movq -176(%rbp), %r11
+    movq %r11, -184(%rbp)
+    movq %r11, %r10
+    movq -160(%rbp), %r11
+    movq %r10, 8(%r11)
+    movq -160(%rbp), %r10
+    jmp TAIL_46
+    TRUE_BRANCH_27:
+    movq -56(%rbp), %r10
+    TAIL_47:
+    movq %r10, %r11
+    movq %r11, -192(%rbp)
+    jmp EXIT_IF_ELSE_28
+    EXIT_IF_ELSE_25:
+    movq -136(%rbp), %r10
+    jmp TAIL_47
+    TRUE_BRANCH_30:
+    movq -64(%rbp), %r10
+    jmp TAIL_48
+    EXIT_IF_ELSE_28:
+    movq -192(%rbp), %r10
+    TAIL_48:
+    movq %r10, %r11
+    movq %r11, -200(%rbp)
+    EXIT_IF_ELSE_31:
+    movq -200(%rbp), %rax
+    # epilogue
+    addq $208, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl f_33
+    .align 4
+    f_33:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $192, %rsp
+    # STORE_RBX_147 in -16(%rbp)
+    # STORE_R12_148 in -24(%rbp)
+    # STORE_R13_149 in -32(%rbp)
+    # STORE_R14_150 in -40(%rbp)
+    # STORE_R15_151 in -48(%rbp)
+    # ARG_24 in -56(%rbp)
+    # LINK_25 in -64(%rbp)
+    # TILE_IMM_137 in -72(%rbp)
+    # TILE_DIV_MUL_RESULT_136 in -80(%rbp)
+    # SHARED_73 in -88(%rbp)
+    # TILE_CALL_138 in -96(%rbp)
+    # CANONIZE_CALL_57 in -104(%rbp)
+    # TILE_IMM_140 in -112(%rbp)
+    # TILE_CALL_139 in -120(%rbp)
+    # CANONIZE_CALL_54 in -128(%rbp)
+    # TILE_IMM_145 in -136(%rbp)
+    # TILE_DIV_MUL_RESULT_144 in -144(%rbp)
+    # TILE_BINOP_RESULT_143 in -152(%rbp)
+    # TILE_BINOP_RESULT_142 in -160(%rbp)
+    # TILE_CALL_141 in -168(%rbp)
+    # CANONIZE_CALL_55 in -176(%rbp)
+    # TILE_CALL_146 in -184(%rbp)
+    # CANONIZE_CALL_56 in -192(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    jle EXIT_IF_ELSE_35
+    movq $10, -72(%rbp)
+    movq -56(%rbp), %rax
+    cqo
+    movq -72(%rbp), %r10
+    idivq %r10
+    movq %rax, -80(%rbp)
+    movq -80(%rbp), %r11
+    movq %r11, -88(%rbp)
+    movq -64(%rbp), %rdi
+    movq -88(%rbp), %rsi
+    call f_33
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    movq $STRING_3, -112(%rbp)
+    movq -112(%rbp), %rdi
+    call __ord__
+    movq %rax, -120(%rbp)
+    movq -120(%rbp), %r11
+    movq %r11, -128(%rbp)
+    movq $10, -136(%rbp)
+    movq -88(%rbp), %rax
+    movq -136(%rbp), %r10
+    imulq %r10
+    movq %rax, -144(%rbp)
+    movq -56(%rbp), %r11
+    movq %r11, -152(%rbp)
+    movq -144(%rbp), %r10
+    movq -152(%rbp), %r11
+    subq %r10, %r11
+    movq %r11, -152(%rbp)
+    movq %r11, %r10
+    movq %r10, -160(%rbp)
+    movq -128(%rbp), %r10
+    movq -160(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, %r10
+    movq %r11, -160(%rbp)
+    movq %r10, %rdi
+    call __chr__
+    movq %rax, -168(%rbp)
+    movq -168(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -176(%rbp)
+    movq %r10, %rdi
+    call __prints__
+    movq %rax, -184(%rbp)
+    movq -184(%rbp), %r10
+    movq %r10, %r11
+    movq %r11, -192(%rbp)
+    EXIT_IF_ELSE_35:
+    xorq %rax, %rax
+    # epilogue
+    addq $192, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl printint_19
+    .align 4
+    printint_19:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $160, %rsp
+    # STORE_RBX_159 in -16(%rbp)
+    # STORE_R12_160 in -24(%rbp)
+    # STORE_R13_161 in -32(%rbp)
+    # STORE_R14_162 in -40(%rbp)
+    # STORE_R15_163 in -48(%rbp)
+    # ARG_23 in -56(%rbp)
+    # TILE_CALL_152 in -64(%rbp)
+    # CANONIZE_CALL_60 in -72(%rbp)
+    # IF_ELSE_RESULT_26 in -80(%rbp)
+    # TILE_IMM_154 in -88(%rbp)
+    # TILE_CALL_153 in -96(%rbp)
+    # CANONIZE_CALL_61 in -104(%rbp)
+    # IF_ELSE_RESULT_27 in -112(%rbp)
+    # TILE_IMM_156 in -120(%rbp)
+    # TILE_CALL_155 in -128(%rbp)
+    # CANONIZE_CALL_59 in -136(%rbp)
+    # TILE_UNARY_RESULT_158 in -144(%rbp)
+    # TILE_CALL_157 in -152(%rbp)
+    # CANONIZE_CALL_58 in -160(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    jl TRUE_BRANCH_40
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    jle FALSE_BRANCH_39
+    movq %rbp, %rdi
+    movq -56(%rbp), %rsi
+    call f_33
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %r11
+    movq %r11, -72(%rbp)
+    jmp TAIL_49
+    FALSE_BRANCH_39:
+    movq $STRING_3, -88(%rbp)
+    movq -88(%rbp), %rdi
+    call __prints__
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    TAIL_49:
+    movq %r11, %r10
+    movq %r10, %r11
+    movq %r11, -80(%rbp)
+    EXIT_IF_ELSE_38:
+    movq -80(%rbp), %r10
+    jmp TAIL_50
+    TRUE_BRANCH_40:
+    movq $STRING_36, -120(%rbp)
+    movq -120(%rbp), %rdi
+    call __prints__
+    movq %rax, -128(%rbp)
+    movq -128(%rbp), %r11
+    movq %r11, -136(%rbp)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -144(%rbp)
+    negq %r10
+    movq %r10, -144(%rbp)
+    movq %rbp, %rdi
+    movq -144(%rbp), %rsi
+    call f_33
+    movq %rax, -152(%rbp)
+    movq -152(%rbp), %r11
+    movq %r11, -160(%rbp)
+    movq %r11, %r10
+    TAIL_50:
+    movq %r10, %r11
+    movq %r11, -112(%rbp)
+    EXIT_IF_ELSE_41:
+    movq -112(%rbp), %rax
+    # epilogue
+    addq $160, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl printlist_20
+    .align 4
+    printlist_20:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $160, %rsp
+    # STORE_RBX_170 in -16(%rbp)
+    # STORE_R12_171 in -24(%rbp)
+    # STORE_R13_172 in -32(%rbp)
+    # STORE_R14_173 in -40(%rbp)
+    # STORE_R15_174 in -48(%rbp)
+    # ARG_28 in -56(%rbp)
+    # LINK_29 in -64(%rbp)
+    # TILE_CALL_164 in -72(%rbp)
+    # CANONIZE_CALL_64 in -80(%rbp)
+    # TILE_IMM_166 in -88(%rbp)
+    # TILE_CALL_165 in -96(%rbp)
+    # CANONIZE_CALL_65 in -104(%rbp)
+    # TILE_CALL_167 in -112(%rbp)
+    # CANONIZE_CALL_63 in -120(%rbp)
+    # IF_ELSE_RESULT_30 in -128(%rbp)
+    # TILE_IMM_169 in -136(%rbp)
+    # TILE_CALL_168 in -144(%rbp)
+    # CANONIZE_CALL_62 in -152(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    je TRUE_BRANCH_43
+    movq -56(%rbp), %r10
+    movq -64(%rbp), %rdi
+    movq (%r10), %rsi
+    call printint_19
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, -80(%rbp)
+    movq $STRING_8, -88(%rbp)
+    movq -88(%rbp), %rdi
+    call __prints__
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    movq -56(%rbp), %r10
+    movq -64(%rbp), %rdi
+    movq 8(%r10), %rsi
+    call printlist_20
+    movq %rax, -112(%rbp)
+    movq -112(%rbp), %r11
+    movq %r11, -120(%rbp)
+    jmp TAIL_51
+    TRUE_BRANCH_43:
+    movq $STRING_9, -136(%rbp)
+    movq -136(%rbp), %rdi
+    call __prints__
+    movq %rax, -144(%rbp)
+    movq -144(%rbp), %r11
+    movq %r11, -152(%rbp)
+    TAIL_51:
+    movq %r11, %r10
+    movq %r10, %r11
+    movq %r11, -128(%rbp)
+    EXIT_IF_ELSE_44:
+    movq -128(%rbp), %rax
+    # epilogue
+    addq $160, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $96, %rsp
+    # STORE_RBX_181 in -16(%rbp)
+    # STORE_R12_182 in -24(%rbp)
+    # STORE_R13_183 in -32(%rbp)
+    # STORE_R14_184 in -40(%rbp)
+    # STORE_R15_185 in -48(%rbp)
+    # CANONIZE_CALL_68 in -56(%rbp)
+    # CANONIZE_CALL_69 in -64(%rbp)
+    # CANONIZE_CALL_71 in -72(%rbp)
+    # CANONIZE_CALL_70 in -80(%rbp)
+    # CANONIZE_CALL_66 in -88(%rbp)
+    # CANONIZE_CALL_67 in -96(%rbp)
+    # body
+    call __getchar__
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %r10
+    movq %r10, -8(%rbp)
+    movq %rbp, %rdi
+    call readlist_17
+    movq %rax, -64(%rbp)
+    call __getchar__
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r10
+    movq %r10, -8(%rbp)
+    movq %rbp, %rdi
+    call readlist_17
+    movq %rax, -80(%rbp)
+    movq %rbp, %rdi
+    movq -64(%rbp), %rsi
+    movq -80(%rbp), %rdx
+    call merge_18
+    movq %rax, -88(%rbp)
+    movq %rbp, %rdi
+    movq -88(%rbp), %rsi
+    call printlist_20
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %rax
+    # epilogue
+    addq $96, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
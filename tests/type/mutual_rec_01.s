@@ -0,0 +1,93 @@
+.local STRING_0
+.align 8
+    .quad 1
+STRING_0:
+    .string "y"
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $144, %rsp
+    # STORE_RBX_22 in -8(%rbp)
+    # STORE_R12_23 in -16(%rbp)
+    # STORE_R13_24 in -24(%rbp)
+    # STORE_R14_25 in -32(%rbp)
+    # STORE_R15_26 in -40(%rbp)
+    # CANONIZE_CALL_6 in -48(%rbp)
+    # TILE_BINOP_RESULT_12 in -56(%rbp)
+    # SHARED_8 in -64(%rbp)
+    # CANONIZE_CALL_7 in -72(%rbp)
+    # TILE_BINOP_RESULT_15 in -80(%rbp)
+    # SHARED_9 in -88(%rbp)
+    # TILE_MEM_17 in -96(%rbp)
+    # TILE_MEM_18 in -104(%rbp)
+    # CANONIZE_CALL_5 in -112(%rbp)
+    # TILE_MEM_20 in -120(%rbp)
+    # TILE_MEM_21 in -128(%rbp)
+    # CANONIZE_CALL_4 in -136(%rbp)
+    # body
+    movq $16, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq $1, (%r10)
+    movq -48(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -56(%rbp)
+    addq $8, %r10
+    movq %r10, -56(%rbp)
+    movq %r10, %r10
+    movq %r10, -64(%rbp)
+    movq -64(%rbp), %r10
+    movq $0, (%r10)
+    movq $16, %rdi
+    call malloc
+    movq %rax, -72(%rbp)
+    movq -72(%rbp), %r10
+    movq $STRING_0, (%r10)
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -80(%rbp)
+    addq $8, %r10
+    movq %r10, -80(%rbp)
+    movq %r10, %r10
+    movq %r10, -88(%rbp)
+    movq -48(%rbp), %r10
+    movq -88(%rbp), %r11
+    movq %r10, (%r11)
+    movq -72(%rbp), %r10
+    movq -64(%rbp), %r11
+    movq %r10, (%r11)
+    movq -64(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -96(%rbp)
+    movq %r11, %r10
+    movq 8(%r10), %r11
+    movq %r11, -104(%rbp)
+    movq %r11, %r10
+    movq (%r10), %rdi
+    call __printi__
+    movq %rax, -112(%rbp)
+    movq -88(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -120(%rbp)
+    movq %r11, %r10
+    movq 8(%r10), %r11
+    movq %r11, -128(%rbp)
+    movq %r11, %r10
+    movq (%r10), %rdi
+    call __prints__
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %rax
+    # epilogue
+    addq $144, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
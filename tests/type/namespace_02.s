@@ -0,0 +1,53 @@
+.local STRING_1
+.align 8
+    .quad 1
+STRING_1:
+    .string "y"
+
+
+    .globl f_0
+    .align 4
+    f_0:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_4 in -16(%rbp)
+    # STORE_R12_5 in -24(%rbp)
+    # STORE_R13_6 in -32(%rbp)
+    # STORE_R14_7 in -40(%rbp)
+    # STORE_R15_8 in -48(%rbp)
+    # body
+    movq %rdi, 40(%rsp)
+    movq %rsi, %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_10 in -8(%rbp)
+    # STORE_R12_11 in -16(%rbp)
+    # STORE_R13_12 in -24(%rbp)
+    # STORE_R14_13 in -32(%rbp)
+    # STORE_R15_14 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # CANONIZE_CALL_3 in -56(%rbp)
+    # body
+    movq $5, -48(%rbp)
+    movq %rbp, %rdi
+    movq -48(%rbp), %rsi
+    call f_0
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
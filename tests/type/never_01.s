@@ -0,0 +1,47 @@
+
+
+    .globl spin_0
+    .align 4
+    spin_0:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_1 in -16(%rbp)
+    # STORE_R12_2 in -24(%rbp)
+    # STORE_R13_3 in -32(%rbp)
+    # STORE_R14_4 in -40(%rbp)
+    # STORE_R15_5 in -48(%rbp)
+    movq %r10, 0(%rsp)
+    # body
+    movq %rdi, 40(%rsp)
+    TRUE_BRANCH_3:
+    jmp TRUE_BRANCH_3
+    # epilogue
+    addq $56, %rsp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_7 in -8(%rbp)
+    # STORE_R12_8 in -16(%rbp)
+    # STORE_R13_9 in -24(%rbp)
+    # STORE_R14_10 in -32(%rbp)
+    # STORE_R15_11 in -40(%rbp)
+    # CANONIZE_CALL_0 in -48(%rbp)
+    # body
+    movq %rbp, %rdi
+    call spin_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
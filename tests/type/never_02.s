@@ -0,0 +1,92 @@
+.local STRING_1
+.align 8
+    .quad 6
+STRING_1:
+    .string "error\n"
+
+
+    .globl fail_0
+    .align 4
+    fail_0:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $112, %rsp
+    # STORE_RBX_12 in -16(%rbp)
+    # STORE_R12_13 in -24(%rbp)
+    # STORE_R13_14 in -32(%rbp)
+    # STORE_R14_15 in -40(%rbp)
+    # STORE_R15_16 in -48(%rbp)
+    # ARG_0 in -56(%rbp)
+    # TILE_CALL_7 in -64(%rbp)
+    # CANONIZE_CALL_4 in -72(%rbp)
+    # IF_ELSE_RESULT_1 in -80(%rbp)
+    # CANONIZE_CALL_3 in -88(%rbp)
+    # TILE_CALL_11 in -96(%rbp)
+    # CANONIZE_CALL_2 in -104(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    jg TRUE_BRANCH_2
+    xorq %rdi, %rdi
+    call __exit__
+    ud2
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %r11
+    movq %r11, -72(%rbp)
+    jmp TAIL_5
+    TRUE_BRANCH_2:
+    movq $STRING_1, %rdi
+    call __prints__
+    movq %rax, -88(%rbp)
+    movq -56(%rbp), %rdi
+    call __exit__
+    ud2
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r11
+    movq %r11, -104(%rbp)
+    TAIL_5:
+    movq %r11, %r10
+    movq %r10, %r11
+    movq %r11, -80(%rbp)
+    EXIT_IF_ELSE_3:
+    movq -80(%rbp), %rax
+    # epilogue
+    addq $112, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_20 in -8(%rbp)
+    # STORE_R12_21 in -16(%rbp)
+    # STORE_R13_22 in -24(%rbp)
+    # STORE_R14_23 in -32(%rbp)
+    # STORE_R15_24 in -40(%rbp)
+    # CANONIZE_CALL_5 in -48(%rbp)
+    # CANONIZE_CALL_6 in -56(%rbp)
+    # body
+    movq %rbp, %rdi
+    movq $1, %rsi
+    call fail_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rdi
+    call __prints__
+    movq %rax, -56(%rbp)
+    movq -56(%rbp), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
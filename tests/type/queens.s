@@ -0,0 +1,559 @@
+.local STRING_4
+.align 8
+    .quad 2
+STRING_4:
+    .string " O"
+.local STRING_5
+.align 8
+    .quad 2
+STRING_5:
+    .string " ."
+.local STRING_11
+.align 8
+    .quad 1
+STRING_11:
+    .string "\n"
+
+
+    .globl printboard_0
+    .align 4
+    printboard_0:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $192, %rsp
+    # STORE_RBX_40 in -16(%rbp)
+    # STORE_R12_41 in -24(%rbp)
+    # STORE_R13_42 in -32(%rbp)
+    # STORE_R14_43 in -40(%rbp)
+    # STORE_R15_44 in -48(%rbp)
+    # LINK_0 in -56(%rbp)
+    # LOCAL_1 in -64(%rbp)
+    # TILE_UNARY_RESULT_29 in -72(%rbp)
+    # FOR_HI_5 in -80(%rbp)
+    # LOCAL_2 in -88(%rbp)
+    # TILE_UNARY_RESULT_30 in -96(%rbp)
+    # FOR_HI_4 in -104(%rbp)
+    # TILE_SHIFT_RESULT_32 in -112(%rbp)
+    # TILE_BINOP_RESULT_31 in -120(%rbp)
+    # IF_ELSE_RESULT_3 in -128(%rbp)
+    # TILE_CALL_33 in -136(%rbp)
+    # CANONIZE_CALL_13 in -144(%rbp)
+    # TILE_IMM_35 in -152(%rbp)
+    # TILE_CALL_34 in -160(%rbp)
+    # CANONIZE_CALL_12 in -168(%rbp)
+    # TILE_UNARY_RESULT_36 in -176(%rbp)
+    # TILE_UNARY_RESULT_37 in -184(%rbp)
+    # CANONIZE_CALL_11 in -192(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rdi, -56(%rbp)
+    movq $0, -64(%rbp)
+    movq -56(%rbp), %r10
+    movq -8(%r10), %r11
+    movq %r11, %r10
+    movq %r11, -72(%rbp)
+    decq %r10
+    movq %r10, -72(%rbp)
+    movq %r10, %r10
+    movq %r10, -80(%rbp)
+    movq -80(%rbp), %r10
+    movq -64(%rbp), %r11
+    cmpq %r10, %r11
+    jg EXIT_FOR_2
+    START_FOR_12:
+    movq $0, -88(%rbp)
+    movq -56(%rbp), %r10
+    movq -8(%r10), %r11
+    movq %r11, %r10
+    movq %r11, -96(%rbp)
+    decq %r10
+    movq %r10, -96(%rbp)
+    movq %r10, %r10
+    movq %r10, -104(%rbp)
+    movq -104(%rbp), %r10
+    movq -88(%rbp), %r11
+    cmpq %r10, %r11
+    jg EXIT_FOR_3
+    START_FOR_9:
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -112(%rbp)
+    shlq $3, %r10
+    movq %r10, -112(%rbp)
+    movq -56(%rbp), %r10
+    movq -24(%r10), %r11
+    movq %r11, -120(%rbp)
+    movq -112(%rbp), %r10
+    movq -120(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -120(%rbp)
+    movq -88(%rbp), %r10
+    movq -120(%rbp), %r11
+    cmpq %r10, (%r11)
+    jne FALSE_BRANCH_8
+    movq $STRING_4, %r10
+    jmp TAIL_25
+    FALSE_BRANCH_8:
+    movq $STRING_5, %r10
+    TAIL_25:
+    movq %r10, -128(%rbp)
+    EXIT_IF_ELSE_7:
+    movq -128(%rbp), %rdi
+    call __prints__
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %r11
+    movq %r11, -144(%rbp)
+    movq -104(%rbp), %r10
+    movq -88(%rbp), %r11
+    cmpq %r10, %r11
+    jl TRUE_BRANCH_10
+    EXIT_FOR_3:
+    movq $STRING_11, -152(%rbp)
+    movq -152(%rbp), %rdi
+    call __prints__
+    movq %rax, -160(%rbp)
+    movq -160(%rbp), %r11
+    movq %r11, -168(%rbp)
+    movq -80(%rbp), %r10
+    movq -64(%rbp), %r11
+    cmpq %r10, %r11
+    jge EXIT_FOR_2
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -176(%rbp)
+    incq %r10
+    movq %r10, -176(%rbp)
+    movq %r10, %r10
+    movq %r10, %r11
+    movq %r11, -64(%rbp)
+    jmp START_FOR_12
+    TRUE_BRANCH_10:
+    movq -88(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -184(%rbp)
+    incq %r10
+    movq %r10, -184(%rbp)
+    movq %r10, %r10
+    movq %r10, %r11
+    movq %r11, -88(%rbp)
+    jmp START_FOR_9
+    EXIT_FOR_2:
+    movq $STRING_11, %rdi
+    call __prints__
+    movq %rax, -192(%rbp)
+    movq -192(%rbp), %rax
+    # epilogue
+    addq $192, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl try_1
+    .align 4
+    try_1:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $400, %rsp
+    # STORE_RBX_76 in -16(%rbp)
+    # STORE_R12_77 in -24(%rbp)
+    # STORE_R13_78 in -32(%rbp)
+    # STORE_R14_79 in -40(%rbp)
+    # STORE_R15_80 in -48(%rbp)
+    # ARG_6 in -56(%rbp)
+    # LINK_7 in -64(%rbp)
+    # LOCAL_8 in -72(%rbp)
+    # TILE_UNARY_RESULT_45 in -80(%rbp)
+    # FOR_HI_9 in -88(%rbp)
+    # TILE_SHIFT_RESULT_47 in -96(%rbp)
+    # TILE_BINOP_RESULT_46 in -104(%rbp)
+    # TILE_BINOP_RESULT_50 in -112(%rbp)
+    # TILE_SHIFT_RESULT_49 in -120(%rbp)
+    # TILE_BINOP_RESULT_48 in -128(%rbp)
+    # TILE_BINOP_RESULT_54 in -136(%rbp)
+    # TILE_BINOP_RESULT_53 in -144(%rbp)
+    # TILE_SHIFT_RESULT_52 in -152(%rbp)
+    # TILE_BINOP_RESULT_51 in -160(%rbp)
+    # TILE_BINOP_RESULT_55 in -168(%rbp)
+    # SHARED_21 in -176(%rbp)
+    # TILE_SHIFT_RESULT_56 in -184(%rbp)
+    # SHARED_22 in -192(%rbp)
+    # TILE_BINOP_RESULT_57 in -200(%rbp)
+    # TILE_BINOP_RESULT_59 in -208(%rbp)
+    # TILE_SHIFT_RESULT_58 in -216(%rbp)
+    # SHARED_23 in -224(%rbp)
+    # TILE_BINOP_RESULT_60 in -232(%rbp)
+    # SHARED_24 in -240(%rbp)
+    # TILE_BINOP_RESULT_61 in -248(%rbp)
+    # TILE_BINOP_RESULT_64 in -256(%rbp)
+    # TILE_BINOP_RESULT_63 in -264(%rbp)
+    # TILE_SHIFT_RESULT_62 in -272(%rbp)
+    # SHARED_25 in -280(%rbp)
+    # TILE_BINOP_RESULT_65 in -288(%rbp)
+    # SHARED_26 in -296(%rbp)
+    # TILE_BINOP_RESULT_66 in -304(%rbp)
+    # TILE_SHIFT_RESULT_68 in -312(%rbp)
+    # TILE_BINOP_RESULT_67 in -320(%rbp)
+    # TILE_UNARY_RESULT_70 in -328(%rbp)
+    # TILE_CALL_69 in -336(%rbp)
+    # CANONIZE_CALL_15 in -344(%rbp)
+    # TILE_BINOP_RESULT_71 in -352(%rbp)
+    # TILE_BINOP_RESULT_72 in -360(%rbp)
+    # TILE_BINOP_RESULT_73 in -368(%rbp)
+    # TILE_CALL_74 in -376(%rbp)
+    # CANONIZE_CALL_14 in -384(%rbp)
+    # IF_ELSE_RESULT_10 in -392(%rbp)
+    # TILE_UNARY_RESULT_75 in -400(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -64(%rbp)
+    movq -64(%rbp), %r10
+    movq -56(%rbp), %r11
+    cmpq -8(%r10), %r11
+    je TRUE_BRANCH_21
+    movq $0, -72(%rbp)
+    movq -64(%rbp), %r10
+    movq -8(%r10), %r11
+    movq %r11, %r10
+    movq %r11, -80(%rbp)
+    decq %r10
+    movq %r10, -80(%rbp)
+    movq %r10, %r10
+    movq %r10, -88(%rbp)
+    movq -88(%rbp), %r10
+    movq -72(%rbp), %r11
+    cmpq %r10, %r11
+    jg EXIT_FOR_14
+    START_FOR_19:
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -96(%rbp)
+    shlq $3, %r10
+    movq %r10, -96(%rbp)
+    movq -64(%rbp), %r10
+    movq -16(%r10), %r11
+    movq %r11, -104(%rbp)
+    movq -96(%rbp), %r10
+    movq -104(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -104(%rbp)
+    movq %r11, %r10
+    cmpq $0, (%r10)
+    jne EXIT_IF_ELSE_18
+    movq -72(%rbp), %r11
+    movq %r11, -112(%rbp)
+    movq -56(%rbp), %r10
+    movq -112(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -112(%rbp)
+    movq %r11, %r10
+    movq %r10, -120(%rbp)
+    movq -120(%rbp), %r10
+    shlq $3, %r10
+    movq %r10, -120(%rbp)
+    movq -64(%rbp), %r10
+    movq -32(%r10), %r11
+    movq %r11, -128(%rbp)
+    movq -120(%rbp), %r10
+    movq -128(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -128(%rbp)
+    movq %r11, %r10
+    cmpq $0, (%r10)
+    jne EXIT_IF_ELSE_18
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -136(%rbp)
+    addq $7, %r10
+    movq %r10, -136(%rbp)
+    movq %r10, %r10
+    movq %r10, -144(%rbp)
+    movq -56(%rbp), %r10
+    movq -144(%rbp), %r11
+    subq %r10, %r11
+    movq %r11, -144(%rbp)
+    movq %r11, %r10
+    movq %r10, -152(%rbp)
+    movq -152(%rbp), %r10
+    shlq $3, %r10
+    movq %r10, -152(%rbp)
+    movq -64(%rbp), %r10
+    movq -40(%r10), %r11
+    movq %r11, -160(%rbp)
+    movq -152(%rbp), %r10
+    movq -160(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -160(%rbp)
+    movq %r11, %r10
+    cmpq $0, (%r10)
+    jne EXIT_IF_ELSE_18
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -168(%rbp)
+    subq $16, %r10
+    movq %r10, -168(%rbp)
+    movq %r10, %r10
+    movq %r10, -176(%rbp)
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -184(%rbp)
+    shlq $3, %r10
+    movq %r10, -184(%rbp)
+    movq %r10, %r10
+    movq %r10, -192(%rbp)
+    movq -176(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -200(%rbp)
+    movq -192(%rbp), %r10
+    movq -200(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -200(%rbp)
+    movq %r11, %r10
+    movq $1, (%r10)
+    movq -72(%rbp), %r11
+    movq %r11, -208(%rbp)
+    movq -56(%rbp), %r10
+    movq -208(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -208(%rbp)
+    movq %r11, %r10
+    movq %r10, -216(%rbp)
+    movq -216(%rbp), %r10
+    shlq $3, %r10
+    movq %r10, -216(%rbp)
+    movq %r10, %r10
+    movq %r10, -224(%rbp)
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -232(%rbp)
+    subq $32, %r10
+    movq %r10, -232(%rbp)
+    movq %r10, %r10
+    movq %r10, -240(%rbp)
+    movq -240(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -248(%rbp)
+    movq -224(%rbp), %r10
+    movq -248(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -248(%rbp)
+    movq %r11, %r10
+    movq $1, (%r10)
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -256(%rbp)
+    addq $7, %r10
+    movq %r10, -256(%rbp)
+    movq %r10, %r10
+    movq %r10, -264(%rbp)
+    movq -56(%rbp), %r10
+    movq -264(%rbp), %r11
+    subq %r10, %r11
+    movq %r11, -264(%rbp)
+    movq %r11, %r10
+    movq %r10, -272(%rbp)
+    movq -272(%rbp), %r10
+    shlq $3, %r10
+    movq %r10, -272(%rbp)
+    movq %r10, %r10
+    movq %r10, -280(%rbp)
+    movq -64(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -288(%rbp)
+    subq $40, %r10
+    movq %r10, -288(%rbp)
+    movq %r10, %r10
+    movq %r10, -296(%rbp)
+    movq -296(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -304(%rbp)
+    movq -280(%rbp), %r10
+    movq -304(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -304(%rbp)
+    movq %r11, %r10
+    movq $1, (%r10)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -312(%rbp)
+    shlq $3, %r10
+    movq %r10, -312(%rbp)
+    movq -64(%rbp), %r10
+    movq -24(%r10), %r11
+    movq %r11, -320(%rbp)
+    movq -312(%rbp), %r10
+    movq -320(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -320(%rbp)
+    movq -72(%rbp), %r10
+    movq -320(%rbp), %r11
+    movq %r10, (%r11)
+    movq -56(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -328(%rbp)
+    incq %r10
+    movq %r10, -328(%rbp)
+    movq -64(%rbp), %rdi
+    movq -328(%rbp), %rsi
+    call try_1
+    movq %rax, -336(%rbp)
+    movq -336(%rbp), %r11
+    movq %r11, -344(%rbp)
+    movq -176(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -352(%rbp)
+    movq -192(%rbp), %r10
+    movq -352(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -352(%rbp)
+    movq %r11, %r10
+    movq $0, (%r10)
+    movq -240(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -360(%rbp)
+    movq -224(%rbp), %r10
+    movq -360(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -360(%rbp)
+    movq %r11, %r10
+    movq $0, (%r10)
+    movq -296(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -368(%rbp)
+    movq -280(%rbp), %r10
+    movq -368(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -368(%rbp)
+    movq %r11, %r10
+    movq $0, (%r10)
+    EXIT_IF_ELSE_18:
+    movq -88(%rbp), %r10
+    movq -72(%rbp), %r11
+    cmpq %r10, %r11
+    jge EXIT_FOR_14
+    jmp TRUE_BRANCH_20
+    TRUE_BRANCH_21:
+    movq -64(%rbp), %rdi
+    call printboard_0
+    movq %rax, -376(%rbp)
+    movq -376(%rbp), %r11
+    movq %r11, -384(%rbp)
+    movq %r11, %r10
+    movq %r10, %r11
+    movq %r11, -392(%rbp)
+    jmp EXIT_IF_ELSE_22
+    EXIT_FOR_14:
+    xorq %r10, %r10
+    movq %r10, -392(%rbp)
+    jmp EXIT_IF_ELSE_22
+    TRUE_BRANCH_20:
+    movq -72(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -400(%rbp)
+    incq %r10
+    movq %r10, -400(%rbp)
+    movq %r10, %r10
+    movq %r10, %r11
+    movq %r11, -72(%rbp)
+    jmp START_FOR_19
+    EXIT_IF_ELSE_22:
+    movq -392(%rbp), %rax
+    # epilogue
+    addq $400, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $176, %rsp
+    # STORE_RBX_95 in -48(%rbp)
+    # STORE_R12_96 in -56(%rbp)
+    # STORE_R13_97 in -64(%rbp)
+    # STORE_R14_98 in -72(%rbp)
+    # STORE_R15_99 in -80(%rbp)
+    # CANONIZE_CALL_17 in -88(%rbp)
+    # CANONIZE_CALL_18 in -96(%rbp)
+    # SHARED_27 in -104(%rbp)
+    # TILE_BINOP_RESULT_87 in -112(%rbp)
+    # TILE_UNARY_RESULT_86 in -120(%rbp)
+    # CANONIZE_CALL_19 in -128(%rbp)
+    # SHARED_28 in -136(%rbp)
+    # TILE_BINOP_RESULT_91 in -144(%rbp)
+    # TILE_UNARY_RESULT_90 in -152(%rbp)
+    # CANONIZE_CALL_20 in -160(%rbp)
+    # CANONIZE_CALL_16 in -168(%rbp)
+    # body
+    movq $8, -8(%rbp)
+    movq -8(%rbp), %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -88(%rbp)
+    movq -88(%rbp), %r10
+    movq %r10, -16(%rbp)
+    movq -8(%rbp), %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -96(%rbp)
+    movq -96(%rbp), %r10
+    movq %r10, -24(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -104(%rbp)
+    movq %r10, %r10
+    movq %r10, -112(%rbp)
+    movq -104(%rbp), %r10
+    movq -112(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -112(%rbp)
+    movq %r11, %r10
+    movq %r10, -120(%rbp)
+    movq -120(%rbp), %r10
+    decq %r10
+    movq %r10, -120(%rbp)
+    movq %r10, %r10
+    movq %r10, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -128(%rbp)
+    movq -128(%rbp), %r10
+    movq %r10, -32(%rbp)
+    movq -8(%rbp), %r10
+    movq %r10, -136(%rbp)
+    movq %r10, %r10
+    movq %r10, -144(%rbp)
+    movq -136(%rbp), %r10
+    movq -144(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -144(%rbp)
+    movq %r11, %r10
+    movq %r10, -152(%rbp)
+    movq -152(%rbp), %r10
+    decq %r10
+    movq %r10, -152(%rbp)
+    movq %r10, %r10
+    movq %r10, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -160(%rbp)
+    movq -160(%rbp), %r10
+    movq %r10, -40(%rbp)
+    movq %rbp, %rdi
+    xorq %rsi, %rsi
+    call try_1
+    movq %rax, -168(%rbp)
+    movq -168(%rbp), %rax
+    # epilogue
+    addq $176, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
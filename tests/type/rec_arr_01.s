@@ -0,0 +1,98 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $144, %rsp
+    # STORE_RBX_23 in -8(%rbp)
+    # STORE_R12_24 in -16(%rbp)
+    # STORE_R13_25 in -24(%rbp)
+    # STORE_R14_26 in -32(%rbp)
+    # STORE_R15_27 in -40(%rbp)
+    # CANONIZE_CALL_4 in -48(%rbp)
+    # CANONIZE_CALL_5 in -56(%rbp)
+    # TILE_BINOP_RESULT_14 in -64(%rbp)
+    # SHARED_7 in -72(%rbp)
+    # CANONIZE_CALL_6 in -80(%rbp)
+    # LOCAL_3 in -88(%rbp)
+    # TILE_SHIFT_RESULT_17 in -96(%rbp)
+    # SHARED_8 in -104(%rbp)
+    # TILE_MEM_19 in -112(%rbp)
+    # TILE_BINOP_RESULT_18 in -120(%rbp)
+    # TILE_BINOP_RESULT_21 in -128(%rbp)
+    # TILE_MEM_22 in -136(%rbp)
+    # TILE_BINOP_RESULT_20 in -144(%rbp)
+    # body
+    movq $16, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq $4, (%r10)
+    movq $4, %rdi
+    xorq %rsi, %rsi
+    call init_array
+    movq %rax, -56(%rbp)
+    movq -48(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -64(%rbp)
+    addq $8, %r10
+    movq %r10, -64(%rbp)
+    movq %r10, %r10
+    movq %r10, -72(%rbp)
+    movq -56(%rbp), %r10
+    movq -72(%rbp), %r11
+    movq %r10, (%r11)
+    movq $2, %rdi
+    movq -48(%rbp), %rsi
+    call init_array
+    movq %rax, -80(%rbp)
+    movq $3, -88(%rbp)
+    movq -88(%rbp), %r11
+    movq %r11, %r10
+    movq %r11, -96(%rbp)
+    shlq $3, %r10
+    movq %r10, -96(%rbp)
+    movq %r10, %r10
+    movq %r10, -104(%rbp)
+    movq -80(%rbp), %r10
+    movq 8(%r10), %r11
+    movq %r11, -112(%rbp)
+    movq %r11, %r10
+    movq 8(%r10), %r11
+    movq %r11, -120(%rbp)
+    movq -104(%rbp), %r10
+    movq -120(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -120(%rbp)
+    movq %r11, %r10
+    movq $7, (%r10)
+    movq -72(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -128(%rbp)
+    movq -104(%rbp), %r10
+    movq -128(%rbp), %r11
+    addq %r10, %r11
+    movq %r11, -128(%rbp)
+    movq -80(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -136(%rbp)
+    movq -128(%rbp), %r10
+    movq (%r10), %r11
+    movq %r11, -144(%rbp)
+    movq -136(%rbp), %r10
+    movq -144(%rbp), %r11
+    addq (%r10), %r11
+    movq %r11, %r10
+    movq %r11, -144(%rbp)
+    movq %r10, %rax
+    # epilogue
+    addq $144, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,76 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $112, %rsp
+    # STORE_RBX_16 in -8(%rbp)
+    # STORE_R12_17 in -16(%rbp)
+    # STORE_R13_18 in -24(%rbp)
+    # STORE_R14_19 in -32(%rbp)
+    # STORE_R15_20 in -40(%rbp)
+    # CANONIZE_CALL_8 in -48(%rbp)
+    # LOCAL_1 in -56(%rbp)
+    # CANONIZE_CALL_9 in -64(%rbp)
+    # LOCAL_3 in -72(%rbp)
+    # COND_EXP_4 in -80(%rbp)
+    # CANONIZE_CALL_7 in -88(%rbp)
+    # COND_EXP_5 in -96(%rbp)
+    # CANONIZE_CALL_6 in -104(%rbp)
+    # body
+    movq $16, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq $1, (%r10)
+    movq -48(%rbp), %r10
+    movq $2, 8(%r10)
+    movq -48(%rbp), %r11
+    movq %r11, -56(%rbp)
+    movq $16, %rdi
+    call malloc
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %r10
+    movq $1, (%r10)
+    movq -64(%rbp), %r10
+    movq $2, 8(%r10)
+    movq -64(%rbp), %r11
+    movq %r11, -72(%rbp)
+    movq -64(%rbp), %r10
+    movq -48(%rbp), %r11
+    cmpq %r10, %r11
+    movq -80(%rbp), %r10
+    sete %r10b
+    movq %r10, -80(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -80(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -88(%rbp)
+    movq -72(%rbp), %r10
+    movq -56(%rbp), %r11
+    cmpq %r10, %r11
+    movq -96(%rbp), %r10
+    setne %r10b
+    movq %r10, -96(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -96(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -104(%rbp)
+    movq -104(%rbp), %rax
+    # epilogue
+    addq $112, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,95 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $144, %rsp
+    # STORE_RBX_19 in -8(%rbp)
+    # STORE_R12_20 in -16(%rbp)
+    # STORE_R13_21 in -24(%rbp)
+    # STORE_R14_22 in -32(%rbp)
+    # STORE_R15_23 in -40(%rbp)
+    # CANONIZE_CALL_10 in -48(%rbp)
+    # LOCAL_1 in -56(%rbp)
+    # COND_EXP_2 in -64(%rbp)
+    # CANONIZE_CALL_7 in -72(%rbp)
+    # TILE_IMM_14 in -80(%rbp)
+    # COND_EXP_3 in -88(%rbp)
+    # CANONIZE_CALL_8 in -96(%rbp)
+    # COND_EXP_4 in -104(%rbp)
+    # CANONIZE_CALL_9 in -112(%rbp)
+    # TILE_IMM_17 in -120(%rbp)
+    # COND_EXP_5 in -128(%rbp)
+    # CANONIZE_CALL_6 in -136(%rbp)
+    # body
+    movq $8, %rdi
+    call malloc
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %r10
+    movq $1, (%r10)
+    movq -48(%rbp), %r11
+    movq %r11, -56(%rbp)
+    movq -48(%rbp), %r10
+    cmpq $0, %r10
+    movq -64(%rbp), %r10
+    sete %r10b
+    movq %r10, -64(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -64(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -72(%rbp)
+    movq $0, -80(%rbp)
+    movq -56(%rbp), %r10
+    movq -80(%rbp), %r11
+    cmpq %r10, %r11
+    movq -88(%rbp), %r10
+    sete %r10b
+    movq %r10, -88(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -88(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -96(%rbp)
+    movq -56(%rbp), %r10
+    cmpq $0, %r10
+    movq -104(%rbp), %r10
+    setne %r10b
+    movq %r10, -104(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -104(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -112(%rbp)
+    movq $0, -120(%rbp)
+    movq -56(%rbp), %r10
+    movq -120(%rbp), %r11
+    cmpq %r10, %r11
+    movq -128(%rbp), %r10
+    setne %r10b
+    movq %r10, -128(%rbp)
+    movq %r10, %r10
+    movzbq %r10b, %r11
+    movq %r11, %r10
+    movq %r11, -128(%rbp)
+    movq %r10, %rdi
+    call __printi__
+    movq %rax, -136(%rbp)
+    movq -136(%rbp), %rax
+    # epilogue
+    addq $144, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
@@ -0,0 +1,31 @@
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $72, %rsp
+    # STORE_RBX_3 in -8(%rbp)
+    # STORE_R12_4 in -16(%rbp)
+    # STORE_R13_5 in -24(%rbp)
+    # STORE_R14_6 in -32(%rbp)
+    # STORE_R15_7 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # TILE_UNARY_RESULT_2 in -56(%rbp)
+    # LOCAL_1 in -64(%rbp)
+    # body
+    movq $1, 16(%rsp)
+    movq 16(%rsp), %r11
+    movq %r11, %r10
+    movq %r11, 8(%rsp)
+    incq %r10
+    movq %r10, 8(%rsp)
+    movq %r10, %r10
+    movq %r10, 0(%rsp)
+    movq 0(%rsp), %rax
+    # epilogue
+    addq $72, %rsp
+    ret
+
+
+
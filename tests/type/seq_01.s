@@ -0,0 +1,27 @@
+.local STRING_0
+.align 8
+    .quad 3
+STRING_0:
+    .string "two"
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_1 in -8(%rbp)
+    # STORE_R12_2 in -16(%rbp)
+    # STORE_R13_3 in -24(%rbp)
+    # STORE_R14_4 in -32(%rbp)
+    # STORE_R15_5 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # body
+    movq $3, 0(%rsp)
+    movq 0(%rsp), %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+
+
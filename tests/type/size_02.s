@@ -0,0 +1,28 @@
+.local STRING_0
+.align 8
+    .quad 0
+STRING_0:
+    .string ""
+
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    subq $56, %rsp
+    # STORE_RBX_2 in -8(%rbp)
+    # STORE_R12_3 in -16(%rbp)
+    # STORE_R13_4 in -24(%rbp)
+    # STORE_R14_5 in -32(%rbp)
+    # STORE_R15_6 in -40(%rbp)
+    # LOCAL_0 in -48(%rbp)
+    # body
+    movq $STRING_0, 0(%rsp)
+    movq 0(%rsp), %r10
+    movq -8(%r10), %rax
+    # epilogue
+    addq $56, %rsp
+    ret
+
+
+
@@ -0,0 +1,108 @@
+
+
+    .globl used_0
+    .align 4
+    used_0:
+    # prologue
+    subq $72, %rsp
+    # STORE_RBX_9 in -16(%rbp)
+    # STORE_R12_10 in -24(%rbp)
+    # STORE_R13_11 in -32(%rbp)
+    # STORE_R14_12 in -40(%rbp)
+    # STORE_R15_13 in -48(%rbp)
+    # TILE_UNARY_RESULT_8 in -56(%rbp)
+    # body
+    movq %rdi, 56(%rsp)
+    movq %rsi, 8(%rsp)
+    movq 8(%rsp), %r10
+    incq %r10
+    movq %r10, 8(%rsp)
+    movq %r10, %r10
+    movq %r10, %rax
+    # epilogue
+    addq $72, %rsp
+    ret
+
+    .globl unused_1
+    .align 4
+    unused_1:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_15 in -16(%rbp)
+    # STORE_R12_16 in -24(%rbp)
+    # STORE_R13_17 in -32(%rbp)
+    # STORE_R14_18 in -40(%rbp)
+    # STORE_R15_19 in -48(%rbp)
+    # ARG_1 in -56(%rbp)
+    # CANONIZE_CALL_5 in -64(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %rdi
+    movq -56(%rbp), %rsi
+    call used_0
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl recursive_2
+    .align 4
+    recursive_2:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $64, %rsp
+    # STORE_RBX_21 in -16(%rbp)
+    # STORE_R12_22 in -24(%rbp)
+    # STORE_R13_23 in -32(%rbp)
+    # STORE_R14_24 in -40(%rbp)
+    # STORE_R15_25 in -48(%rbp)
+    # ARG_3 in -56(%rbp)
+    # CANONIZE_CALL_6 in -64(%rbp)
+    # body
+    movq %rdi, -8(%rbp)
+    movq %rsi, -56(%rbp)
+    movq -8(%rbp), %rdi
+    movq -56(%rbp), %rsi
+    call recursive_2
+    movq %rax, -64(%rbp)
+    movq -64(%rbp), %rax
+    # epilogue
+    addq $64, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+    .globl main
+    .align 4
+    main:
+    # prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $48, %rsp
+    # STORE_RBX_28 in -8(%rbp)
+    # STORE_R12_29 in -16(%rbp)
+    # STORE_R13_30 in -24(%rbp)
+    # STORE_R14_31 in -32(%rbp)
+    # STORE_R15_32 in -40(%rbp)
+    # CANONIZE_CALL_7 in -48(%rbp)
+    # body
+    movq %rbp, %rdi
+    movq $1, %rsi
+    call used_0
+    movq %rax, -48(%rbp)
+    movq -48(%rbp), %rax
+    # epilogue
+    addq $48, %rsp
+    movq %rbp, %rsp
+    popq %rbp
+    ret
+
+
+
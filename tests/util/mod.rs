@@ -28,7 +28,7 @@ macro_rules! generate {
                 pub fn $name() {
                     let Unit { file, solution, actual } = get_unit($file, $dir, $sol_ext, $act_ext);
                     run($flag, &file);
-                    $compare(solution, actual);
+                    assert!($compare(solution, actual));
                 }
             }
         }
@@ -0,0 +1,134 @@
+extern crate tigerc;
+
+use tigerc::error::{IRError, Kind};
+use tigerc::ir::*;
+use tigerc::operand::{Label, Temp};
+use tigerc::translate::{validate, Layout};
+
+fn unit(body: Vec<Stm>) -> Unit {
+    Unit {
+        data: Vec::new(),
+        functions: vec![
+            Function {
+                label: Label::from_fixed("main"),
+                body,
+                escapes: 0,
+                layout: Layout::default(),
+            }
+        ],
+    }
+}
+
+#[test]
+pub fn test_valid() {
+    let exit = Label::from_str("EXIT");
+    let body = vec![
+        Stm::Move(Exp::Const(1), Exp::Temp(Temp::from_str("X"))),
+        Stm::Jump(Exp::Name(exit), vec![exit]),
+        Stm::Label(exit),
+    ];
+
+    assert!(validate(&unit(body)).is_ok());
+}
+
+#[test]
+pub fn test_stray_eseq() {
+    let body = vec![
+        Stm::Move(
+            Exp::ESeq(
+                Box::new(Stm::Label(Label::from_str("STRAY"))),
+                Box::new(Exp::Const(1)),
+            ),
+            Exp::Temp(Temp::from_str("X")),
+        ),
+    ];
+
+    match validate(&unit(body)).map_err(|err| err.kind) {
+    | Err(Kind::Internal(IRError::ESeq(_))) => (),
+    | result => panic!("Expected ESeq error, found {:?}", result),
+    }
+}
+
+#[test]
+pub fn test_missing_label() {
+    let missing = Label::from_str("MISSING");
+    let body = vec![
+        Stm::Jump(Exp::Name(missing), vec![missing]),
+    ];
+
+    match validate(&unit(body)).map_err(|err| err.kind) {
+    | Err(Kind::Internal(IRError::MissingLabel(_, label))) => assert_eq!(label, missing),
+    | result => panic!("Expected missing label error, found {:?}", result),
+    }
+}
+
+#[test]
+pub fn test_use_before_def() {
+    let x = Temp::from_str("X");
+    let y = Temp::from_str("Y");
+    let t = Label::from_str("TRUE");
+    let f = Label::from_str("FALSE");
+    let exit = Label::from_str("EXIT");
+
+    // Y is only defined on the true branch, but used after the join
+    let body = vec![
+        Stm::Move(Exp::Const(1), Exp::Temp(x)),
+        Stm::CJump(Exp::Temp(x), Relop::Eq, Exp::Const(1), t, f),
+        Stm::Label(f),
+        Stm::Jump(Exp::Name(exit), vec![exit]),
+        Stm::Label(t),
+        Stm::Move(Exp::Const(2), Exp::Temp(y)),
+        Stm::Jump(Exp::Name(exit), vec![exit]),
+        Stm::Label(exit),
+        Stm::Move(Exp::Temp(y), Exp::Temp(x)),
+    ];
+
+    match validate(&unit(body)).map_err(|err| err.kind) {
+    | Err(Kind::Internal(IRError::UseBeforeDef(_, temp, _))) => assert_eq!(temp, y),
+    | result => panic!("Expected use before definition error, found {:?}", result),
+    }
+}
+
+#[test]
+pub fn test_def_on_all_paths() {
+    let x = Temp::from_str("X");
+    let t = Label::from_str("TRUE");
+    let f = Label::from_str("FALSE");
+    let exit = Label::from_str("EXIT");
+
+    let body = vec![
+        Stm::CJump(Exp::Const(0), Relop::Eq, Exp::Const(1), t, f),
+        Stm::Label(f),
+        Stm::Move(Exp::Const(1), Exp::Temp(x)),
+        Stm::Jump(Exp::Name(exit), vec![exit]),
+        Stm::Label(t),
+        Stm::Move(Exp::Const(2), Exp::Temp(x)),
+        Stm::Jump(Exp::Name(exit), vec![exit]),
+        Stm::Label(exit),
+        Stm::Move(Exp::Temp(x), Exp::Temp(x)),
+    ];
+
+    assert!(validate(&unit(body)).is_ok());
+}
+
+#[test]
+pub fn test_false_label() {
+    let t = Label::from_str("TRUE");
+    let f = Label::from_str("FALSE");
+    let cjump = Stm::CJump(Exp::Const(0), Relop::Eq, Exp::Const(1), t, f);
+
+    // A false label cleaned away after reordering is fine on its own
+    let cleaned = vec![cjump.clone(), Stm::Label(t)];
+    assert!(validate(&unit(cleaned)).is_ok());
+
+    // But not when anything else still refers to it
+    let shared = vec![cjump.clone(), cjump.clone(), Stm::Label(t)];
+    let elsewhere = vec![cjump.clone(), Stm::Label(t), Stm::Label(f)];
+
+    for body in vec![shared, elsewhere] {
+        match validate(&unit(body)).map_err(|err| err.kind) {
+        | Err(Kind::Internal(IRError::CJump(_, label))) => assert_eq!(label, f),
+        | result => panic!("Expected conditional jump error, found {:?}", result),
+        }
+    }
+}